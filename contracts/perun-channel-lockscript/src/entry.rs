@@ -1,26 +1,44 @@
 // Import from `core` instead of from `std` since we are in no-std mode
 use core::result::Result;
+// Import heap related library from `alloc`
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::vec::Vec;
 
 // Import CKB syscalls and structures
 // https://docs.rs/ckb-std/
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::{bytes::Bytes, prelude::*},
-    high_level::{load_cell_lock_hash, load_cell_type, load_script},
+    ckb_types::{bytes::Bytes, packed::BytesOpt, prelude::*},
+    high_level::{
+        load_cell_lock_hash, load_cell_type, load_script, load_tx_hash, load_witness_args,
+    },
     syscalls::SysError,
 };
-use perun_common::{error::Error, perun_types::ChannelConstants};
+use perun_common::{
+    error::Error,
+    helpers::tx_signing_digest,
+    perun_types::{ChannelConstants, ChannelWitness, ChannelWitnessUnion, Participants},
+    sig::verify_signature_with_scheme,
+};
 
 // The perun-channel-lockscript (pcls) is used to lock access to interacting with a channel and is attached as lock script
 // to the channel-cell (the cell which uses the perun-channel-type-script (pcts) as its type script).
-// A channel defines two participants, each of which has their own unlock_script_hash (also defined in the ChannelConstants.params.{party_a,party_b}).
+// A channel defines two or more participants, each of which has their own unlock_script_hash (also defined in the ChannelConstants.params.parties).
 // The pcls allows a transaction to interact with the channel, if at least one input cell is present with:
-// - cell's lock script hash == unlock_script_hash of party_a or
-// - cell's lock script hash == unlock_script_hash of party_b
+// - cell's lock script hash == unlock_script_hash of any of the parties
 // We recommend using the secp256k1_blake160_sighash_all script as unlock script and corresponding payment args for the participants.
 //
 // Note: This means, that each participant needs to use a secp256k1_blake160_sighash_all as input to interact with the channel.
 // This should not be a substantial restriction, since a payment input will likely be used anyway (e.g. for funding or fees).
+//
+// Alternatively, a participant can authorize the transaction by a signature in its signature scheme, which is placed in the
+// lock field of the witness of the channel cell. It signs the transaction hash together with the witnesses of all channel
+// cells in the transaction (see tx_signing_digest), as the transaction hash does not cover the witnesses. This way, the
+// channel cell can be consumed without any input cell of the participant, e.g. if an outside party pays the transaction fees.
+//
+// Participants may name delegates (e.g. watchtowers) in their delegates list. If no participant is present, the pcls also allows
+// a transaction with an input cell whose lock script hash is one of the delegates, but only to dispute or force close the channel.
+// The pcts ensures that neither of these can redirect any funds.
 
 pub fn main() -> Result<(), Error> {
     let script = load_script()?;
@@ -45,14 +63,21 @@ pub fn main() -> Result<(), Error> {
         let constants = ChannelConstants::from_slice(&type_script_args)
             .expect("unable to parse args as channel parameters");
 
-        let is_participant = verify_is_participant(
-            &constants.params().party_a().unlock_script_hash().unpack(),
-            &constants.params().party_b().unlock_script_hash().unpack(),
-        )?;
+        let is_participant = verify_is_participant(&constants.params().parties())?;
+        if is_participant {
+            continue;
+        }
+
+        let is_signed = verify_is_signed_by_participant(i, &constants.params().parties())?;
+        if is_signed {
+            continue;
+        }
 
-        if !is_participant {
+        let is_delegate = verify_is_delegate(&constants.params().parties())?;
+        if !is_delegate {
             return Err(Error::NotParticipant);
         }
+        verify_delegated_action(i)?;
     }
 
     return Ok(());
@@ -60,10 +85,7 @@ pub fn main() -> Result<(), Error> {
 
 /// check_is_participant checks if the current transaction is executed by a channel participant.
 /// It does so by looking for an input cell with the same lock script hash as the unlock_script_hash
-pub fn verify_is_participant(
-    unlock_script_hash_a: &[u8; 32],
-    unlock_script_hash_b: &[u8; 32],
-) -> Result<bool, Error> {
+pub fn verify_is_participant(parties: &Participants) -> Result<bool, Error> {
     for i in 0.. {
         // Loop over all input cells.
         let cell_lock_script_hash = match load_cell_lock_hash(i, Source::Input) {
@@ -71,11 +93,98 @@ pub fn verify_is_participant(
             Err(SysError::IndexOutOfBound) => return Ok(false),
             Err(err) => return Err(err.into()),
         };
-        if cell_lock_script_hash[..] == unlock_script_hash_a[..]
-            || cell_lock_script_hash[..] == unlock_script_hash_b[..]
-        {
+        for party in parties.clone().into_iter() {
+            if cell_lock_script_hash[..] == party.unlock_script_hash().unpack()[..] {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// verify_is_signed_by_participant checks if the current transaction is authorized by a signature of a channel
+/// participant on the transaction signing digest. The signature is located in the lock field of the witness of the
+/// channel cell at index `idx` of the group inputs. If a signature is present, it has to be valid.
+pub fn verify_is_signed_by_participant(idx: usize, parties: &Participants) -> Result<bool, Error> {
+    let witness_args = match load_witness_args(idx, Source::GroupInput) {
+        Ok(witness_args) => witness_args,
+        Err(SysError::IndexOutOfBound) => return Ok(false),
+        Err(err) => return Err(err.into()),
+    };
+    let sig: Bytes = match witness_args.lock().to_opt() {
+        Some(sig) => sig.unpack(),
+        None => return Ok(false),
+    };
+    let digest = load_tx_signing_digest()?;
+    for party in parties.clone().into_iter() {
+        let verified = verify_signature_with_scheme(
+            party.sig_scheme().into(),
+            &digest,
+            &sig,
+            party.pub_key().as_slice(),
+        );
+        if verified.is_ok() {
             return Ok(true);
         }
     }
+    Err(Error::InvalidSignature)
+}
+
+/// load_tx_signing_digest returns the digest which a participant signs to authorize the current transaction. It
+/// covers the transaction hash and the witnesses of all channel cells in the group inputs without their lock field.
+pub fn load_tx_signing_digest() -> Result<[u8; 32], Error> {
+    let mut witnesses = Vec::new();
+    for i in 0.. {
+        match load_cell_lock_hash(i, Source::GroupInput) {
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => break,
+            Err(err) => return Err(err.into()),
+        };
+        let witness_args = load_witness_args(i, Source::GroupInput)?
+            .as_builder()
+            .lock(BytesOpt::default())
+            .build();
+        witnesses.push(witness_args);
+    }
+    Ok(tx_signing_digest(
+        &load_tx_hash()?,
+        witnesses.iter().map(|w| w.as_slice()),
+    ))
+}
+
+/// verify_is_delegate checks if the current transaction is executed by a delegate of a channel participant.
+/// It does so by looking for an input cell with the same lock script hash as one of the delegates of any participant.
+pub fn verify_is_delegate(parties: &Participants) -> Result<bool, Error> {
+    for i in 0.. {
+        // Loop over all input cells.
+        let cell_lock_script_hash = match load_cell_lock_hash(i, Source::Input) {
+            Ok(lock_hash) => lock_hash,
+            Err(SysError::IndexOutOfBound) => return Ok(false),
+            Err(err) => return Err(err.into()),
+        };
+        for party in parties.clone().into_iter() {
+            for delegate in party.delegates().into_iter() {
+                if cell_lock_script_hash[..] == delegate.unpack()[..] {
+                    return Ok(true);
+                }
+            }
+        }
+    }
     Ok(false)
 }
+
+/// verify_delegated_action verifies that the channel cell at index `idx` of the group inputs is
+/// either disputed or force closed, which are the only actions delegates may perform.
+pub fn verify_delegated_action(idx: usize) -> Result<(), Error> {
+    let witness_args = load_witness_args(idx, Source::GroupInput)?;
+    let witness_bytes: Bytes = witness_args
+        .input_type()
+        .to_opt()
+        .ok_or(Error::NoWitness)?
+        .unpack();
+    let channel_witness = ChannelWitness::from_slice(&witness_bytes)?;
+    match channel_witness.to_enum() {
+        ChannelWitnessUnion::Dispute(_) | ChannelWitnessUnion::ForceClose(_) => Ok(()),
+        _ => Err(Error::DelegateActionNotAllowed),
+    }
+}
@@ -2,7 +2,7 @@
 use core::result::Result;
 // Import heap related library from `alloc`
 // https://doc.rust-lang.org/alloc/index.html
-use alloc::{self, vec};
+use alloc::{self, format, vec, vec::Vec};
 
 // Import CKB syscalls and structures
 // https://docs.rs/ckb-std/
@@ -10,27 +10,40 @@ use ckb_std::{
     ckb_constants::Source,
     ckb_types::{
         bytes::Bytes,
-        packed::{Byte32, Script},
+        core::ScriptHashType,
+        packed::{Byte32, BytesOpt, BytesVec, CellOutput, Script},
         prelude::*,
     },
+    cstr_core::CStr,
     debug,
     high_level::{
-        load_cell_capacity, load_cell_data, load_cell_lock, load_cell_lock_hash, load_header,
-        load_script, load_script_hash, load_transaction, load_witness_args,
+        exec_cell, load_cell, load_cell_capacity, load_cell_data, load_cell_lock,
+        load_cell_lock_hash, load_cell_occupied_capacity, load_cell_type, load_cell_type_hash,
+        load_header, load_input_out_point, load_input_since, load_script, load_script_hash,
+        load_transaction, load_tx_hash, load_witness_args, QueryIter,
     },
+    since::{EpochNumberWithFraction, LockValue, Since},
     syscalls::{self, SysError},
 };
 use perun_common::{
     error::Error,
-    helpers::blake2b256,
+    helpers::{
+        apply_dust_policy, blake2b256, geq_components, state_signing_digest, tx_signing_digest,
+        CHALLENGE_DURATION_METRIC_BLOCK_NUMBER, CHALLENGE_DURATION_METRIC_EPOCH,
+        CHALLENGE_DURATION_METRIC_TIMESTAMP, DUST_POLICY_COUNTERPARTY, DUST_POLICY_FEE,
+        DUST_POLICY_ROUND_UP,
+    },
     perun_types::{
-        Balances, ChannelConstants, ChannelParameters, ChannelState, ChannelStatus, ChannelToken,
-        ChannelWitness, ChannelWitnessUnion, SEC1EncodedPubKey,
+        AppScript, Balances, BoolVec, CapacityRefundUnion, ChannelConstants, ChannelParameters,
+        ChannelState, ChannelStates, ChannelStatus, ChannelToken, ChannelWitness,
+        ChannelWitnessUnion, Participant, SUDTAllocation, SignedState, SignedStates, SubAlloc,
     },
-    sig::verify_signature,
+    sig::verify_signature_with_scheme,
 };
 
 const SUDT_MIN_LEN: usize = 16;
+// SHANNONS_PER_BYTE is the capacity in shannons that one byte of a cell occupies.
+const SHANNONS_PER_BYTE: u64 = 100_000_000;
 
 /// ChannelAction describes what kind of interaction with the channel is currently happening.
 ///
@@ -45,7 +58,8 @@ const SUDT_MIN_LEN: usize = 16;
 pub enum ChannelAction {
     /// Progress indicates that a channel is being progressed. This means that a channel cell is consumed
     /// in the inputs and the same channel with updated state is progressed in the outputs.
-    /// The possible redeemers associated with the Progress action are Fund and Dispute.
+    /// The possible redeemers associated with the Progress action are Fund, Dispute, Deposit, Withdraw and
+    /// Checkpoint.
     Progress {
         old_status: ChannelStatus,
         new_status: ChannelStatus,
@@ -56,7 +70,7 @@ pub enum ChannelAction {
     Start { new_status: ChannelStatus }, // no PCTS input, one PCTS output
     /// Close indicates that a channel is being closed. This means that a channel's cell is consumed without being
     /// recreated in the outputs with updated state. The possible redeemers associated with the Close action are
-    /// Close, Abort and ForceClose.
+    /// Close, Abort, ForceClose and Rotate.
     /// The channel type script assures that all funds are payed out to the correct parties upon closing.
     Close { old_status: ChannelStatus }, // one PCTS input , no PCTS output
 }
@@ -70,7 +84,9 @@ pub fn main() -> Result<(), Error> {
         return Err(Error::NoArgs);
     }
 
-    // We verify that there is at most one channel in the GroupInputs and GroupOutputs respectively.
+    // Each channel forms its own script group, so batched transactions interacting with several channels
+    // are verified channel by channel. We verify that there is at most one channel in the GroupInputs and
+    // GroupOutputs respectively, which rejects copies of the same channel.
     verify_max_one_channel()?;
     debug!("verify_max_one_channel passed");
 
@@ -134,6 +150,16 @@ pub fn check_valid_start(
     verify_thread_token_integrity(&channel_constants.thread_token())?;
     debug!("verify_thread_token_integrity passed");
 
+    // A channel with a predecessor is started by a rotation of the participants of its predecessor. Its thread
+    // token is the outpoint of the channel cell of the predecessor, which has to be consumed with a Rotate witness.
+    // The pcts of the predecessor verifies that the status, including the balances and the version, and the
+    // funds of the channel are carried over and that all participants of the predecessor agreed to the rotation.
+    let rotated = channel_constants.predecessor().is_some();
+    if let Some(predecessor) = channel_constants.predecessor().to_opt() {
+        verify_predecessor_rotated(&predecessor, &channel_constants.thread_token())?;
+        debug!("verify_predecessor_rotated passed");
+    }
+
     // We verify that the channel id is the hash of the channel parameters.
     verify_channel_id_integrity(
         &new_status.state().channel_id(),
@@ -152,34 +178,93 @@ pub fn check_valid_start(
 
     // We verify that there are no funds locked by the pfls hash of this channel in the inputs of the transaction.
     // This check is not strictly necessary for the current implementation of the pfls, but it is good practice to
-    // verify this anyway, as there is no reason to include funds locked for any channel in the input of a transaction
-    // that creates a new channel besides trying some kind of attack.
+    // verify this anyway, as there is no reason to include funds locked for this channel in the input of a transaction
+    // that creates the channel besides trying some kind of attack. Funds of other channels may be present, e.g. when
+    // a predecessor channel is spliced into this channel.
     verify_no_funds_in_inputs(channel_constants)?;
     debug!("verify_no_funds_in_inputs passed");
 
-    // We verify that the state the channel starts with is valid according to the utxo-adaption of the perun protocol.
-    // For example, the channel must not be final and the version number must be 0.
-    verify_state_valid_as_start(
-        &new_status.state(),
-        channel_constants.pfls_min_capacity().unpack(),
-    )?;
-    debug!("verify_state_valid_as_start passed");
+    // We verify that each SUDT asset appears at most once in the balances, as the funding and payout accounting
+    // looks up the distribution of an asset by its type script, that its max_capacity suffices for a payout
+    // to each party and that the number of assets does not exceed the limit in the channel constants.
+    verify_valid_sudt_allocation(&new_status.state().balances().sudts(), channel_constants)?;
+    debug!("verify_valid_sudt_allocation passed");
+
+    // We verify that the channel parameters choose a known dust policy, as the channel could not be closed
+    // otherwise.
+    verify_valid_dust_policy(&channel_constants.params())?;
+    debug!("verify_valid_dust_policy passed");
+
+    // We verify that the funding deadline is a valid point in time, as the channel could not be aborted
+    // otherwise.
+    if let Some(deadline) = channel_constants.params().funding_deadline().to_opt() {
+        verify_valid_time(&channel_constants.params(), deadline.unpack())?;
+        debug!("verify_valid_time passed for the funding deadline");
+    }
 
-    // Here we verify that the first party completes its funding and that itsfunds are actually locked to the pfls with correct args.
-    verify_funding_in_outputs(
-        FUNDER_INDEX,
-        &new_status.state().balances(),
-        channel_constants,
-    )?;
-    debug!("verify_funding_in_outputs passed");
+    // We verify that the capacity of the channel cell is refunded to a party of the channel or to a sponsor
+    // that can be told apart from the parties.
+    verify_valid_capacity_refund(channel_constants)?;
+    debug!("verify_valid_capacity_refund passed");
+
+    // We verify that the state the channel starts with is valid according to the utxo-adaption of the perun protocol.
+    // For example, the channel must not be final and the version number must be 0. A rotated channel continues
+    // with the state of its predecessor instead, which is verified by the pcts of the predecessor.
+    if !rotated {
+        verify_state_valid_as_start(
+            &new_status.state(),
+            channel_constants.pfls_min_capacity().unpack(),
+            channel_constants.params().parties().len(),
+        )?;
+        debug!("verify_state_valid_as_start passed");
+    } else if !new_status.funded().to_bool() {
+        return Err(Error::InvalidPredecessor);
+    }
 
-    // We check that the funded bit in the channel status is set to true, exactly if the funding is complete.
-    verify_funded_status(new_status, true)?;
-    debug!("verify_funded_status passed");
+    if new_status.funded().to_bool() {
+        // A channel can be started fully funded, if all parties fund it jointly in the start transaction or if it
+        // is the successor of a spliced or rotated channel. This saves the funding transactions and there is no funding phase
+        // in which the channel could be aborted. In that case the funds of all parties have to be locked to the
+        // pfls with correct args.
+        verify_full_funding_in_outputs(&new_status.state().balances(), channel_constants)?;
+        debug!("verify_full_funding_in_outputs passed");
+
+        // We check that all parties are marked as funded.
+        verify_funded_status(new_status, false)?;
+        debug!("verify_funded_status passed");
+    } else {
+        // Here we verify that the first party completes its funding and that its funds are actually locked to the pfls with correct args.
+        verify_funding_in_outputs(
+            FUNDER_INDEX,
+            &new_status.state().balances(),
+            channel_constants,
+        )?;
+        debug!("verify_funding_in_outputs passed");
+
+        // We check that exactly the first party and all parties without anything to fund are marked as funded and
+        // that the funded bit in the channel status is set to true, exactly if the funding is complete.
+        verify_funded_status(new_status, true)?;
+        debug!("verify_funded_status passed");
+    }
 
     // We verify that the channel status is not disputed upon start.
     verify_status_not_disputed(new_status)?;
     debug!("verify_status_not_disputed passed");
+
+    // Sub-channel states, the dispute deadline and the holder of the dispute bond are only registered in
+    // disputes.
+    verify_no_sub_states(new_status)?;
+    debug!("verify_no_sub_states passed");
+    verify_no_dispute_deadline(new_status)?;
+    debug!("verify_no_dispute_deadline passed");
+    verify_no_bond_holder(new_status)?;
+    debug!("verify_no_bond_holder passed");
+
+    // The fee budget is reserved in the capacity of the channel cell and nothing of it has been spent yet.
+    verify_fee_budget_reserved(channel_constants)?;
+    debug!("verify_fee_budget_reserved passed");
+    verify_no_spent_fee(new_status)?;
+    debug!("verify_no_spent_fee passed");
     Ok(())
 }
 
@@ -191,29 +276,37 @@ pub fn check_valid_progress(
 ) -> Result<(), Error> {
     debug!("check_valid_progress");
 
-    // At this point we know that the transaction progresses the channel. There are two different
-    // kinds of channel progression: Funding and Dispute. Which kind of progression is performed
-    // depends on the witness.
+    // At this point we know that the transaction progresses the channel. There are five different
+    // kinds of channel progression: Funding, Dispute, Deposit, Withdraw and Checkpoint. Which kind of progression is
+    // performed depends on the witness.
 
-    // Some checks are common to both kinds of progression and are performed here.
+    // Some checks are common to all kinds of progression and are performed here.
     // We check that both the old and the new state have the same channel id.
     verify_equal_channel_id(&old_status.state(), &new_status.state())?;
     debug!("verify_equal_channel_id passed");
 
-    // No kind of channel progression should pay out any funds locked by the pfls, so we just check
-    // that there are no funds locked by the pfls in the inputs of the transaction.
-    verify_no_funds_in_inputs(channel_constants)?;
-    debug!("verify_no_funds_in_inputs passed");
     // Here we verify that the cell with the PCTS in the outputs is locked by the same lock script
     // as the input channel cell.
     verify_channel_continues_locked()?;
     debug!("verify_channel_continues_locked passed");
 
+    // The capacity of the channel cell is refunded to the party or sponsor recorded as capacity_refund upon
+    // close. We verify that it does not decrease, so that no one progressing the channel, e.g. a delegate,
+    // can take part of it. Only disputes may pay their fees from it, so that a party without spare cells can
+    // still dispute the channel. The fees are recorded in the status and all disputes together may not pay
+    // more than max_fee.
+    verify_fee_budget(old_status, new_status, witness, channel_constants)?;
+    debug!("verify_fee_budget passed");
+
     match witness.to_enum() {
-        ChannelWitnessUnion::Fund(_) => {
-            const FUNDER_INDEX: usize = 1;
+        ChannelWitnessUnion::Fund(f) => {
             debug!("ChannelWitnessUnion::Fund");
 
+            // Only a withdrawal may pay out funds locked by the pfls, so we check that there are no funds
+            // locked by the pfls in the inputs of the transaction.
+            verify_no_funds_in_inputs(channel_constants)?;
+            debug!("verify_no_funds_in_inputs passed");
+
             // The funding array in a channel status reflects how much each party has funded up to that point.
             // Funding must not alter the channel's state.
             verify_equal_channel_state(&old_status.state(), &new_status.state())?;
@@ -223,13 +316,28 @@ pub fn check_valid_progress(
             verify_status_not_funded(&old_status)?;
             debug!("verify_status_not_funded passed");
 
+            // Funding is rejected once the channel cell was created at or after the funding deadline. A late
+            // Fund that consumes a channel cell created before the deadline is accepted, as no transaction can
+            // prove an upper bound of its inclusion time (see verify_funding_deadline_not_passed).
+            verify_funding_deadline_not_passed(&channel_constants.params())?;
+            debug!("verify_funding_deadline_not_passed passed");
+
+            // The participants fund the channel one after another in any order. The witness names the funder,
+            // which must not have funded the channel yet.
+            let funder_index = get_funder(old_status, u8::from(f.nth0()) as usize)?;
+            debug!("get_funder passed: {}", funder_index);
+
             verify_funding_in_outputs(
-                FUNDER_INDEX,
+                funder_index,
                 &old_status.state().balances(),
                 channel_constants,
             )?;
             debug!("verify_funding_in_outputs passed");
 
+            // The funder has to be marked as funded in the new status. Nothing else may change.
+            verify_funded_parties_progression(old_status, new_status, funder_index)?;
+            debug!("verify_funded_parties_progression passed");
+
             // Funding a disputed status is invalid. This should not be able to happen anyway, but we check
             // it nontheless.
             verify_status_not_disputed(new_status)?;
@@ -238,6 +346,15 @@ pub fn check_valid_progress(
             // We check that the funded bit in the channel status is set to true, iff the funding is complete.
             verify_funded_status(&new_status, false)?;
             debug!("verify_funded_status passed");
+
+            // Sub-channel states, the dispute deadline and the holder of the dispute bond are only registered
+            // in disputes.
+            verify_no_sub_states(new_status)?;
+            debug!("verify_no_sub_states passed");
+            verify_no_dispute_deadline(new_status)?;
+            debug!("verify_no_dispute_deadline passed");
+            verify_no_bond_holder(new_status)?;
+            debug!("verify_no_bond_holder passed");
             Ok(())
         }
         ChannelWitnessUnion::Dispute(d) => {
@@ -246,36 +363,218 @@ pub fn check_valid_progress(
             // An honest party will dispute a channel, e.g. if its peer does not respond and it wants to close
             // the channel. For this, the honest party needs to provide the latest state (in the "new" channel status)
             // as well as a valid signature by each party on that state (in the witness). After the expiration of the
-            // relative time lock (challenge duration), the honest party can forcibly close the channel.
+            // challenge duration, the honest party can forcibly close the channel.
             // If a malicious party disputes with an old channel state, an honest party can dispute again with
             // the latest state (with higher version number) and the corresponding signatures within the challenge
             // duration.
-
-            // First, we verify the integrity of the channel state. For this, the following must hold:
-            // - channel id is equal
-            // - version number is increasing (see verify_increasing_version_number)
-            // - sum of balances is equal
-            // - old state is not final
-            verify_channel_state_progression(old_status, &new_status.state())?;
-            debug!("verify_channel_state_progression passed");
+            // A refutation may also keep the registered state and only register newer states of its
+            // sub-channels. Otherwise, a party could register the latest state together with an outdated
+            // state of a virtual channel, which the intermediary could only refute with a newer state.
+
+            // A dispute must not pay out any funds locked by the pfls.
+            verify_no_funds_in_inputs(channel_constants)?;
+            debug!("verify_no_funds_in_inputs passed");
+
+            let sub_states_only =
+                is_sub_state_refutation(old_status, new_status, &d.sub_channels());
+            if !sub_states_only {
+                // Next, we verify the integrity of the channel state. For this, the following must hold:
+                // - channel id is equal
+                // - version number is increasing (see verify_increasing_version_number)
+                // - sum of balances is equal
+                // - old state is not final
+                // The latter rejects any refutation of a disputed final state.
+                verify_channel_state_progression(old_status, &new_status.state())?;
+                debug!("verify_channel_state_progression passed");
+            }
+            // A disputed final state is settled at once on force close, before the registered
+            // sub-channel states could be refuted. Like on close, it must not lock any funds for
+            // sub-channels.
+            if new_status.state().is_final().to_bool() {
+                verify_no_locked_funds(&new_status.state().balances())?;
+                debug!("verify_no_locked_funds passed");
+            }
+            verify_valid_htlc_timeouts(
+                &new_status.state().balances(),
+                &channel_constants.params(),
+            )?;
+            debug!("verify_valid_htlc_timeouts passed");
 
             // One cannot dispute if funding is not complete.
             verify_status_funded(old_status)?;
             debug!("verify_status_funded passed");
 
+            // The funding information must be carried over to the disputed status unaltered.
+            verify_funding_unchanged(old_status, new_status)?;
+            debug!("verify_funding_unchanged passed");
+
             // The disputed flag in the new status must be set. This indicates that the channel can be closed
             // forcibly after the expiration of the challenge duration in a later transaction.
             verify_status_disputed(new_status)?;
             debug!("verify_status_disputed passed");
 
-            // We verify that the signatures of both parties are valid on the new channel state.
-            verify_valid_state_sigs(
-                &d.sig_a().unpack(),
-                &d.sig_b().unpack(),
-                &new_status.state(),
-                &channel_constants.params().party_a().pub_key(),
-                &channel_constants.params().party_b().pub_key(),
+            // The challenge duration starts with the first dispute. Refutations must not move its deadline.
+            verify_dispute_deadline(old_status, new_status, &channel_constants.params())?;
+            debug!("verify_dispute_deadline passed");
+
+            // If the new state locks funds for sub-channels (e.g. virtual channels), the latest state of each
+            // sub-channel has to be registered together with it. A sub-channel state is either provided in the
+            // witness or carried over from a previous dispute.
+            verify_sub_states(old_status, new_status, &d.sub_channels())?;
+            debug!("verify_sub_states passed");
+
+            // In app channels, a disputed channel can also be progressed unilaterally: The acting party only
+            // provides its own signature on the new state and the app validator decides whether the transition
+            // from the old to the new state is valid.
+            let sole_signer = if sub_states_only {
+                None
+            } else {
+                get_sole_signer(&d.sigs(), &channel_constants.params())
+            };
+
+            // The first dispute locks the dispute bond, which is payed to the party that registered the latest
+            // state upon close.
+            verify_dispute_bond(old_status, new_status, channel_constants, sole_signer)?;
+            debug!("verify_dispute_bond passed");
+
+            if let Some(actor) = sole_signer {
+                debug!("unilateral progression by party {}", actor);
+                let app = verify_valid_unilateral_progression(
+                    old_status,
+                    &new_status.state(),
+                    channel_constants,
+                )?;
+                debug!("verify_valid_unilateral_progression passed");
+
+                verify_valid_state_sig(
+                    &d.sigs(),
+                    actor,
+                    &new_status.state(),
+                    &channel_constants.params(),
+                )?;
+                debug!("verify_valid_state_sig passed");
+
+                // The app validator replaces this script upon execution, so this has to be the last check.
+                return exec_app_validator(&app, actor);
+            }
+
+            // The signatures on a state kept by a refutation were verified upon its registration.
+            if sub_states_only {
+                return Ok(());
+            }
+
+            // We verify that the signatures of all parties are valid on the new channel state.
+            verify_valid_state_sigs(&d.sigs(), &new_status.state(), &channel_constants.params())?;
+            debug!("verify_valid_state_sigs passed");
+            Ok(())
+        }
+        ChannelWitnessUnion::Deposit(d) => {
+            debug!("ChannelWitnessUnion::Deposit");
+
+            // A deposit locks additional funds to a running channel. The depositing parties lock their funds
+            // to the pfls in the outputs and all parties sign the new state, which credits the deposited
+            // funds to the depositors. Apart from that, nothing may change.
+            verify_no_funds_in_inputs(channel_constants)?;
+            debug!("verify_no_funds_in_inputs passed");
+
+            // A disputed channel can not be topped up, it can only be closed.
+            verify_valid_cooperative_update(old_status, new_status, channel_constants)?;
+            debug!("verify_valid_cooperative_update passed");
+
+            let (ckbytes_deposit, udt_deposit) = verify_valid_deposit(
+                &old_status.state().balances(),
+                &new_status.state().balances(),
+            )?;
+            debug!("verify_valid_deposit passed");
+
+            verify_deposit_in_outputs(
+                &new_status.state().balances(),
+                ckbytes_deposit,
+                &udt_deposit,
+                channel_constants,
+            )?;
+            debug!("verify_deposit_in_outputs passed");
+
+            verify_valid_state_sigs(&d.sigs(), &new_status.state(), &channel_constants.params())?;
+            debug!("verify_valid_state_sigs passed");
+            Ok(())
+        }
+        ChannelWitnessUnion::Withdraw(w) => {
+            debug!("ChannelWitnessUnion::Withdraw");
+
+            // A withdrawal pays out part of the funds of a running channel. The funds locked by the pfls are
+            // consumed in the inputs, the withdrawn funds are payed to the withdrawing parties and the
+            // remainder is locked to the pfls again. The channel may have progressed off-chain since the old
+            // state was recorded, so the withdrawn funds are determined relative to the latest off-chain
+            // state in the witness. All parties sign both states.
+            // A disputed channel can only be closed.
+            verify_valid_cooperative_update(old_status, new_status, channel_constants)?;
+            debug!("verify_valid_cooperative_update passed");
+
+            let (ckbytes_withdrawals, udt_withdrawals) =
+                verify_valid_withdrawal(&old_status.state(), &w.state(), &new_status.state())?;
+            debug!("verify_valid_withdrawal passed");
+
+            verify_valid_state_sigs(&w.state_sigs(), &w.state(), &channel_constants.params())?;
+            debug!("verify_valid_state_sigs passed for the latest off-chain state");
+
+            verify_withdrawal_in_outputs(
+                &new_status.state().balances(),
+                &ckbytes_withdrawals,
+                &udt_withdrawals,
+                channel_constants,
+            )?;
+            debug!("verify_withdrawal_in_outputs passed");
+
+            verify_valid_state_sigs(&w.sigs(), &new_status.state(), &channel_constants.params())?;
+            debug!("verify_valid_state_sigs passed");
+            Ok(())
+        }
+        ChannelWitnessUnion::Checkpoint(c) => {
+            debug!("ChannelWitnessUnion::Checkpoint");
+
+            // A checkpoint records a newer state, which is signed by all participants, in the channel cell without
+            // disputing the channel. As a dispute has to register a state with a higher version, older states can
+            // not be registered afterwards, so that they do not need to be kept, e.g. by watchtowers.
+            // A checkpoint must not pay out any funds locked by the pfls.
+            verify_no_funds_in_inputs(channel_constants)?;
+            debug!("verify_no_funds_in_inputs passed");
+
+            // The same conditions as for a dispute apply to the new state (see verify_channel_state_progression).
+            verify_channel_state_progression(old_status, &new_status.state())?;
+            debug!("verify_channel_state_progression passed");
+            // Unlike a dispute, a checkpoint can not register the initial state again, as it would not replace
+            // any older state.
+            verify_strictly_increasing_version_number(&old_status.state(), &new_status.state())?;
+            debug!("verify_strictly_increasing_version_number passed");
+            verify_valid_htlc_timeouts(
+                &new_status.state().balances(),
+                &channel_constants.params(),
             )?;
+            debug!("verify_valid_htlc_timeouts passed");
+
+            verify_status_funded(old_status)?;
+            debug!("verify_status_funded passed");
+
+            // A disputed channel can only be progressed by refutations, which keep it disputed.
+            verify_status_not_disputed(old_status)?;
+            debug!("verify_status_not_disputed passed");
+            verify_status_not_disputed(new_status)?;
+            debug!("verify_status_not_disputed passed for the new status");
+
+            verify_funding_unchanged(old_status, new_status)?;
+            debug!("verify_funding_unchanged passed");
+
+            // Sub-channel states, the dispute deadline and the holder of the dispute bond are only registered
+            // in disputes.
+            verify_no_sub_states(new_status)?;
+            debug!("verify_no_sub_states passed");
+            verify_no_dispute_deadline(new_status)?;
+            debug!("verify_no_dispute_deadline passed");
+            verify_no_bond_holder(new_status)?;
+            debug!("verify_no_bond_holder passed");
+
+            verify_valid_state_sigs(&c.sigs(), &new_status.state(), &channel_constants.params())?;
             debug!("verify_valid_state_sigs passed");
             Ok(())
         }
@@ -283,6 +582,7 @@ pub fn check_valid_progress(
         ChannelWitnessUnion::Close(_) => Err(Error::ChannelCloseWithChannelOutput),
         ChannelWitnessUnion::ForceClose(_) => Err(Error::ChannelForceCloseWithChannelOutput),
         ChannelWitnessUnion::Abort(_) => Err(Error::ChannelAbortWithChannelOutput),
+        ChannelWitnessUnion::Rotate(_) => Err(Error::InvalidRotation),
     }
 }
 
@@ -296,12 +596,14 @@ pub fn check_valid_close(
     // At this point we know that this transaction closes the channel. There are three different kinds of
     // closing: Abort, ForceClose and Close. Which kind of closing is performed depends on the witness.
     // Every channel closing transaction must pay out all funds the the channel participants. The amount
-    // to be payed to each party
+    // to be payed to each party depends on the kind of closing. Only a force close may pay its fee from the
+    // channel funds, so that a party without spare cells can still settle the channel. It may only pay what
+    // the disputes before have left of the fee budget max_fee.
     let channel_capacity = load_cell_capacity(0, Source::GroupInput)?;
+    let (capacity_payouts, sponsor_payout) =
+        get_channel_capacity_payouts(old_status, channel_capacity, channel_constants)?;
     match channel_witness.to_enum() {
         ChannelWitnessUnion::Abort(_) => {
-            const PARTY_B_INDEX: usize = 1;
-
             debug!("ChannelWitnessUnion::Abort");
 
             // An abort can be performed at any time by a channel participant on a channel for which funding
@@ -310,34 +612,55 @@ pub fn check_valid_close(
             verify_status_not_funded(old_status)?;
             debug!("verify_status_not_funded passed");
 
+            // If the channel has a funding deadline, it can only be aborted once the deadline has passed, so
+            // that the abort can not race against funding before the deadline.
+            verify_funding_deadline_passed(&channel_constants.params())?;
+            debug!("verify_funding_deadline_passed passed");
+
             // We verify that every party is payed the amount of funds that it has locked to the channel so far.
-            // If abourt is called, Party A must have fully funded the channel and Party B can not have funded
-            // the channel because of our funding protocol.
+            // The parties that have not yet funded the channel are not payed anything.
             verify_all_payed(
-                &old_status.state().balances().clear_index(PARTY_B_INDEX)?,
-                channel_capacity,
+                &get_funded_balances(old_status)?,
+                &capacity_payouts,
+                sponsor_payout,
                 channel_constants,
-                true,
+                &old_status.funded_parties(),
+                0,
             )?;
             debug!("verify_all_payed passed");
             Ok(())
         }
-        ChannelWitnessUnion::ForceClose(_) => {
+        ChannelWitnessUnion::ForceClose(f) => {
             debug!("ChannelWitnessUnion::ForceClose");
             // A force close can be performed after the channel was disputed and the challenge duration has
-            // expired. Upon force close, each party is payed according to the balance distribution in the
-            // latest state.
+            // expired. If the disputed state is final, no later state can exist, which could be registered
+            // in a refutation. It can therefore be settled at once. Upon force close, each party is payed
+            // according to the balance distribution in the latest state. The funds locked for sub-channels
+            // are payed out according to the registered sub-channel states and the funds locked in HTLCs
+            // according to the preimages in the witness and the since of the inputs.
             verify_status_funded(old_status)?;
             debug!("verify_status_funded passed");
-            verify_time_lock_expired(channel_constants.params().challenge_duration().unpack())?;
-            debug!("verify_time_lock_expired passed");
+            if !old_status.state().is_final().to_bool() {
+                verify_time_lock_expired(old_status, &channel_constants.params())?;
+                debug!("verify_time_lock_expired passed");
+            }
             verify_status_disputed(old_status)?;
             debug!("verify_status_disputed passed");
+            let params = channel_constants.params();
+            let since = get_absolute_input_sinces()?;
             verify_all_payed(
-                &old_status.state().balances(),
-                channel_capacity,
+                &old_status
+                    .state()
+                    .balances()
+                    .settle_sub_allocs(&old_status.sub_states())?
+                    .settle_htlcs(&f.preimages(), |timeout| {
+                        is_htlc_timed_out(&params, &since, timeout)
+                    })?,
+                &capacity_payouts,
+                sponsor_payout,
                 channel_constants,
-                false,
+                &old_status.funded_parties(),
+                remaining_fee_budget(old_status, channel_constants),
             )?;
             debug!("verify_all_payed passed");
             Ok(())
@@ -354,25 +677,121 @@ pub fn check_valid_close(
             debug!("check_valid_close: Status funded verified");
             verify_state_finalized(&c.state())?;
             debug!("check_valid_close: State finalized verified");
-            verify_valid_state_sigs(
-                &c.sig_a().unpack(),
-                &c.sig_b().unpack(),
-                &c.state(),
-                &channel_constants.params().party_a().pub_key(),
-                &channel_constants.params().party_b().pub_key(),
+            // A final state must not lock any funds for sub-channels. They have to be unlocked cooperatively
+            // before, or the channel has to be disputed together with the sub-channel states.
+            verify_no_locked_funds(&c.state().balances())?;
+            debug!("check_valid_close: No locked funds verified");
+            verify_balances_num_parties(
+                &c.state().balances(),
+                channel_constants.params().parties().len(),
             )?;
+            debug!("check_valid_close: Number of parties verified");
+            verify_valid_state_sigs(&c.sigs(), &c.state(), &channel_constants.params())?;
+            // The funds locked in HTLCs are settled to their receivers by the preimages in the witness and
+            // to their senders once the timeout has passed.
+            let params = channel_constants.params();
+            verify_valid_htlc_timeouts(&c.state().balances(), &params)?;
+            let since = get_absolute_input_sinces()?;
+            let balances = c
+                .state()
+                .balances()
+                .settle_htlcs(&c.preimages(), |timeout| {
+                    is_htlc_timed_out(&params, &since, timeout)
+                })?;
+            debug!("check_valid_close: HTLCs settled");
+            if let Some(successor) = c.state().successor().to_opt() {
+                // A final state with a successor splices the channel: Instead of being payed out, the final
+                // balances fund the successor channel, which is started in the same transaction.
+                verify_successor_started(&balances, &successor)?;
+                debug!("verify_successor_started passed");
+                // The channel capacity is returned to the party or sponsor that provided it upon channel start.
+                verify_channel_capacity_payed(
+                    &balances,
+                    &capacity_payouts,
+                    sponsor_payout,
+                    channel_constants,
+                )?;
+                debug!("verify_channel_capacity_payed passed");
+                return Ok(());
+            }
             // We verify that each party is payed according to the balance distribution in the final state.
             verify_all_payed(
-                &c.state().balances(),
-                channel_capacity,
+                &balances,
+                &capacity_payouts,
+                sponsor_payout,
                 channel_constants,
-                false,
+                &old_status.funded_parties(),
+                0,
             )?;
             debug!("verify_all_payed passed");
             Ok(())
         }
+        ChannelWitnessUnion::Rotate(r) => {
+            debug!("ChannelWitnessUnion::Rotate");
+
+            // A rotation replaces the keys of the participants of a running channel, e.g. if a key has been
+            // compromised. As the participants are part of the channel constants, the channel is closed and a
+            // successor channel with the rotated participants is started in the same transaction. The funds are
+            // moved to the pfls of the successor and its channel id is the hash of the rotated parameters.
+            // A disputed channel can only be closed.
+            verify_status_funded(old_status)?;
+            debug!("verify_status_funded passed");
+            verify_status_not_disputed(old_status)?;
+            debug!("verify_status_not_disputed passed");
+            verify_state_not_finalized(&old_status.state())?;
+            debug!("verify_state_not_finalized passed");
+
+            let (successor_hash, successor_constants, successor_status) =
+                get_rotated_successor(&old_status.state().channel_id(), channel_capacity)?;
+            debug!("get_rotated_successor passed");
+
+            verify_valid_rotation(channel_constants, &successor_constants)?;
+            debug!("verify_valid_rotation passed");
+
+            // The successor continues with the latest off-chain state, which all participants sign with the
+            // rotation. It must not be older than the state of this channel and has to hold the same funds. The
+            // rest of the status is carried over unaltered.
+            let successor_state = successor_status.state();
+            verify_state_not_finalized(&successor_state)?;
+            verify_balances_num_parties(
+                &successor_state.balances(),
+                channel_constants.params().parties().len(),
+            )?;
+            verify_equal_sum_of_balances(
+                &old_status.state().balances(),
+                &successor_state.balances(),
+            )?;
+            let old_version: u64 = old_status.state().version().unpack();
+            let successor_version: u64 = successor_state.version().unpack();
+            if successor_version < old_version {
+                return Err(Error::VersionNumberNotIncreasing);
+            }
+            let expected_status = old_status
+                .clone()
+                .as_builder()
+                .state(successor_state.clone())
+                .build();
+            if expected_status.as_slice()[..] != successor_status.as_slice()[..] {
+                return Err(Error::InvalidRotation);
+            }
+            debug!("successor status verified");
+
+            // All participants of this channel agree to the rotation by signing the state of the successor. The
+            // signatures are bound to the pcts of the successor, so they can not be used for this channel.
+            verify_valid_state_sigs_in_domain(
+                &r.sigs(),
+                &successor_state,
+                &channel_constants.params(),
+                &successor_hash,
+            )?;
+            debug!("verify_valid_state_sigs_in_domain passed");
+            Ok(())
+        }
         ChannelWitnessUnion::Fund(_) => Err(Error::ChannelFundWithoutChannelOutput),
         ChannelWitnessUnion::Dispute(_) => Err(Error::ChannelDisputeWithoutChannelOutput),
+        ChannelWitnessUnion::Deposit(_) => Err(Error::ChannelDepositWithoutChannelOutput),
+        ChannelWitnessUnion::Withdraw(_) => Err(Error::ChannelWithdrawWithoutChannelOutput),
+        ChannelWitnessUnion::Checkpoint(_) => Err(Error::ChannelCheckpointWithoutChannelOutput),
     }
 }
 
@@ -415,21 +834,143 @@ pub fn verify_increasing_version_number(
     Err(Error::VersionNumberNotIncreasing)
 }
 
+/// verify_strictly_increasing_version_number verifies that the version of `new_state` is higher than the
+/// version of `old_state`. Unlike verify_increasing_version_number, it does not allow registering the
+/// initial state again.
+pub fn verify_strictly_increasing_version_number(
+    old_state: &ChannelState,
+    new_state: &ChannelState,
+) -> Result<(), Error> {
+    if old_state.version().unpack() >= new_state.version().unpack() {
+        return Err(Error::VersionNumberNotIncreasing);
+    }
+    Ok(())
+}
+
+/// verify_valid_state_sigs verifies that `sigs` holds a valid signature on `state` for each participant,
+/// in the order of the participants in the channel parameters.
 pub fn verify_valid_state_sigs(
-    sig_a: &Bytes,
-    sig_b: &Bytes,
+    sigs: &BytesVec,
+    state: &ChannelState,
+    params: &ChannelParameters,
+) -> Result<(), Error> {
+    verify_valid_state_sigs_in_domain(sigs, state, params, &state_signing_domain(params)?)
+}
+
+/// verify_valid_state_sigs_in_domain verifies that `sigs` holds a valid signature on `state` bound to the
+/// pcts hash `domain` for each participant, in the order of the participants in the channel parameters.
+pub fn verify_valid_state_sigs_in_domain(
+    sigs: &BytesVec,
+    state: &ChannelState,
+    params: &ChannelParameters,
+    domain: &[u8; 32],
+) -> Result<(), Error> {
+    let parties = params.parties();
+    if sigs.len() != parties.len() {
+        return Err(Error::PartyCountMismatch);
+    }
+    let msg_hash = state_signing_digest(domain, state);
+    for (i, party) in parties.into_iter().enumerate() {
+        let sig: Bytes = sigs.get(i).ok_or(Error::IndexOutOfBound)?.unpack();
+        verify_signature_with_scheme(
+            party.sig_scheme().into(),
+            &msg_hash,
+            &sig,
+            party.pub_key().as_slice(),
+        )?;
+        debug!("verify_valid_state_sigs: Signature {} verified", i);
+    }
+    Ok(())
+}
+
+/// get_sole_signer returns the index of the only party that provided a signature in `sigs`, if there
+/// is exactly one such party. Parties that did not sign are represented by an empty signature.
+pub fn get_sole_signer(sigs: &BytesVec, params: &ChannelParameters) -> Option<usize> {
+    if sigs.len() != params.parties().len() {
+        return None;
+    }
+    let mut signers = sigs
+        .clone()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, sig)| !sig.is_empty());
+    match (signers.next(), signers.next()) {
+        (Some((actor, _)), None) => Some(actor),
+        _ => None,
+    }
+}
+
+/// verify_valid_state_sig verifies that `sigs` holds a valid signature on `state` by the party at `idx`.
+pub fn verify_valid_state_sig(
+    sigs: &BytesVec,
+    idx: usize,
     state: &ChannelState,
-    pub_key_a: &SEC1EncodedPubKey,
-    pub_key_b: &SEC1EncodedPubKey,
+    params: &ChannelParameters,
 ) -> Result<(), Error> {
-    let msg_hash = blake2b256(state.as_slice());
-    verify_signature(&msg_hash, sig_a, pub_key_a.as_slice())?;
-    debug!("verify_valid_state_sigs: Signature A verified");
-    verify_signature(&msg_hash, sig_b, pub_key_b.as_slice())?;
-    debug!("verify_valid_state_sigs: Signature B verified");
+    let party = params.parties().get(idx).ok_or(Error::IndexOutOfBound)?;
+    let sig: Bytes = sigs.get(idx).ok_or(Error::IndexOutOfBound)?.unpack();
+    verify_signature_with_scheme(
+        party.sig_scheme().into(),
+        &state_signing_digest(&state_signing_domain(params)?, state),
+        &sig,
+        party.pub_key().as_slice(),
+    )?;
     Ok(())
 }
 
+/// state_signing_domain returns the pcts hash which signatures on states of the channel with the
+/// given parameters are bound to. This is the script hash of this pcts for ledger channels and its
+/// code hash for virtual channels, whose states are registered in several parent channels.
+pub fn state_signing_domain(params: &ChannelParameters) -> Result<[u8; 32], Error> {
+    if params.is_virtual_channel().to_bool() {
+        return Ok(load_script()?.code_hash().unpack());
+    }
+    Ok(load_script_hash()?)
+}
+
+/// verify_valid_unilateral_progression verifies that the channel has an app and that the old status
+/// is disputed, so that it can be progressed by a single party. A unilateral progression has to
+/// increase the version by exactly one. It returns the app of the channel.
+pub fn verify_valid_unilateral_progression(
+    old_status: &ChannelStatus,
+    new_state: &ChannelState,
+    channel_constants: &ChannelConstants,
+) -> Result<AppScript, Error> {
+    let app = channel_constants
+        .params()
+        .app()
+        .to_opt()
+        .ok_or(Error::InvalidUnilateralProgression)?;
+    if !old_status.disputed().to_bool() {
+        return Err(Error::InvalidUnilateralProgression);
+    }
+    let old_version: u64 = old_status.state().version().unpack();
+    let new_version: u64 = new_state.version().unpack();
+    if old_version.checked_add(1) != Some(new_version) {
+        return Err(Error::InvalidUnilateralProgression);
+    }
+    Ok(app)
+}
+
+/// exec_app_validator executes the app validator referenced by `app` with the index of the acting
+/// party as its only argument. The app validator is executed in place of the pcts, i.e. its exit
+/// code decides about the validity of the transaction. The app validator can load the old and new
+/// channel status from the GroupInput and GroupOutput respectively.
+pub fn exec_app_validator(app: &AppScript, actor: usize) -> Result<(), Error> {
+    let hash_type = match u8::from(app.hash_type()) {
+        0 => ScriptHashType::Data,
+        1 => ScriptHashType::Type,
+        2 => ScriptHashType::Data1,
+        _ => return Err(Error::InvalidAppHashType),
+    };
+    let actor_arg = format!("{}\0", actor);
+    let argv = [CStr::from_bytes_with_nul(actor_arg.as_bytes())
+        .map_err(|_| Error::AppValidatorExecFailed)?];
+    exec_cell(&app.code_hash().unpack(), hash_type, 0, 0, &argv)?;
+    // exec_cell only returns, if the app validator could not be executed.
+    Err(Error::AppValidatorExecFailed)
+}
+
 pub fn verify_state_not_finalized(state: &ChannelState) -> Result<(), Error> {
     if state.is_final().to_bool() {
         return Err(Error::StateIsFinal);
@@ -463,13 +1004,83 @@ pub fn verify_channel_continues_locked() -> Result<(), Error> {
     Ok(())
 }
 
+/// verify_fee_budget verifies that the capacity of the channel cell only decreases by the fee of a dispute,
+/// which may not exceed the rest of the fee budget and is added to the spent fee in the status. The first
+/// dispute also locks the dispute bond in the channel cell, which does not count as decrease. All other
+/// progressions must neither decrease the capacity nor alter the spent fee.
+pub fn verify_fee_budget(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    witness: &ChannelWitness,
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
+    let output_capacity = load_cell_capacity(0, Source::GroupOutput)?;
+    let (budget, bond) = match witness.to_enum() {
+        ChannelWitnessUnion::Dispute(_) => {
+            let bond = match channel_constants.params().dispute_bond().to_opt() {
+                Some(bond) if !old_status.disputed().to_bool() => bond.unpack(),
+                _ => 0,
+            };
+            (remaining_fee_budget(old_status, channel_constants), bond)
+        }
+        _ => (0, 0),
+    };
+    let fee = input_capacity
+        .saturating_add(bond)
+        .saturating_sub(output_capacity);
+    if fee > budget {
+        return Err(match budget {
+            0 => Error::ChannelCapacityDecreased,
+            _ => Error::MaxFeeExceeded,
+        });
+    }
+    let old_spent: u64 = old_status.spent_fee().unpack();
+    let new_spent: u64 = new_status.spent_fee().unpack();
+    if new_spent != old_spent + fee {
+        return Err(Error::InvalidSpentFee);
+    }
+    Ok(())
+}
+
+/// remaining_fee_budget returns the part of the fee budget max_fee, which has not been spent by disputes yet.
+pub fn remaining_fee_budget(status: &ChannelStatus, channel_constants: &ChannelConstants) -> u64 {
+    let max_fee: u64 = channel_constants.max_fee().unpack();
+    max_fee.saturating_sub(status.spent_fee().unpack())
+}
+
+/// verify_fee_budget_reserved verifies that the capacity of the started channel cell covers its occupied
+/// capacity and the fee budget max_fee, which disputes and the force close may pay from it.
+pub fn verify_fee_budget_reserved(channel_constants: &ChannelConstants) -> Result<(), Error> {
+    let max_fee: u64 = channel_constants.max_fee().unpack();
+    let capacity = load_cell_capacity(0, Source::GroupOutput)?;
+    let occupied = load_cell_occupied_capacity(0, Source::GroupOutput)?;
+    if capacity < occupied.saturating_add(max_fee) {
+        return Err(Error::FeeBudgetNotReserved);
+    }
+    Ok(())
+}
+
+/// verify_no_spent_fee verifies that no fee has been payed from the capacity of the channel cell.
+pub fn verify_no_spent_fee(status: &ChannelStatus) -> Result<(), Error> {
+    if status.spent_fee().unpack() != 0 {
+        return Err(Error::InvalidSpentFee);
+    }
+    Ok(())
+}
+
+/// verify_no_funds_in_inputs verifies that no funds locked by the pfls of this channel are in the
+/// inputs of the transaction.
 pub fn verify_no_funds_in_inputs(channel_constants: &ChannelConstants) -> Result<(), Error> {
+    let own_script_hash = load_script_hash()?;
     let num_inputs = load_transaction()?.raw().inputs().len();
     for i in 0..num_inputs {
-        let cell_lock_hash = load_cell_lock(i, Source::Input)?;
-        if cell_lock_hash.code_hash().unpack()[..]
-            == channel_constants.pfls_code_hash().unpack()[..]
-        {
+        let cell_lock = load_cell_lock(i, Source::Input)?;
+        if cell_lock.code_hash().unpack()[..] != channel_constants.pfls_code_hash().unpack()[..] {
+            continue;
+        }
+        let args: Bytes = cell_lock.args().unpack();
+        if args[..] == own_script_hash[..] {
             return Err(Error::FundsInInputs);
         }
     }
@@ -492,40 +1103,13 @@ pub fn verify_funding_in_outputs(
     channel_constants: &ChannelConstants,
 ) -> Result<(), Error> {
     let ckbytes_locked_for_sudts = initial_balance.sudts().get_locked_ckbytes();
-    let to_fund = initial_balance.ckbytes().get(idx)? + ckbytes_locked_for_sudts;
+    let to_fund = initial_balance.ckbytes().get_amount(idx)? + ckbytes_locked_for_sudts;
     if to_fund == 0 {
         return Ok(());
     }
 
-    let mut udt_sum =
-        vec![0u128, initial_balance.sudts().len().try_into().unwrap()].into_boxed_slice();
-
-    let expected_pcts_script_hash = load_script_hash()?;
-    let outputs = load_transaction()?.raw().outputs();
-    let expected_pfls_code_hash = channel_constants.pfls_code_hash().unpack();
-    let expected_pfls_hash_type = channel_constants.pfls_hash_type();
-    let mut capacity_sum: u64 = 0;
-    for (i, output) in outputs.into_iter().enumerate() {
-        if output.lock().code_hash().unpack()[..] == expected_pfls_code_hash[..]
-            && output.lock().hash_type().eq(&expected_pfls_hash_type)
-        {
-            let output_lock_args: Bytes = output.lock().args().unpack();
-            let script_hash_in_pfls_args = Byte32::from_slice(&output_lock_args)?.unpack();
-            if script_hash_in_pfls_args[..] == expected_pcts_script_hash[..] {
-                capacity_sum += output.capacity().unpack();
-            } else {
-                return Err(Error::InvalidPFLSInOutputs);
-            }
-            if output.type_().is_some() {
-                let (sudt_idx, amount) = get_sudt_amout(
-                    initial_balance,
-                    i,
-                    &output.type_().to_opt().expect("checked above"),
-                )?;
-                udt_sum[sudt_idx] += amount;
-            }
-        }
-    }
+    let (capacity_sum, udt_sum) =
+        get_pfls_cells(initial_balance, channel_constants, Source::Output)?;
     if capacity_sum != to_fund {
         debug!(
             "verify_funding_in_outputs: capacity_sum: {}, to_fund: {}",
@@ -540,31 +1124,104 @@ pub fn verify_funding_in_outputs(
     Ok(())
 }
 
-pub fn verify_funded_status(status: &ChannelStatus, is_start: bool) -> Result<(), Error> {
-    if !is_start {
-        if !status.funded().to_bool() {
-            return Err(Error::FundedBitStatusNotCorrect);
-        }
-        return Ok(());
+/// verify_full_funding_in_outputs verifies that the funds of all parties, including the funds locked for
+/// sub-channels and HTLCs, are locked to the pfls of this channel in the outputs of the transaction.
+pub fn verify_full_funding_in_outputs(
+    initial_balance: &Balances,
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    let num_parties = initial_balance.ckbytes().len() as u64;
+    let to_fund = initial_balance.total_ckbytes()
+        + num_parties * initial_balance.sudts().get_locked_ckbytes();
+    let (capacity_sum, udt_sum) =
+        get_pfls_cells(initial_balance, channel_constants, Source::Output)?;
+    if capacity_sum != to_fund {
+        debug!(
+            "verify_full_funding_in_outputs: capacity_sum: {}, to_fund: {}",
+            capacity_sum, to_fund
+        );
+        return Err(Error::OwnFundingNotInOutputs);
     }
-    if status.state().balances().ckbytes().get(1)? != 0 {
-        if status.funded().to_bool() {
-            return Err(Error::FundedBitStatusNotCorrect);
+    for (i, amount) in udt_sum.iter().enumerate() {
+        if initial_balance.total_sudt(i)? != *amount {
+            return Err(Error::OwnFundingNotInOutputs);
         }
-        return Ok(());
     }
-    if status.state().balances().sudts().len() != 0 {
-        if status.funded().to_bool() {
-            return Err(Error::FundedBitStatusNotCorrect);
+    Ok(())
+}
+
+pub fn verify_funded_status(status: &ChannelStatus, is_start: bool) -> Result<(), Error> {
+    const FUNDER_INDEX: usize = 0;
+
+    let balances = status.state().balances();
+    let funded_parties = status.funded_parties();
+    if funded_parties.len() != balances.ckbytes().len() {
+        return Err(Error::PartyCountMismatch);
+    }
+    if is_start {
+        // Upon start, the first party has funded the channel. Every other party is only considered
+        // to have funded the channel, if it has nothing to fund.
+        for i in 0..funded_parties.len() {
+            let nothing_to_fund =
+                balances.ckbytes().get_amount(i)? == 0 && balances.sudts().len() == 0;
+            if funded_parties.get_bool(i)? != (i == FUNDER_INDEX || nothing_to_fund) {
+                return Err(Error::FundedBitStatusNotCorrect);
+            }
         }
-        return Ok(());
     }
-    if !status.funded().to_bool() {
+    if status.funded().to_bool() != funded_parties.all() {
         return Err(Error::FundedBitStatusNotCorrect);
     }
     Ok(())
 }
 
+/// get_funder returns `idx`, if the party at `idx` has not yet funded the channel.
+pub fn get_funder(status: &ChannelStatus, idx: usize) -> Result<usize, Error> {
+    if status.funded_parties().get_bool(idx)? {
+        return Err(Error::StateIsFunded);
+    }
+    Ok(idx)
+}
+
+/// verify_funded_parties_progression verifies that the new status marks exactly the parties
+/// as funded that were funded in the old status and the party at `funder_index`.
+pub fn verify_funded_parties_progression(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    funder_index: usize,
+) -> Result<(), Error> {
+    let expected = old_status.funded_parties().set_index(funder_index)?;
+    if expected.as_slice()[..] != new_status.funded_parties().as_slice()[..] {
+        return Err(Error::FundingChanged);
+    }
+    Ok(())
+}
+
+/// verify_funding_unchanged verifies that the funding information of the old and new status is equal.
+pub fn verify_funding_unchanged(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+) -> Result<(), Error> {
+    if old_status.funded().as_slice()[..] != new_status.funded().as_slice()[..]
+        || old_status.funded_parties().as_slice()[..] != new_status.funded_parties().as_slice()[..]
+    {
+        return Err(Error::FundingChanged);
+    }
+    Ok(())
+}
+
+/// get_funded_balances returns the balances of the given status, in which every party that
+/// has not yet funded the channel holds nothing.
+pub fn get_funded_balances(status: &ChannelStatus) -> Result<Balances, Error> {
+    let mut balances = status.state().balances();
+    for (i, funded) in status.funded_parties().into_iter().enumerate() {
+        if !funded.to_bool() {
+            balances = balances.clear_index(i)?;
+        }
+    }
+    Ok(balances)
+}
+
 pub fn verify_status_not_funded(status: &ChannelStatus) -> Result<(), Error> {
     if status.funded().to_bool() {
         return Err(Error::StateIsFunded);
@@ -573,8 +1230,8 @@ pub fn verify_status_not_funded(status: &ChannelStatus) -> Result<(), Error> {
 }
 
 pub fn verify_channel_params_compatibility(params: &ChannelParameters) -> Result<(), Error> {
-    if params.app().to_opt().is_some() {
-        return Err(Error::AppChannelsNotSupported);
+    if params.parties().len() < 2 {
+        return Err(Error::NotEnoughParties);
     }
     if !params.is_ledger_channel().to_bool() {
         return Err(Error::NonLedgerChannelsNotSupported);
@@ -601,6 +1258,10 @@ pub fn verify_channel_state_progression(
 ) -> Result<(), Error> {
     verify_equal_channel_id(&old_status.state(), new_state)?;
     verify_increasing_version_number(old_status, new_state)?;
+    verify_balances_num_parties(
+        &new_state.balances(),
+        old_status.state().balances().ckbytes().len(),
+    )?;
     verify_equal_sum_of_balances(&old_status.state().balances(), &new_state.balances())?;
     verify_state_not_finalized(&old_status.state())?;
     Ok(())
@@ -630,6 +1291,7 @@ pub fn verify_channel_id_integrity(
 pub fn verify_state_valid_as_start(
     state: &ChannelState,
     pfls_min_capacity: u64,
+    num_parties: usize,
 ) -> Result<(), Error> {
     if state.version().unpack() != 0 {
         return Err(Error::StartWithNonZeroVersion);
@@ -638,168 +1300,1128 @@ pub fn verify_state_valid_as_start(
         return Err(Error::StartWithFinalizedState);
     }
 
+    // A channel can not lock funds for sub-channels or in HTLCs before it is funded.
+    verify_no_locked_funds(&state.balances())?;
+    verify_no_htlcs(&state.balances())?;
+
+    // We verify that the initial balances hold an entry for every participant.
+    verify_balances_num_parties(&state.balances(), num_parties)?;
+
     // We verify that each participant's initial balance is at least the minimum capacity of a PFLS (or zero),
     // to ensure that funding is possible for the initial balance distribution.
-    let balance_a = state.balances().ckbytes().get(0)?;
-    let balance_b = state.balances().ckbytes().get(1)?;
-    if balance_a < pfls_min_capacity && balance_a != 0 {
-        return Err(Error::BalanceBelowPFLSMinCapacity);
+    for balance in state.balances().ckbytes().into_iter() {
+        let balance: u64 = balance.unpack();
+        if balance < pfls_min_capacity && balance != 0 {
+            return Err(Error::BalanceBelowPFLSMinCapacity);
+        }
+    }
+    Ok(())
+}
+
+/// verify_balances_num_parties verifies that every distribution in the balances holds exactly one
+/// entry per participant and that every HTLC is between two of the participants.
+pub fn verify_balances_num_parties(balances: &Balances, num_parties: usize) -> Result<(), Error> {
+    if !balances.has_num_parties(num_parties) {
+        return Err(Error::PartyCountMismatch);
     }
-    if balance_b < pfls_min_capacity && balance_b != 0 {
-        return Err(Error::BalanceBelowPFLSMinCapacity);
+    if !balances.has_valid_htlcs(num_parties) {
+        return Err(Error::InvalidHTLC);
     }
     Ok(())
 }
 
-pub fn verify_valid_lock_script(channel_constants: &ChannelConstants) -> Result<(), Error> {
-    let lock_script = load_cell_lock(0, Source::GroupOutput)?;
-    if lock_script.code_hash().unpack()[..] != channel_constants.pcls_code_hash().unpack()[..] {
-        return Err(Error::InvalidPCLSCodeHash);
+/// verify_no_locked_funds verifies that the balances do not lock any funds for sub-channels.
+pub fn verify_no_locked_funds(balances: &Balances) -> Result<(), Error> {
+    if !balances.locked().is_empty() {
+        return Err(Error::LockedFundsNotAllowed);
     }
-    if !lock_script
-        .hash_type()
-        .eq(&channel_constants.pcls_hash_type())
-    {
-        return Err(Error::InvalidPCLSHashType);
+    Ok(())
+}
+
+/// verify_no_htlcs verifies that the balances do not lock any funds in HTLCs.
+pub fn verify_no_htlcs(balances: &Balances) -> Result<(), Error> {
+    if !balances.htlcs().is_empty() {
+        return Err(Error::InvalidHTLC);
     }
+    Ok(())
+}
 
-    if !lock_script.args().is_empty() {
-        return Err(Error::PCLSWithArgs);
+/// verify_no_sub_states verifies that no sub-channel states are registered in the status.
+pub fn verify_no_sub_states(status: &ChannelStatus) -> Result<(), Error> {
+    if !status.sub_states().is_empty() {
+        return Err(Error::SubStatesMismatch);
     }
     Ok(())
 }
 
-pub fn verify_status_not_disputed(status: &ChannelStatus) -> Result<(), Error> {
-    if status.disputed().to_bool() {
-        return Err(Error::StatusDisputed);
+/// verify_no_dispute_deadline verifies that no dispute deadline is recorded in the status.
+pub fn verify_no_dispute_deadline(status: &ChannelStatus) -> Result<(), Error> {
+    if status.dispute_deadline().is_some() {
+        return Err(Error::DisputeDeadlineMismatch);
     }
     Ok(())
 }
 
-pub fn verify_status_disputed(status: &ChannelStatus) -> Result<(), Error> {
-    if !status.disputed().to_bool() {
-        return Err(Error::StatusNotDisputed);
+/// verify_no_bond_holder verifies that no holder of the dispute bond is recorded in the status.
+pub fn verify_no_bond_holder(status: &ChannelStatus) -> Result<(), Error> {
+    if status.bond_holder().is_some() {
+        return Err(Error::InvalidBondHolder);
     }
     Ok(())
 }
 
-pub fn verify_all_payed(
+/// verify_dispute_bond verifies that the first dispute of a channel with a dispute bond locks the bond in
+/// the channel cell and that each dispute records the party that registered the new state as its holder.
+/// For a unilateral progression of an app channel, this is the acting party `sole_signer`. For a state
+/// signed by all parties, it is a party that authorized the transaction. This way, a refutation passes the
+/// bond to the party proving the registered state stale, while the holder keeps it when refuting its own
+/// state.
+pub fn verify_dispute_bond(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    channel_constants: &ChannelConstants,
+    sole_signer: Option<usize>,
+) -> Result<(), Error> {
+    let bond: u64 = match channel_constants.params().dispute_bond().to_opt() {
+        Some(bond) => bond.unpack(),
+        None => return verify_no_bond_holder(new_status),
+    };
+    let holder = match new_status.bond_holder().to_opt() {
+        Some(holder) => u8::from(holder) as usize,
+        None => return Err(Error::InvalidBondHolder),
+    };
+    if holder >= channel_constants.params().parties().len() {
+        return Err(Error::InvalidBondHolder);
+    }
+    let registered_by_holder = match sole_signer {
+        Some(actor) => holder == actor,
+        None => is_authorized_by(holder, &channel_constants.params())?,
+    };
+    if !registered_by_holder {
+        return Err(Error::InvalidBondHolder);
+    }
+    if old_status.bond_holder().is_none() {
+        // The first dispute locks the bond in the channel cell. Its fee may be payed from it.
+        let max_fee = remaining_fee_budget(old_status, channel_constants);
+        let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
+        let output_capacity = load_cell_capacity(0, Source::GroupOutput)?;
+        if output_capacity.saturating_add(max_fee) < input_capacity.saturating_add(bond) {
+            return Err(Error::DisputeBondNotLocked);
+        }
+    }
+    Ok(())
+}
+
+/// is_authorized_by returns whether the party at `idx` authorized the current transaction in one of the
+/// ways the pcls accepts: By an input cell locked by its unlock script or by one of its delegates, or by
+/// its signature on the transaction signing digest in the lock field of the witness of the channel cell.
+pub fn is_authorized_by(idx: usize, params: &ChannelParameters) -> Result<bool, Error> {
+    let party = params.parties().get(idx).ok_or(Error::IndexOutOfBound)?;
+    let unlock_script_hash: [u8; 32] = party.unlock_script_hash().unpack();
+    let has_input = QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash| {
+        lock_hash == unlock_script_hash
+            || party
+                .delegates()
+                .into_iter()
+                .any(|delegate| delegate.unpack()[..] == lock_hash[..])
+    });
+    if has_input {
+        return Ok(true);
+    }
+    let sig: Bytes = match load_witness_args(0, Source::GroupInput)?.lock().to_opt() {
+        Some(sig) => sig.unpack(),
+        None => return Ok(false),
+    };
+    Ok(verify_signature_with_scheme(
+        party.sig_scheme().into(),
+        &load_tx_signing_digest()?,
+        &sig,
+        party.pub_key().as_slice(),
+    )
+    .is_ok())
+}
+
+/// load_tx_signing_digest returns the digest which a participant signs to authorize the current transaction
+/// (see tx_signing_digest). It covers the witnesses of all inputs locked by the pcls, which is the lock script
+/// of the channel cell, without their lock field.
+pub fn load_tx_signing_digest() -> Result<[u8; 32], Error> {
+    let pcls_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+    let mut witnesses = Vec::new();
+    for (i, lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Input).enumerate() {
+        if lock_hash != pcls_hash {
+            continue;
+        }
+        let witness_args = load_witness_args(i, Source::Input)?
+            .as_builder()
+            .lock(BytesOpt::default())
+            .build();
+        witnesses.push(witness_args);
+    }
+    Ok(tx_signing_digest(
+        &load_tx_hash()?,
+        witnesses.iter().map(|w| w.as_slice()),
+    ))
+}
+
+/// is_sub_state_refutation returns whether the dispute refutes a disputed channel only by registering newer
+/// states of its sub-channels, while the registered state of the channel itself is kept. verify_sub_states
+/// ensures that each provided sub-channel state supersedes the registered one.
+pub fn is_sub_state_refutation(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    sub_channels: &SignedStates,
+) -> bool {
+    old_status.disputed().to_bool()
+        && !sub_channels.is_empty()
+        && old_status.state().as_slice()[..] == new_status.state().as_slice()[..]
+}
+
+/// verify_sub_states verifies that the new status registers the latest state of each sub-channel that
+/// funds are locked for in the new state, in the order of the sub-allocations. The state of a sub-channel
+/// is taken from `sub_channels`, if it is provided there, and carried over from the old status otherwise.
+/// A provided sub-channel state must be signed by all sub-channel participants and supersede the state
+/// registered so far. Each registered state must distribute exactly the funds of its sub-allocation.
+pub fn verify_sub_states(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    sub_channels: &SignedStates,
+) -> Result<(), Error> {
+    let balances = new_status.state().balances();
+    let num_parties = balances.ckbytes().len();
+    let mut expected: Vec<ChannelState> = Vec::new();
+    for sub_alloc in balances.locked().into_iter() {
+        verify_valid_sub_alloc(&sub_alloc, &balances, num_parties)?;
+        let registered = find_sub_state(&old_status.sub_states(), &sub_alloc.id());
+        let provided = sub_channels
+            .clone()
+            .into_iter()
+            .find(|s| s.state().channel_id().as_slice() == sub_alloc.id().as_slice());
+        let sub_state = match (provided, registered) {
+            (Some(signed), registered) => {
+                verify_valid_sub_channel_state(&signed, &sub_alloc, registered.as_ref())?;
+                signed.state()
+            }
+            (None, Some(registered)) => registered,
+            (None, None) => return Err(Error::SubChannelNotRegistered),
+        };
+        verify_sub_state_matches_sub_alloc(&sub_state, &sub_alloc, &balances)?;
+        expected.push(sub_state);
+    }
+    let expected = ChannelStates::new_builder().set(expected).build();
+    // Every provided sub-channel state has to belong to a sub-allocation.
+    for signed in sub_channels.clone().into_iter() {
+        if find_sub_state(&expected, &signed.state().channel_id()).is_none() {
+            return Err(Error::InvalidSubChannelState);
+        }
+    }
+    if expected.as_slice()[..] != new_status.sub_states().as_slice()[..] {
+        return Err(Error::SubStatesMismatch);
+    }
+    Ok(())
+}
+
+/// find_sub_state returns the state with the given channel id, if there is one in `sub_states`.
+pub fn find_sub_state(sub_states: &ChannelStates, id: &Byte32) -> Option<ChannelState> {
+    sub_states
+        .clone()
+        .into_iter()
+        .find(|s| s.channel_id().as_slice() == id.as_slice())
+}
+
+/// verify_valid_sub_alloc verifies that the sub-allocation holds an amount for every asset of the
+/// enclosing balances and that it maps the sub-channel participants to distinct participants of this
+/// channel.
+pub fn verify_valid_sub_alloc(
+    sub_alloc: &SubAlloc,
+    balances: &Balances,
+    num_parties: usize,
+) -> Result<(), Error> {
+    if sub_alloc.sudts().len() != balances.sudts().len() {
+        return Err(Error::InvalidSubAlloc);
+    }
+    let index_map: Vec<usize> = sub_alloc
+        .index_map()
+        .into_iter()
+        .map(|idx| u8::from(idx) as usize)
+        .collect();
+    for (i, idx) in index_map.iter().enumerate() {
+        if *idx >= num_parties || index_map[..i].contains(idx) {
+            return Err(Error::InvalidSubAlloc);
+        }
+    }
+    Ok(())
+}
+
+/// verify_valid_sub_channel_state verifies that the signed state is a valid state of a virtual channel
+/// with the id of the sub-allocation, which is signed by all of its participants. If a state of the
+/// sub-channel is already registered, the signed state must have a higher version.
+pub fn verify_valid_sub_channel_state(
+    signed: &SignedState,
+    sub_alloc: &SubAlloc,
+    registered: Option<&ChannelState>,
+) -> Result<(), Error> {
+    let params = signed.params();
+    let state = signed.state();
+    if !params.is_virtual_channel().to_bool() || params.is_ledger_channel().to_bool() {
+        return Err(Error::InvalidSubChannelState);
+    }
+    verify_channel_id_integrity(&sub_alloc.id(), &params)?;
+    if state.channel_id().as_slice()[..] != sub_alloc.id().as_slice()[..] {
+        return Err(Error::ChannelIdMismatch);
+    }
+    verify_balances_num_parties(&state.balances(), params.parties().len())?;
+    if !state.balances().locked().is_empty() || !state.balances().htlcs().is_empty() {
+        return Err(Error::InvalidSubChannelState);
+    }
+    verify_valid_state_sigs(&signed.sigs(), &state, &params)?;
+    if let Some(registered) = registered {
+        let registered_version: u64 = registered.version().unpack();
+        let version: u64 = state.version().unpack();
+        if version <= registered_version {
+            return Err(Error::SubChannelVersionNotIncreasing);
+        }
+    }
+    Ok(())
+}
+
+/// verify_sub_state_matches_sub_alloc verifies that the sub-channel state has one participant per entry
+/// in the index map of the sub-allocation and that it distributes exactly the locked funds.
+pub fn verify_sub_state_matches_sub_alloc(
+    sub_state: &ChannelState,
+    sub_alloc: &SubAlloc,
+    balances: &Balances,
+) -> Result<(), Error> {
+    let sub_balances = sub_state.balances();
+    if !sub_balances.has_num_parties(sub_alloc.index_map().len()) {
+        return Err(Error::InvalidSubChannelState);
+    }
+    if sub_balances.ckbytes().sum() != sub_alloc.ckbytes().unpack() {
+        return Err(Error::InvalidSubChannelState);
+    }
+    if sub_balances.sudts().len() != balances.sudts().len() {
+        return Err(Error::InvalidSubChannelState);
+    }
+    for (i, sub_sudt) in sub_balances.sudts().into_iter().enumerate() {
+        let sudt = balances.sudts().get(i).ok_or(Error::IndexOutOfBound)?;
+        if sub_sudt.asset().as_slice()[..] != sudt.asset().as_slice()[..] {
+            return Err(Error::InvalidSubChannelState);
+        }
+        let locked: u128 = sub_alloc
+            .sudts()
+            .get(i)
+            .ok_or(Error::IndexOutOfBound)?
+            .unpack();
+        if sub_sudt.distribution().sum() != locked {
+            return Err(Error::InvalidSubChannelState);
+        }
+    }
+    Ok(())
+}
+
+pub fn verify_valid_lock_script(channel_constants: &ChannelConstants) -> Result<(), Error> {
+    let lock_script = load_cell_lock(0, Source::GroupOutput)?;
+    if lock_script.code_hash().unpack()[..] != channel_constants.pcls_code_hash().unpack()[..] {
+        return Err(Error::InvalidPCLSCodeHash);
+    }
+    if !lock_script
+        .hash_type()
+        .eq(&channel_constants.pcls_hash_type())
+    {
+        return Err(Error::InvalidPCLSHashType);
+    }
+
+    if !lock_script.args().is_empty() {
+        return Err(Error::PCLSWithArgs);
+    }
+    Ok(())
+}
+
+pub fn verify_status_not_disputed(status: &ChannelStatus) -> Result<(), Error> {
+    if status.disputed().to_bool() {
+        return Err(Error::StatusDisputed);
+    }
+    Ok(())
+}
+
+pub fn verify_status_disputed(status: &ChannelStatus) -> Result<(), Error> {
+    if !status.disputed().to_bool() {
+        return Err(Error::StatusNotDisputed);
+    }
+    Ok(())
+}
+
+/// verify_all_payed verifies that each party is payed according to `final_balance` and its share of the
+/// channel capacity in `capacity_payouts`, and that everything that enters the transaction from the
+/// channel cell and the pfls of this channel is payed out to the parties and the sponsor, except for at
+/// most `max_fee` shannons. The fee is taken from the share of the party or sponsor that is refunded the
+/// channel capacity.
+pub fn verify_all_payed(
     final_balance: &Balances,
-    channel_capacity: u64,
+    capacity_payouts: &[u64],
+    sponsor_payout: u64,
     channel_constants: &ChannelConstants,
-    is_abort: bool,
+    funded_parties: &BoolVec,
+    max_fee: u64,
 ) -> Result<(), Error> {
     debug!("verify_all_payed");
-    debug!("is_abort: {}", is_abort);
-    let minimum_payment_a = channel_constants
-        .params()
-        .party_a()
-        .payment_min_capacity()
-        .unpack();
-    let minimum_payment_b: u64 = channel_constants
-        .params()
-        .party_b()
-        .payment_min_capacity()
-        .unpack();
+    let parties = channel_constants.params().parties();
+    let num_parties = parties.len();
+
+    // Every party that funded the channel has locked the max_capacity of each sudt asset in addition
+    // to its CKByte balance. These CKBytes are reimbursed to the party.
+    let reimburse = final_balance.sudts().get_locked_ckbytes();
+    let mut ckbytes_balances = vec![0u64; num_parties];
+    for (i, ckbytes_balance) in ckbytes_balances.iter_mut().enumerate() {
+        *ckbytes_balance = final_balance.ckbytes().get_amount(i)?;
+        if funded_parties.get_bool(i)? {
+            *ckbytes_balance += reimburse;
+        }
+    }
+    for (ckbytes_balance, payout) in ckbytes_balances.iter_mut().zip(capacity_payouts) {
+        *ckbytes_balance += payout;
+    }
+    // The dust policy of the channel decides who receives the balances below the payment_min_capacity of
+    // their party, so that they are not left to whoever builds the transaction.
+    let refund_party = channel_constants.refund_party();
+    let capacity_share = match refund_party {
+        Some(r) => capacity_payouts[r],
+        None => sponsor_payout,
+    };
+    let rounded_up = apply_dust_policy(&mut ckbytes_balances, capacity_share, channel_constants)?;
+    debug!("ckbytes_balances: {:?}", ckbytes_balances);
+
+    let (ckbytes_outputs, udt_outputs) = get_payments(final_balance, channel_constants)?;
+    debug!("ckbytes_outputs: {:?}", ckbytes_outputs);
+    debug!("udt_outputs: {:?}", udt_outputs);
+
+    // The fee can only be payed from the channel capacity, never from the balances of the parties.
+    let fee_allowance = max_fee.min(capacity_share - rounded_up);
+    let mut unpayed = 0u64;
+    for (i, party) in parties.into_iter().enumerate() {
+        // Parties with balances below the minimum capacity of the payment script after applying the
+        // dust policy are not required to be payed.
+        let minimum_payment: u64 = party.payment_min_capacity().unpack();
+        let allowance = if Some(i) == refund_party {
+            fee_allowance
+        } else {
+            0
+        };
+        if ckbytes_balances[i] > ckbytes_outputs[i] + allowance
+            && ckbytes_balances[i] >= minimum_payment
+        {
+            return Err(Error::NotAllPayed);
+        }
+        if ckbytes_balances[i] < minimum_payment {
+            unpayed += ckbytes_balances[i].saturating_sub(ckbytes_outputs[i]);
+        }
+        if !final_balance
+            .sudts()
+            .fully_represented(i, &udt_outputs[i])?
+        {
+            return Err(Error::NotAllPayed);
+        }
+    }
+
+    // A sponsor is refunded the channel capacity, except for the fee and the dust rounded up from it.
+    let sponsor_payed = verify_sponsor_refunded(
+        sponsor_payout.saturating_sub(rounded_up),
+        fee_allowance,
+        channel_constants,
+    )?;
 
-    let reimburse_a = final_balance.sudts().get_locked_ckbytes();
-    let mut reimburse_b = 0u64;
-    if !is_abort {
-        reimburse_b = reimburse_a;
+    // Exact accounting: Everything that enters from the channel cell and the pfls has to go out to the
+    // parties and the sponsor, except for the fee and the balances that are not required to be payed.
+    let (pfls_capacity, _) = get_pfls_cells(final_balance, channel_constants, Source::Input)?;
+    let channel_capacity: u64 = capacity_payouts.iter().sum::<u64>() + sponsor_payout;
+    let payed: u64 = ckbytes_outputs.iter().sum::<u64>() + sponsor_payed;
+    if (pfls_capacity + channel_capacity).saturating_sub(payed) > max_fee + unpayed {
+        return Err(Error::MaxFeeExceeded);
     }
+    Ok(())
+}
 
-    let ckbytes_balance_a = final_balance.ckbytes().get(0)? + channel_capacity + reimburse_a;
-    let payment_script_hash_a = channel_constants
-        .params()
-        .party_a()
-        .payment_script_hash()
+/// get_pfls_cells returns the sum of the capacities and the sum of the amount of each sudt asset of
+/// all cells in `source` locked by the pfls of this channel. The sudt amounts are in the order of the
+/// sudts in `balances`. Cells locked by the pfls of other channels are ignored, so that several
+/// channels can be funded or closed in the same transaction.
+pub fn get_pfls_cells(
+    balances: &Balances,
+    channel_constants: &ChannelConstants,
+    source: Source,
+) -> Result<(u64, Vec<u128>), Error> {
+    let mut udt_sum = vec![0u128; balances.sudts().len()];
+
+    let expected_pcts_script_hash = load_script_hash()?;
+    let expected_pfls_code_hash = channel_constants.pfls_code_hash().unpack();
+    let expected_pfls_hash_type = channel_constants.pfls_hash_type();
+    let mut capacity_sum: u64 = 0;
+    for (i, output) in QueryIter::new(load_cell, source).enumerate() {
+        if output.lock().code_hash().unpack()[..] == expected_pfls_code_hash[..]
+            && output.lock().hash_type().eq(&expected_pfls_hash_type)
+        {
+            let output_lock_args: Bytes = output.lock().args().unpack();
+            let script_hash_in_pfls_args = Byte32::from_slice(&output_lock_args)?.unpack();
+            if script_hash_in_pfls_args[..] != expected_pcts_script_hash[..] {
+                continue;
+            }
+            capacity_sum += output.capacity().unpack();
+            if output.type_().is_some() {
+                let (sudt_idx, amount) = get_sudt_amout(
+                    balances,
+                    i,
+                    &output.type_().to_opt().expect("checked above"),
+                    source,
+                )?;
+                udt_sum[sudt_idx] += amount;
+            }
+        }
+    }
+    Ok((capacity_sum, udt_sum))
+}
+
+/// verify_valid_deposit verifies that the total funds of the new state exceed those of the old state.
+/// The distribution among the participants may differ, as the channel may have been progressed off-chain
+/// since the old state was recorded. The new state is signed by all participants, who thereby agree on
+/// how the deposited funds are credited. The assets and the funds locked for sub-channels and in HTLCs must not change
+/// and something must be deposited. It returns the total deposited CKBytes and the total deposited amount
+/// of each sudt asset.
+pub fn verify_valid_deposit(
+    old_balances: &Balances,
+    new_balances: &Balances,
+) -> Result<(u64, Vec<u128>), Error> {
+    if old_balances.locked().as_slice()[..] != new_balances.locked().as_slice()[..]
+        || old_balances.htlcs().as_slice()[..] != new_balances.htlcs().as_slice()[..]
+        || old_balances.sudts().len() != new_balances.sudts().len()
+    {
+        return Err(Error::InvalidDeposit);
+    }
+    let ckbytes_deposit = new_balances
+        .total_ckbytes()
+        .checked_sub(old_balances.total_ckbytes())
+        .ok_or(Error::DecreasingAmount)?;
+    let mut udt_deposit = vec![0u128; new_balances.sudts().len()];
+    for (i, (old, new)) in old_balances
+        .sudts()
+        .into_iter()
+        .zip(new_balances.sudts().into_iter())
+        .enumerate()
+    {
+        if old.asset().as_slice()[..] != new.asset().as_slice()[..] {
+            return Err(Error::InvalidDeposit);
+        }
+        udt_deposit[i] = new
+            .distribution()
+            .sum()
+            .checked_sub(old.distribution().sum())
+            .ok_or(Error::DecreasingAmount)?;
+    }
+    if ckbytes_deposit == 0 && udt_deposit.iter().all(|amount| *amount == 0) {
+        return Err(Error::InvalidDeposit);
+    }
+    Ok((ckbytes_deposit, udt_deposit))
+}
+
+/// verify_valid_cooperative_update verifies the parts of a deposit or withdrawal that do not concern the
+/// moved funds: The channel is funded and not disputed, the funding information and the channel id do
+/// not change, neither state is final, the version increases and the balances hold an entry for
+/// every participant.
+pub fn verify_valid_cooperative_update(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    verify_status_funded(old_status)?;
+    verify_status_not_disputed(old_status)?;
+    verify_status_not_disputed(new_status)?;
+    verify_funding_unchanged(old_status, new_status)?;
+    verify_no_sub_states(new_status)?;
+    verify_no_dispute_deadline(new_status)?;
+    verify_no_bond_holder(new_status)?;
+    verify_equal_channel_id(&old_status.state(), &new_status.state())?;
+    verify_state_not_finalized(&old_status.state())?;
+    verify_state_not_finalized(&new_status.state())?;
+    verify_strictly_increasing_version_number(&old_status.state(), &new_status.state())?;
+    verify_balances_num_parties(
+        &new_status.state().balances(),
+        channel_constants.params().parties().len(),
+    )?;
+    verify_valid_htlc_timeouts(&new_status.state().balances(), &channel_constants.params())?;
+    Ok(())
+}
+
+/// verify_valid_withdrawal verifies that the new state directly succeeds the latest off-chain state
+/// `prev_state` and only deducts withdrawn funds from the participants. `prev_state` has to hold the same
+/// funds as the old state. No balance may increase from `prev_state` to the new state, the assets and the
+/// funds locked for sub-channels and in HTLCs must not change and at least one party must withdraw something. It
+/// returns the CKBytes and the amount of each sudt asset withdrawn by each party.
+pub fn verify_valid_withdrawal(
+    old_state: &ChannelState,
+    prev_state: &ChannelState,
+    new_state: &ChannelState,
+) -> Result<(Vec<u64>, Vec<Vec<u128>>), Error> {
+    verify_equal_channel_id(old_state, prev_state)?;
+    verify_state_not_finalized(prev_state)?;
+    let prev_version: u64 = prev_state.version().unpack();
+    if prev_version.checked_add(1) != Some(new_state.version().unpack()) {
+        return Err(Error::InvalidWithdrawal);
+    }
+    let prev_balances = prev_state.balances();
+    let new_balances = new_state.balances();
+    verify_balances_num_parties(&prev_balances, new_balances.ckbytes().len())?;
+    verify_equal_sum_of_balances(&old_state.balances(), &prev_balances)?;
+    if prev_balances.locked().as_slice()[..] != new_balances.locked().as_slice()[..]
+        || prev_balances.htlcs().as_slice()[..] != new_balances.htlcs().as_slice()[..]
+        || prev_balances.sudts().len() != new_balances.sudts().len()
+    {
+        return Err(Error::InvalidWithdrawal);
+    }
+    let mut ckbytes_withdrawals: Vec<u64> = Vec::new();
+    for (prev, new) in prev_balances
+        .ckbytes()
+        .to_vec()
+        .iter()
+        .zip(new_balances.ckbytes().to_vec().iter())
+    {
+        ckbytes_withdrawals.push(prev.checked_sub(*new).ok_or(Error::InvalidWithdrawal)?);
+    }
+    let mut udt_withdrawals =
+        vec![vec![0u128; new_balances.sudts().len()]; ckbytes_withdrawals.len()];
+    for (i, (prev, new)) in prev_balances
+        .sudts()
+        .into_iter()
+        .zip(new_balances.sudts().into_iter())
+        .enumerate()
+    {
+        if prev.asset().as_slice()[..] != new.asset().as_slice()[..] {
+            return Err(Error::InvalidWithdrawal);
+        }
+        for (party_idx, (prev_amount, new_amount)) in prev
+            .distribution()
+            .to_vec()
+            .iter()
+            .zip(new.distribution().to_vec().iter())
+            .enumerate()
+        {
+            udt_withdrawals[party_idx][i] = prev_amount
+                .checked_sub(*new_amount)
+                .ok_or(Error::InvalidWithdrawal)?;
+        }
+    }
+    if ckbytes_withdrawals.iter().all(|amount| *amount == 0)
+        && udt_withdrawals.iter().flatten().all(|amount| *amount == 0)
+    {
+        return Err(Error::InvalidWithdrawal);
+    }
+    Ok((ckbytes_withdrawals, udt_withdrawals))
+}
+
+/// verify_withdrawal_in_outputs verifies that the funds locked by the pfls of this channel decrease by
+/// exactly the withdrawn funds and that each party is payed at least its withdrawn funds.
+pub fn verify_withdrawal_in_outputs(
+    new_balances: &Balances,
+    ckbytes_withdrawals: &[u64],
+    udt_withdrawals: &[Vec<u128>],
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    let (capacity_in, udt_in) = get_pfls_cells(new_balances, channel_constants, Source::Input)?;
+    let (capacity_out, udt_out) = get_pfls_cells(new_balances, channel_constants, Source::Output)?;
+    let ckbytes_withdrawn: u64 = ckbytes_withdrawals.iter().sum();
+    if capacity_in.checked_sub(capacity_out) != Some(ckbytes_withdrawn) {
+        debug!(
+            "verify_withdrawal_in_outputs: capacity_in: {}, capacity_out: {}, withdrawn: {}",
+            capacity_in, capacity_out, ckbytes_withdrawn
+        );
+        return Err(Error::WithdrawalMismatch);
+    }
+    for (i, (amount_in, amount_out)) in udt_in.iter().zip(udt_out.iter()).enumerate() {
+        let withdrawn: u128 = udt_withdrawals.iter().map(|w| w[i]).sum();
+        if amount_in.checked_sub(*amount_out) != Some(withdrawn) {
+            return Err(Error::WithdrawalMismatch);
+        }
+    }
+    let (ckbytes_payed, udt_payed) = get_payments(new_balances, channel_constants)?;
+    for (i, withdrawal) in ckbytes_withdrawals.iter().enumerate() {
+        if ckbytes_payed[i] < *withdrawal || udt_payed[i][..] != udt_withdrawals[i][..] {
+            return Err(Error::WithdrawalMismatch);
+        }
+    }
+    Ok(())
+}
+
+/// verify_successor_started verifies that the successor channel with the given pcts script hash is
+/// started in the outputs of the transaction. The successor has to run the same pcts code as this
+/// channel and has to be fully funded. Its initial balances must credit each party at least its final
+/// balances in this channel, so that the parties can add funds to the successor in the same transaction.
+/// The pcts of the successor verifies that its funds are locked to its pfls.
+pub fn verify_successor_started(
+    final_balances: &Balances,
+    successor: &Byte32,
+) -> Result<(), Error> {
+    let own_script = load_script()?;
+    let mut successor_status: Option<ChannelStatus> = None;
+    for (i, type_hash) in QueryIter::new(load_cell_type_hash, Source::Output).enumerate() {
+        if type_hash.map_or(true, |h| h[..] != successor.unpack()[..]) {
+            continue;
+        }
+        let type_script = load_cell_type(i, Source::Output)?.expect("type hash is set");
+        if type_script.code_hash().as_slice()[..] != own_script.code_hash().as_slice()[..]
+            || type_script.hash_type().as_slice()[..] != own_script.hash_type().as_slice()[..]
+        {
+            return Err(Error::InvalidSuccessor);
+        }
+        let data = load_cell_data(i, Source::Output)?;
+        successor_status = Some(ChannelStatus::from_slice(&data)?);
+        break;
+    }
+    let successor_status = successor_status.ok_or(Error::SuccessorNotInOutputs)?;
+    if !successor_status.funded().to_bool() {
+        return Err(Error::InvalidSuccessor);
+    }
+    let successor_balances = successor_status.state().balances();
+    if !geq_components(&successor_balances.ckbytes(), &final_balances.ckbytes())
+        || successor_balances.sudts().len() != final_balances.sudts().len()
+    {
+        return Err(Error::InvalidSuccessor);
+    }
+    for (final_sudt, successor_sudt) in final_balances
+        .sudts()
+        .into_iter()
+        .zip(successor_balances.sudts().into_iter())
+    {
+        if final_sudt.asset().as_slice()[..] != successor_sudt.asset().as_slice()[..]
+            || final_sudt.distribution().len() != successor_sudt.distribution().len()
+        {
+            return Err(Error::InvalidSuccessor);
+        }
+        for (final_amount, successor_amount) in final_sudt
+            .distribution()
+            .to_vec()
+            .iter()
+            .zip(successor_sudt.distribution().to_vec().iter())
+        {
+            if successor_amount < final_amount {
+                return Err(Error::InvalidSuccessor);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// get_rotated_successor returns the pcts script hash, the channel constants and the status of the channel in
+/// the outputs of the transaction, which succeeds the channel with the given id in a rotation. The successor has
+/// to run the same pcts code as this channel, its thread token has to be the outpoint of the channel cell of this
+/// channel and its channel cell has to hold at least the capacity of the channel cell of this channel, which is
+/// refunded to the party or sponsor recorded as capacity_refund upon close.
+pub fn get_rotated_successor(
+    channel_id: &Byte32,
+    channel_capacity: u64,
+) -> Result<([u8; 32], ChannelConstants, ChannelStatus), Error> {
+    let own_script = load_script()?;
+    let own_out_point = load_input_out_point(0, Source::GroupInput)?;
+    for (i, type_script) in QueryIter::new(load_cell_type, Source::Output).enumerate() {
+        let type_script = match type_script {
+            Some(type_script) => type_script,
+            None => continue,
+        };
+        if type_script.code_hash().as_slice()[..] != own_script.code_hash().as_slice()[..]
+            || type_script.hash_type().as_slice()[..] != own_script.hash_type().as_slice()[..]
+        {
+            continue;
+        }
+        let args: Bytes = type_script.args().unpack();
+        let constants = ChannelConstants::from_slice(&args)?;
+        match constants.predecessor().to_opt() {
+            Some(predecessor) if predecessor.as_slice()[..] == channel_id.as_slice()[..] => {}
+            _ => continue,
+        }
+        if constants.thread_token().out_point().as_slice()[..] != own_out_point.as_slice()[..]
+            || load_cell_capacity(i, Source::Output)? < channel_capacity
+        {
+            return Err(Error::InvalidRotation);
+        }
+        let data = load_cell_data(i, Source::Output)?;
+        let status = ChannelStatus::from_slice(&data)?;
+        let type_hash = load_cell_type_hash(i, Source::Output)?.expect("type script is set");
+        return Ok((type_hash, constants, status));
+    }
+    Err(Error::SuccessorNotInOutputs)
+}
+
+/// verify_valid_rotation verifies that the channel constants of the successor of a rotation equal the channel
+/// constants of this channel, apart from the thread token, the predecessor and the keys of the participants. Each
+/// participant may replace its unlock_script_hash, pub_key, delegates and sig_scheme, but neither its payment
+/// script hash nor its payment min capacity, as these determine the payouts of the channel.
+pub fn verify_valid_rotation(
+    channel_constants: &ChannelConstants,
+    successor_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    let params = channel_constants.params();
+    let successor_params = successor_constants.params();
+    let parties = params.parties();
+    let successor_parties = successor_params.parties();
+    if parties.len() != successor_parties.len() {
+        return Err(Error::InvalidRotation);
+    }
+    for (party, successor_party) in parties.clone().into_iter().zip(successor_parties) {
+        if rotated_party(&party, &successor_party).as_slice()[..] != successor_party.as_slice()[..]
+        {
+            return Err(Error::InvalidRotation);
+        }
+    }
+    let expected_params = successor_params.as_builder().parties(parties).build();
+    let expected_constants = successor_constants
+        .clone()
+        .as_builder()
+        .params(expected_params)
+        .thread_token(channel_constants.thread_token())
+        .predecessor(channel_constants.predecessor())
+        .build();
+    if expected_constants.as_slice()[..] != channel_constants.as_slice()[..] {
+        return Err(Error::InvalidRotation);
+    }
+    Ok(())
+}
+
+/// rotated_party returns `party` with the keys of `successor`.
+fn rotated_party(party: &Participant, successor: &Participant) -> Participant {
+    party
+        .clone()
+        .as_builder()
+        .unlock_script_hash(successor.unlock_script_hash())
+        .pub_key(successor.pub_key())
+        .delegates(successor.delegates())
+        .sig_scheme(successor.sig_scheme())
+        .build()
+}
+
+/// verify_predecessor_rotated verifies that the input consumed by the thread token is the channel cell of the
+/// predecessor with the given channel id and that it is consumed with a Rotate witness.
+pub fn verify_predecessor_rotated(
+    predecessor: &Byte32,
+    thread_token: &ChannelToken,
+) -> Result<(), Error> {
+    let own_script = load_script()?;
+    let inputs = load_transaction()?.raw().inputs();
+    let idx = inputs
+        .into_iter()
+        .position(|input| {
+            input.previous_output().as_slice()[..] == thread_token.out_point().as_slice()[..]
+        })
+        .ok_or(Error::InvalidThreadToken)?;
+    let type_script = load_cell_type(idx, Source::Input)?.ok_or(Error::InvalidPredecessor)?;
+    if type_script.code_hash().as_slice()[..] != own_script.code_hash().as_slice()[..]
+        || type_script.hash_type().as_slice()[..] != own_script.hash_type().as_slice()[..]
+    {
+        return Err(Error::InvalidPredecessor);
+    }
+    let status = ChannelStatus::from_slice(&load_cell_data(idx, Source::Input)?)?;
+    if status.state().channel_id().as_slice()[..] != predecessor.as_slice()[..] {
+        return Err(Error::InvalidPredecessor);
+    }
+    let witness: Bytes = load_witness_args(idx, Source::Input)?
+        .input_type()
+        .to_opt()
+        .ok_or(Error::NoWitness)?
         .unpack();
+    match ChannelWitness::from_slice(&witness)?.to_enum() {
+        ChannelWitnessUnion::Rotate(_) => Ok(()),
+        _ => Err(Error::InvalidPredecessor),
+    }
+}
 
-    let ckbytes_balance_b = final_balance.ckbytes().get(1)? + reimburse_b;
-    let payment_script_hash_b = channel_constants
+/// verify_valid_dust_policy verifies that the dust policy of the channel is one of the known policies.
+pub fn verify_valid_dust_policy(params: &ChannelParameters) -> Result<(), Error> {
+    match u8::from(params.dust_policy()) {
+        DUST_POLICY_FEE | DUST_POLICY_COUNTERPARTY | DUST_POLICY_ROUND_UP => Ok(()),
+        _ => Err(Error::InvalidDustPolicy),
+    }
+}
+
+/// verify_valid_sudt_allocation verifies that the allocation holds at most `max_sudt_assets` assets, that no
+/// asset appears twice and that the max_capacity of each asset covers the occupied capacity of an SUDT cell
+/// with its type script and the largest payment_min_capacity of the parties.
+pub fn verify_valid_sudt_allocation(
+    sudts: &SUDTAllocation,
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    let max_sudt_assets: u8 = channel_constants.max_sudt_assets().into();
+    if sudts.len() > max_sudt_assets as usize {
+        return Err(Error::TooManySUDTAssets);
+    }
+    let max_payment_min_capacity = channel_constants
         .params()
-        .party_b()
-        .payment_script_hash()
-        .unpack();
+        .parties()
+        .into_iter()
+        .map(|party| Unpack::<u64>::unpack(&party.payment_min_capacity()))
+        .max()
+        .unwrap_or_default();
+    for (i, sb) in sudts.clone().into_iter().enumerate() {
+        let type_script = sb.asset().type_script();
+        if sudts
+            .clone()
+            .into_iter()
+            .skip(i + 1)
+            .any(|other| other.asset().type_script().as_slice() == type_script.as_slice())
+        {
+            return Err(Error::DuplicateSUDTAsset);
+        }
+        // The type script occupies its code hash, hash type and args.
+        let occupied_bytes = 32 + 1 + type_script.args().raw_data().len() + SUDT_MIN_LEN;
+        let min_capacity = (occupied_bytes as u64)
+            .saturating_mul(SHANNONS_PER_BYTE)
+            .saturating_add(max_payment_min_capacity);
+        let max_capacity: u64 = sb.asset().max_capacity().unpack();
+        if max_capacity < min_capacity {
+            return Err(Error::SUDTMaxCapacityTooSmall);
+        }
+    }
+    Ok(())
+}
+
+/// verify_channel_capacity_payed verifies that each party is payed its share of the channel capacity in
+/// `capacity_payouts` and the sponsor its share in `sponsor_payout`.
+pub fn verify_channel_capacity_payed(
+    balances: &Balances,
+    capacity_payouts: &[u64],
+    sponsor_payout: u64,
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    verify_sponsor_refunded(sponsor_payout, 0, channel_constants)?;
+    let (ckbytes_outputs, _) = get_payments(balances, channel_constants)?;
+    for (i, party) in channel_constants.params().parties().into_iter().enumerate() {
+        let minimum_payment: u64 = party.payment_min_capacity().unpack();
+        if ckbytes_outputs[i] < capacity_payouts[i] && capacity_payouts[i] >= minimum_payment {
+            return Err(Error::NotAllPayed);
+        }
+    }
+    Ok(())
+}
+
+/// get_channel_capacity_payouts returns the share of each party and the share of the sponsor in the
+/// channel capacity. The channel capacity is payed back to the party or sponsor recorded in the channel
+/// constants, which provided it upon channel start, except for the dispute bond, which is payed to its
+/// holder.
+pub fn get_channel_capacity_payouts(
+    status: &ChannelStatus,
+    channel_capacity: u64,
+    channel_constants: &ChannelConstants,
+) -> Result<(Vec<u64>, u64), Error> {
+    let mut payouts = vec![0u64; channel_constants.params().parties().len()];
+    if payouts.is_empty() {
+        return Err(Error::NotEnoughParties);
+    }
+    let mut refund = channel_capacity;
+    if let (Some(holder), Some(bond)) = (
+        status.bond_holder().to_opt(),
+        channel_constants.params().dispute_bond().to_opt(),
+    ) {
+        let bond: u64 = bond.unpack();
+        let bond = bond.min(channel_capacity);
+        refund -= bond;
+        *payouts
+            .get_mut(u8::from(holder) as usize)
+            .ok_or(Error::InvalidBondHolder)? += bond;
+    }
+    match channel_constants.refund_party() {
+        Some(r) => {
+            *payouts.get_mut(r).ok_or(Error::InvalidCapacityRefund)? += refund;
+            Ok((payouts, 0))
+        }
+        None => Ok((payouts, refund)),
+    }
+}
+
+/// verify_sponsor_refunded verifies that the sponsor recorded in the channel constants, if any, is payed
+/// at least `refund` less `allowance` and returns the amount payed to it.
+pub fn verify_sponsor_refunded(
+    refund: u64,
+    allowance: u64,
+    channel_constants: &ChannelConstants,
+) -> Result<u64, Error> {
+    let sponsor = match channel_constants.capacity_refund().to_enum() {
+        CapacityRefundUnion::RefundSponsor(sponsor) => sponsor,
+        CapacityRefundUnion::RefundParty(_) => return Ok(0),
+    };
+    let payed = get_sponsor_payment(sponsor.as_slice())?;
+    if payed + allowance < refund {
+        return Err(Error::NotAllPayed);
+    }
+    Ok(payed)
+}
+
+/// get_sponsor_payment returns the sum of the capacities of all outputs without type script, which are
+/// locked by the lock script with hash `sponsor` and attributed to this channel.
+pub fn get_sponsor_payment(sponsor: &[u8]) -> Result<u64, Error> {
+    let own_script_hash = load_script_hash()?;
+    let single_channel = count_channels_in_inputs()? == 1;
+    let mut payed = 0u64;
+    for (i, output) in QueryIter::new(load_cell, Source::Output).enumerate() {
+        if output.type_().is_some() || load_cell_lock_hash(i, Source::Output)?[..] != sponsor[..] {
+            continue;
+        }
+        let data = load_cell_data(i, Source::Output)?;
+        if is_payment_of_channel(&data, &own_script_hash, single_channel) {
+            payed += Unpack::<u64>::unpack(&output.capacity());
+        }
+    }
+    Ok(payed)
+}
 
-    debug!("ckbytes_balance_a: {}", ckbytes_balance_a);
-    debug!("ckbytes_balance_b: {}", ckbytes_balance_b);
+/// verify_valid_capacity_refund verifies that the channel capacity is refunded to a party of the channel
+/// or to a sponsor whose lock script hash is not the payment address of a party, as the payments to the
+/// sponsor could not be told apart from the payments to that party otherwise.
+pub fn verify_valid_capacity_refund(channel_constants: &ChannelConstants) -> Result<(), Error> {
+    let parties = channel_constants.params().parties();
+    match channel_constants.capacity_refund().to_enum() {
+        CapacityRefundUnion::RefundParty(party) => {
+            if u8::from(party.nth0()) as usize >= parties.len() {
+                return Err(Error::InvalidCapacityRefund);
+            }
+        }
+        CapacityRefundUnion::RefundSponsor(sponsor) => {
+            if parties
+                .into_iter()
+                .any(|party| party.payment_script_hash().as_slice() == sponsor.as_slice())
+            {
+                return Err(Error::InvalidCapacityRefund);
+            }
+        }
+    }
+    Ok(())
+}
 
-    let mut ckbytes_outputs_a = 0;
-    let mut ckbytes_outputs_b = 0;
+/// verify_deposit_in_outputs verifies that exactly the deposited funds are locked to the pfls of this
+/// channel in the outputs of the transaction.
+pub fn verify_deposit_in_outputs(
+    new_balances: &Balances,
+    ckbytes_deposit: u64,
+    udt_deposit: &[u128],
+    channel_constants: &ChannelConstants,
+) -> Result<(), Error> {
+    let (capacity_sum, udt_sum) = get_pfls_cells(new_balances, channel_constants, Source::Output)?;
+    if capacity_sum != ckbytes_deposit || udt_sum[..] != udt_deposit[..] {
+        debug!(
+            "verify_deposit_in_outputs: capacity_sum: {}, ckbytes_deposit: {}",
+            capacity_sum, ckbytes_deposit
+        );
+        return Err(Error::DepositNotInOutputs);
+    }
+    Ok(())
+}
 
-    let mut udt_outputs_a =
-        vec![0u128; final_balance.sudts().len().try_into().unwrap()].into_boxed_slice();
-    let mut udt_outputs_b =
-        vec![0u128; final_balance.sudts().len().try_into().unwrap()].into_boxed_slice();
+/// get_payments returns the CKBytes and the amount of each sudt asset payed to each party in the
+/// outputs of the transaction, in the order of the parties in the channel parameters. Only payments
+/// attributed to this channel are taken into account (see is_payment_of_channel).
+pub fn get_payments(
+    balances: &Balances,
+    channel_constants: &ChannelConstants,
+) -> Result<(Vec<u64>, Vec<Vec<u128>>), Error> {
+    let parties = channel_constants.params().parties();
+    let num_parties = parties.len();
+    let mut ckbytes_outputs = vec![0u64; num_parties];
+    let mut udt_outputs = vec![vec![0u128; balances.sudts().len()]; num_parties];
 
     let outputs = load_transaction()?.raw().outputs();
+    let own_script_hash = load_script_hash()?;
+    let single_channel = count_channels_in_inputs()? == 1;
 
-    // Note: Currently it is allowed to pay out a party's CKBytes in the capacity field of an
-    // output, that is used as SUDT payment.
+    // Note: It is allowed to pay out a party's CKBytes in the capacity field of an output, that is
+    // used as SUDT payment.
     for (i, output) in outputs.into_iter().enumerate() {
         let output_lock_script_hash = load_cell_lock_hash(i, Source::Output)?;
 
-        if output_lock_script_hash[..] == payment_script_hash_a[..] {
-            if output.type_().is_some() {
-                let (sudt_idx, amount) = get_sudt_amout(
-                    final_balance,
-                    i,
-                    &output.type_().to_opt().expect("checked above"),
-                )?;
-                udt_outputs_a[sudt_idx] += amount;
+        for (party_idx, party) in parties.clone().into_iter().enumerate() {
+            if output_lock_script_hash[..] != party.payment_script_hash().unpack()[..] {
+                continue;
+            }
+            let data = load_cell_data(i, Source::Output)?;
+            let tag = match output.type_().is_some() {
+                true => data.get(SUDT_MIN_LEN..).unwrap_or_default(),
+                false => &data[..],
+            };
+            if !is_payment_of_channel(tag, &own_script_hash, single_channel) {
+                break;
             }
-            ckbytes_outputs_a += output.capacity().unpack();
-        }
-        if output_lock_script_hash[..] == payment_script_hash_b[..] {
             if output.type_().is_some() {
                 let (sudt_idx, amount) = get_sudt_amout(
-                    final_balance,
+                    balances,
                     i,
                     &output.type_().to_opt().expect("checked above"),
+                    Source::Output,
                 )?;
-                udt_outputs_b[sudt_idx] += amount;
+                udt_outputs[party_idx][sudt_idx] += amount;
+                // Every party that funded the channel has locked the max_capacity of each sudt asset,
+                // which is reimbursed with its sudt payment. If the sudt payment carried less, the
+                // difference could be taken from a party that is not required to be payed its CKBytes,
+                // e.g. if the max_capacity is below its payment_min_capacity.
+                verify_sudt_payment_capacity(balances, sudt_idx, &output)?;
             }
-            ckbytes_outputs_b += output.capacity().unpack();
+            ckbytes_outputs[party_idx] += output.capacity().unpack();
+            // Payment addresses are unique among the parties (see verify_different_payment_addresses).
+            break;
         }
     }
-    debug!("ckbytes_outputs_a: {}", ckbytes_outputs_a);
-    debug!("ckbytes_outputs_b: {}", ckbytes_outputs_b);
+    Ok((ckbytes_outputs, udt_outputs))
+}
 
-    // Parties with balances below the minimum capacity of the payment script
-    // are not required to be payed.
-    if (ckbytes_balance_a > ckbytes_outputs_a && ckbytes_balance_a >= minimum_payment_a)
-        || (ckbytes_balance_b > ckbytes_outputs_b && ckbytes_balance_b >= minimum_payment_b)
-    {
-        return Err(Error::NotAllPayed);
+/// is_payment_of_channel returns whether a payment output with the given tag is attributed to the
+/// channel with the pcts script hash `own_script_hash`. The tag is the data of a CKBytes payment
+/// and the data following the amount of a sudt payment. A payment tagged with the pcts script hash
+/// of a channel is attributed to that channel. Untagged payments are only attributed to a channel,
+/// if it is the only channel consumed by the transaction. Otherwise, the same output could be
+/// counted as payment by several channels, which interact in the same transaction.
+pub fn is_payment_of_channel(tag: &[u8], own_script_hash: &[u8; 32], single_channel: bool) -> bool {
+    match tag.is_empty() {
+        true => single_channel,
+        false => tag[..] == own_script_hash[..],
     }
+}
 
-    debug!("udt_outputs_a: {:?}", udt_outputs_a);
-    debug!("udt_outputs_b: {:?}", udt_outputs_b);
+/// count_channels_in_inputs returns the number of channel cells in the inputs of the transaction,
+/// i.e. the number of input cells with a type script running the same code as this pcts.
+pub fn count_channels_in_inputs() -> Result<usize, Error> {
+    let own_script = load_script()?;
+    let count = QueryIter::new(load_cell_type, Source::Input)
+        .flatten()
+        .filter(|type_script| {
+            type_script.code_hash().as_slice()[..] == own_script.code_hash().as_slice()[..]
+                && type_script.hash_type().as_slice()[..] == own_script.hash_type().as_slice()[..]
+        })
+        .count();
+    Ok(count)
+}
 
-    if !final_balance.sudts().fully_represented(0, &udt_outputs_a)? {
-        return Err(Error::NotAllPayed);
-    }
-    if !final_balance.sudts().fully_represented(1, &udt_outputs_b)? {
-        return Err(Error::NotAllPayed);
+/// verify_sudt_payment_capacity verifies that the sudt payment `output` carries at least the
+/// max_capacity of the sudt asset at `sudt_idx` in `balances`.
+pub fn verify_sudt_payment_capacity(
+    balances: &Balances,
+    sudt_idx: usize,
+    output: &CellOutput,
+) -> Result<(), Error> {
+    let max_capacity: u64 = balances
+        .sudts()
+        .get(sudt_idx)
+        .ok_or(Error::IndexOutOfBound)?
+        .asset()
+        .max_capacity()
+        .unpack();
+    let capacity: u64 = output.capacity().unpack();
+    if capacity < max_capacity {
+        return Err(Error::SUDTPayoutBelowMaxCapacity);
     }
     Ok(())
 }
 
-// TODO: We might want to verify that the capacity of the sudt output is at least the max_capacity of the SUDT asset.
-//      Not doing so may result in the ability to steal funds up to the
-//      (max_capacity of the SUDT asset - actual occupied capacity of the SUDT type script), if the SUDT asset's max_capacity
-//      is smaller than the payment_min_capacity of the participant. We do not do this for now, because it is an extreme edge case
-//      and the max_capacity of an SUDT should never be set that low.
 pub fn get_sudt_amout(
     balances: &Balances,
-    output_idx: usize,
+    idx: usize,
     type_script: &Script,
+    source: Source,
 ) -> Result<(usize, u128), Error> {
     let mut buf = [0u8; SUDT_MIN_LEN];
 
     let (sudt_idx, _) = balances.sudts().get_distribution(type_script)?;
-    let sudt_data = load_cell_data(output_idx, Source::Output)?;
+    let sudt_data = load_cell_data(idx, source)?;
     if sudt_data.len() < SUDT_MIN_LEN {
         return Err(Error::InvalidSUDTDataLength);
     }
@@ -807,30 +2429,248 @@ pub fn get_sudt_amout(
     return Ok((sudt_idx, u128::from_le_bytes(buf)));
 }
 
-pub fn verify_time_lock_expired(time_lock: u64) -> Result<(), Error> {
-    let old_header = load_header(0, Source::GroupInput)?;
-    let old_timestamp = old_header.raw().timestamp().unpack();
-    let current_time = find_closest_current_time();
-    if old_timestamp + time_lock > current_time {
+/// verify_time_lock_expired verifies that the challenge duration of the disputed channel has expired. If
+/// the dispute deadline is recorded in the status, the channel cell has to be consumed with an absolute
+/// since of at least the deadline. Otherwise, the challenge duration started when the channel cell was
+/// created and the channel cell has to be consumed with a relative since of at least the challenge
+/// duration. In both cases the since is enforced by CKB: The transaction is only valid once it has
+/// matured.
+pub fn verify_time_lock_expired(
+    old_status: &ChannelStatus,
+    params: &ChannelParameters,
+) -> Result<(), Error> {
+    let since = Since::new(load_input_since(0, Source::GroupInput)?);
+    if !since.flags_is_valid() {
+        return Err(Error::InvalidChallengeSince);
+    }
+    let expired = match old_status.dispute_deadline().to_opt() {
+        Some(deadline) if since.is_absolute() => since_reached(params, &since, deadline.unpack())?,
+        None if !since.is_absolute() => {
+            duration_passed(params, &since, params.challenge_duration().unpack())?
+        }
+        _ => return Err(Error::InvalidChallengeSince),
+    };
+    if !expired {
         return Err(Error::TimeLockNotExpired);
     }
     Ok(())
 }
 
-pub fn find_closest_current_time() -> u64 {
-    let mut latest_time = 0;
-    for i in 0.. {
-        match load_header(i, Source::HeaderDep) {
-            Ok(header) => {
-                let timestamp = header.raw().timestamp().unpack();
-                if timestamp > latest_time {
-                    latest_time = timestamp;
-                }
-            }
-            Err(_) => break,
+/// verify_funding_deadline_passed verifies that the channel cell is consumed with an absolute since of at
+/// least the funding deadline, if the channel has one.
+pub fn verify_funding_deadline_passed(params: &ChannelParameters) -> Result<(), Error> {
+    let deadline: u64 = match params.funding_deadline().to_opt() {
+        Some(deadline) => deadline.unpack(),
+        None => return Ok(()),
+    };
+    let since = Since::new(load_input_since(0, Source::GroupInput)?);
+    if !since.flags_is_valid() || !since.is_absolute() {
+        return Err(Error::InvalidChallengeSince);
+    }
+    if !since_reached(params, &since, deadline)? {
+        return Err(Error::FundingDeadlineNotReached);
+    }
+    Ok(())
+}
+
+/// verify_funding_deadline_not_passed verifies that the consumed channel cell was created before the
+/// funding deadline, if the channel has one. The header of the block that includes the channel cell has to
+/// be provided as header dep.
+///
+/// This deliberately falls short of rejecting every Fund included after the deadline: Both the since of an
+/// input and a header dep only prove a lower bound of the time a transaction is included at, and CKB offers
+/// no way to prove an upper bound. The creation of the consumed channel cell is the latest point in time
+/// funding can be verified to be on time at, so a Fund consuming a channel cell created before the deadline
+/// is accepted even if it is included after the deadline.
+pub fn verify_funding_deadline_not_passed(params: &ChannelParameters) -> Result<(), Error> {
+    let deadline: u64 = match params.funding_deadline().to_opt() {
+        Some(deadline) => deadline.unpack(),
+        None => return Ok(()),
+    };
+    if time_reached(params, get_block_time_of_input(params)?, deadline)? {
+        return Err(Error::FundingDeadlinePassed);
+    }
+    Ok(())
+}
+
+/// duration_passed returns whether the lock value of the relative `since` is at least `duration`, which
+/// is measured in the challenge duration metric of the channel. Epoch durations are given as a number of
+/// epochs, just like the challenge duration added by get_challenge_end_of_input.
+pub fn duration_passed(
+    params: &ChannelParameters,
+    since: &Since,
+    duration: u64,
+) -> Result<bool, Error> {
+    match (
+        u8::from(params.challenge_duration_metric()),
+        since.extract_lock_value(),
+    ) {
+        (CHALLENGE_DURATION_METRIC_TIMESTAMP, Some(LockValue::Timestamp(ms))) => Ok(ms >= duration),
+        (CHALLENGE_DURATION_METRIC_BLOCK_NUMBER, Some(LockValue::BlockNumber(blocks))) => {
+            Ok(blocks >= duration)
+        }
+        (CHALLENGE_DURATION_METRIC_EPOCH, Some(LockValue::EpochNumberWithFraction(epoch))) => {
+            // number + index / length >= duration
+            let length = epoch.length() as u128;
+            Ok(
+                epoch.number() as u128 * length + epoch.index() as u128
+                    >= duration as u128 * length,
+            )
         }
+        _ => Err(Error::InvalidChallengeSince),
+    }
+}
+
+/// since_reached returns whether the lock value of the absolute `since` is at least `target`, which is
+/// measured in the challenge duration metric of the channel. Epochs are given as full epoch values.
+pub fn since_reached(
+    params: &ChannelParameters,
+    since: &Since,
+    target: u64,
+) -> Result<bool, Error> {
+    match (
+        u8::from(params.challenge_duration_metric()),
+        since.extract_lock_value(),
+    ) {
+        (CHALLENGE_DURATION_METRIC_TIMESTAMP, Some(LockValue::Timestamp(ms))) => Ok(ms >= target),
+        (CHALLENGE_DURATION_METRIC_BLOCK_NUMBER, Some(LockValue::BlockNumber(blocks))) => {
+            Ok(blocks >= target)
+        }
+        (CHALLENGE_DURATION_METRIC_EPOCH, Some(LockValue::EpochNumberWithFraction(epoch))) => {
+            epoch_reached(&epoch, target)
+        }
+        _ => Err(Error::InvalidChallengeSince),
+    }
+}
+
+/// time_reached returns whether `time` is at least `target`, both measured in the challenge duration
+/// metric of the channel. Epochs are given as full epoch values.
+pub fn time_reached(params: &ChannelParameters, time: u64, target: u64) -> Result<bool, Error> {
+    match u8::from(params.challenge_duration_metric()) {
+        CHALLENGE_DURATION_METRIC_TIMESTAMP | CHALLENGE_DURATION_METRIC_BLOCK_NUMBER => {
+            Ok(time >= target)
+        }
+        CHALLENGE_DURATION_METRIC_EPOCH => {
+            epoch_reached(&EpochNumberWithFraction::from_full_value(time), target)
+        }
+        _ => Err(Error::InvalidChallengeSince),
+    }
+}
+
+/// epoch_reached returns whether `epoch` is at least the epoch with the full value `target`.
+pub fn epoch_reached(epoch: &EpochNumberWithFraction, target: u64) -> Result<bool, Error> {
+    let target = parse_epoch(target)?;
+    // number + index / length >= target number + target index / target length
+    let lhs = (epoch.number() as u128 * epoch.length() as u128 + epoch.index() as u128)
+        * target.length() as u128;
+    let rhs = (target.number() as u128 * target.length() as u128 + target.index() as u128)
+        * epoch.length() as u128;
+    Ok(lhs >= rhs)
+}
+
+/// parse_epoch parses the full epoch value `value`. EpochNumberWithFraction::from_full_value silently
+/// rewrites a value with a length of 0 to the start of its epoch, so such values are rejected instead,
+/// just like values with an index beyond their length.
+pub fn parse_epoch(value: u64) -> Result<EpochNumberWithFraction, Error> {
+    let epoch = EpochNumberWithFraction::from_full_value(value);
+    if epoch.full_value() != value || epoch.index() >= epoch.length() {
+        return Err(Error::InvalidEpoch);
+    }
+    Ok(epoch)
+}
+
+/// verify_valid_time verifies that `time`, e.g. a deadline or an HTLC timeout, is a valid point in time in
+/// the challenge duration metric of the channel.
+pub fn verify_valid_time(params: &ChannelParameters, time: u64) -> Result<(), Error> {
+    if u8::from(params.challenge_duration_metric()) == CHALLENGE_DURATION_METRIC_EPOCH {
+        parse_epoch(time)?;
+    }
+    Ok(())
+}
+
+/// verify_valid_htlc_timeouts verifies that the timeout of every HTLC in the balances is a valid point in
+/// time, as an HTLC with an invalid timeout could never time out.
+pub fn verify_valid_htlc_timeouts(
+    balances: &Balances,
+    params: &ChannelParameters,
+) -> Result<(), Error> {
+    for htlc in balances.htlcs().into_iter() {
+        verify_valid_time(params, htlc.timeout().unpack())?;
+    }
+    Ok(())
+}
+
+/// get_absolute_input_sinces returns the absolute sinces of all inputs of the transaction. CKB enforces
+/// them, so the transaction is included only after each of them has been reached.
+pub fn get_absolute_input_sinces() -> Result<Vec<Since>, Error> {
+    Ok(QueryIter::new(load_input_since, Source::Input)
+        .map(Since::new)
+        .filter(|since| since.flags_is_valid() && since.is_absolute())
+        .collect())
+}
+
+/// is_htlc_timed_out returns whether one of the absolute input sinces `since` has reached the HTLC
+/// `timeout`, which is measured in the challenge duration metric of the channel.
+pub fn is_htlc_timed_out(params: &ChannelParameters, since: &[Since], timeout: u64) -> bool {
+    since
+        .iter()
+        .any(|s| since_reached(params, s, timeout).unwrap_or(false))
+}
+
+/// verify_dispute_deadline verifies that the dispute deadline is carried over by refutations. The first
+/// dispute does not know when it is included in a block, so it does not record a deadline. The first
+/// refutation records the deadline as the end of the challenge duration starting with the block that
+/// includes the disputed channel cell. Its header has to be provided as header dep. Later refutations
+/// can not move the deadline.
+pub fn verify_dispute_deadline(
+    old_status: &ChannelStatus,
+    new_status: &ChannelStatus,
+    params: &ChannelParameters,
+) -> Result<(), Error> {
+    let expected = match (
+        old_status.disputed().to_bool(),
+        old_status.dispute_deadline().to_opt(),
+    ) {
+        (false, _) => None,
+        (true, Some(deadline)) => Some(deadline.unpack()),
+        (true, None) => Some(get_challenge_end_of_input(params)?),
+    };
+    let deadline: Option<u64> = new_status.dispute_deadline().to_opt().map(|d| d.unpack());
+    if deadline != expected {
+        return Err(Error::DisputeDeadlineMismatch);
+    }
+    Ok(())
+}
+
+/// get_challenge_end_of_input returns the end of a challenge duration starting with the block that
+/// includes the consumed channel cell, measured in the challenge duration metric of the channel.
+pub fn get_challenge_end_of_input(params: &ChannelParameters) -> Result<u64, Error> {
+    let time = get_block_time_of_input(params)?;
+    let duration: u64 = params.challenge_duration().unpack();
+    match u8::from(params.challenge_duration_metric()) {
+        CHALLENGE_DURATION_METRIC_EPOCH => {
+            let epoch = EpochNumberWithFraction::from_full_value(time);
+            Ok(EpochNumberWithFraction::new_unchecked(
+                epoch.number().saturating_add(duration),
+                epoch.index(),
+                epoch.length(),
+            )
+            .full_value())
+        }
+        _ => Ok(time.saturating_add(duration)),
+    }
+}
+
+/// get_block_time_of_input returns the time of the block that includes the consumed channel cell, measured
+/// in the challenge duration metric of the channel. Its header has to be provided as header dep.
+pub fn get_block_time_of_input(params: &ChannelParameters) -> Result<u64, Error> {
+    let header = load_header(0, Source::GroupInput)?.raw();
+    match u8::from(params.challenge_duration_metric()) {
+        CHALLENGE_DURATION_METRIC_TIMESTAMP => Ok(header.timestamp().unpack()),
+        CHALLENGE_DURATION_METRIC_BLOCK_NUMBER => Ok(header.number().unpack()),
+        CHALLENGE_DURATION_METRIC_EPOCH => Ok(header.epoch().unpack()),
+        _ => Err(Error::InvalidChallengeSince),
     }
-    latest_time
 }
 
 pub fn verify_state_finalized(state: &ChannelState) -> Result<(), Error> {
@@ -863,6 +2703,10 @@ pub fn get_channel_action() -> Result<ChannelAction, Error> {
 }
 
 /// verify_max_one_channel verifies that there is at most one channel in the group input and group output respectively.
+/// As the args of the pcts hold the ChannelConstants, including the unique thread token, every channel forms its own
+/// script group. Several cells in one group would be copies of the same channel, e.g. two channel cells started with the
+/// same thread token. Different channels can still interact in the same transaction, each of them being verified within
+/// its own group. Payments are attributed to the channels by their tags (see is_payment_of_channel).
 pub fn verify_max_one_channel() -> Result<(), Error> {
     if count_cells(Source::GroupInput)? > 1 || count_cells(Source::GroupOutput)? > 1 {
         return Err(Error::MoreThanOneChannel);
@@ -887,18 +2731,14 @@ pub fn count_cells(source: Source) -> Result<usize, Error> {
 pub fn verify_different_payment_addresses(
     channel_constants: &ChannelConstants,
 ) -> Result<(), Error> {
-    if channel_constants
-        .params()
-        .party_a()
-        .payment_script_hash()
-        .unpack()[..]
-        == channel_constants
-            .params()
-            .party_b()
-            .payment_script_hash()
-            .unpack()[..]
-    {
-        return Err(Error::SamePaymentAddress);
+    let parties = channel_constants.params().parties();
+    for (i, party) in parties.clone().into_iter().enumerate() {
+        for other in parties.clone().into_iter().skip(i + 1) {
+            if party.payment_script_hash().unpack()[..] == other.payment_script_hash().unpack()[..]
+            {
+                return Err(Error::SamePaymentAddress);
+            }
+        }
     }
     Ok(())
 }
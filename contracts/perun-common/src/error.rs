@@ -80,6 +80,51 @@ pub enum Error {
     InvalidSUDT,
     InvalidSUDTDataLength,
     DecreasingAmount,
+    NotEnoughParties,
+    PartyCountMismatch,
+    InvalidUnilateralProgression,
+    InvalidAppHashType,
+    AppValidatorExecFailed,
+    InvalidAppData,
+    InvalidAppTransition,
+    InvalidSubAlloc,
+    InvalidSubChannelState,
+    SubChannelNotRegistered,
+    SubChannelVersionNotIncreasing,
+    SubStatesMismatch,
+    LockedFundsNotAllowed,
+    ChannelDepositWithoutChannelOutput,
+    InvalidDeposit,
+    DepositNotInOutputs,
+    ChannelWithdrawWithoutChannelOutput,
+    InvalidWithdrawal,
+    WithdrawalMismatch,
+    SuccessorNotInOutputs,
+    InvalidSuccessor,
+    DelegateActionNotAllowed,
+    ChannelCapacityDecreased,
+    UnknownSignatureScheme,
+    InvalidChallengeSince,
+    DisputeDeadlineMismatch,
+    InvalidHTLC,
+    HTLCNotSettled,
+    FundingDeadlinePassed,
+    FundingDeadlineNotReached,
+    InvalidRotation,
+    InvalidPredecessor,
+    ChannelCheckpointWithoutChannelOutput,
+    MaxFeeExceeded,
+    InvalidBondHolder,
+    DisputeBondNotLocked,
+    SUDTPayoutBelowMaxCapacity,
+    DuplicateSUDTAsset,
+    SUDTMaxCapacityTooSmall,
+    TooManySUDTAssets,
+    InvalidDustPolicy,
+    InvalidCapacityRefund,
+    InvalidEpoch,
+    InvalidSpentFee,
+    FeeBudgetNotReserved,
 }
 
 impl From<SysError> for Error {
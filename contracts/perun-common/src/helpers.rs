@@ -1,20 +1,14 @@
 use blake2b_rs::Blake2bBuilder;
 
 #[cfg(feature = "std")]
-use {
-    crate::perun_types::ChannelState, ckb_types::bytes, ckb_types::packed::*,
-    ckb_types::prelude::*, std::vec::Vec,
-};
+use {ckb_types::bytes, ckb_types::packed::*, ckb_types::prelude::*, std::vec::Vec};
 
 #[cfg(not(feature = "std"))]
-use {
-    ckb_standalone_types::packed::*,
-    ckb_standalone_types::prelude::*,
-    molecule::prelude::{vec, Vec},
-};
+use {ckb_standalone_types::packed::*, ckb_standalone_types::prelude::*, molecule::prelude::Vec};
 
 use crate::perun_types::{
-    Balances, Bool, BoolUnion, ChannelParameters, ChannelStatus, SEC1EncodedPubKey,
+    Balances, Bool, BoolUnion, BoolVec, CapacityRefundUnion, ChannelConstants, ChannelParameters,
+    ChannelState, ChannelStates, ChannelStatus, SEC1EncodedPubKey, HTLC,
 };
 use crate::{
     error::Error,
@@ -82,16 +76,88 @@ macro_rules! fund {
     () => {
         $crate::perun_types::ChannelWitnessUnion::Fund($crate::perun_types::Fund::default())
     };
+    ($idx:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Fund(
+            $crate::perun_types::Fund::new_builder()
+                .nth0($idx.into())
+                .build(),
+        )
+    };
 }
 
 #[macro_export]
 macro_rules! close {
-    ($state:expr, $siga:expr, $sigb:expr) => {
+    ($state:expr, $sigs:expr) => {
         $crate::perun_types::ChannelWitnessUnion::Close(
             $crate::perun_types::Close::new_builder()
                 .state($state)
-                .sig_a($siga)
-                .sig_b($sigb)
+                .sigs($sigs)
+                .build(),
+        )
+    };
+    ($state:expr, $sigs:expr, $preimages:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Close(
+            $crate::perun_types::Close::new_builder()
+                .state($state)
+                .sigs($sigs)
+                .preimages($preimages)
+                .build(),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! force_close {
+    ($preimages:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::ForceClose(
+            $crate::perun_types::ForceClose::new_builder()
+                .preimages($preimages)
+                .build(),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! deposit {
+    ($sigs:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Deposit(
+            $crate::perun_types::Deposit::new_builder()
+                .sigs($sigs)
+                .build(),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! withdraw {
+    ($state:expr, $state_sigs:expr, $sigs:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Withdraw(
+            $crate::perun_types::Withdraw::new_builder()
+                .state($state)
+                .state_sigs($state_sigs)
+                .sigs($sigs)
+                .build(),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rotate {
+    ($sigs:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Rotate(
+            $crate::perun_types::Rotate::new_builder()
+                .sigs($sigs)
+                .build(),
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! checkpoint {
+    ($sigs:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Checkpoint(
+            $crate::perun_types::Checkpoint::new_builder()
+                .sigs($sigs)
                 .build(),
         )
     };
@@ -99,11 +165,18 @@ macro_rules! close {
 
 #[macro_export]
 macro_rules! dispute {
-    ($siga:expr, $sigb:expr) => {
+    ($sigs:expr) => {
         $crate::perun_types::ChannelWitnessUnion::Dispute(
             $crate::perun_types::Dispute::new_builder()
-                .sig_a($siga)
-                .sig_b($sigb)
+                .sigs($sigs)
+                .build(),
+        )
+    };
+    ($sigs:expr, $sub_channels:expr) => {
+        $crate::perun_types::ChannelWitnessUnion::Dispute(
+            $crate::perun_types::Dispute::new_builder()
+                .sigs($sigs)
+                .sub_channels($sub_channels)
                 .build(),
         )
     };
@@ -111,40 +184,39 @@ macro_rules! dispute {
 
 impl SUDTDistribution {
     pub fn sum(&self) -> u128 {
-        let a: u128 = self.nth0().unpack();
-        let b: u128 = self.nth1().unpack();
-        a + b
+        self.clone()
+            .into_iter()
+            .fold(0u128, |acc, x| acc + Unpack::<u128>::unpack(&x))
     }
 
     pub fn equal(&self, other: &Balances) -> bool {
         self.as_slice()[..] == other.as_slice()[..]
     }
 
-    pub fn get(&self, i: usize) -> Result<u128, Error> {
-        match i {
-            0 => Ok(self.nth0().unpack()),
-            1 => Ok(self.nth1().unpack()),
-            _ => Err(Error::IndexOutOfBound),
+    pub fn get_amount(&self, i: usize) -> Result<u128, Error> {
+        match self.get(i) {
+            Some(v) => Ok(v.unpack()),
+            None => Err(Error::IndexOutOfBound),
         }
     }
 
     pub fn clear_index(&self, idx: usize) -> Result<SUDTDistribution, Error> {
-        match idx {
-            0 => Ok(self.clone().as_builder().nth0(0u128.pack()).build()),
-            1 => Ok(self.clone().as_builder().nth1(0u128.pack()).build()),
-            _ => Err(Error::IndexOutOfBound),
+        let mut values = self.to_vec();
+        if idx >= values.len() {
+            return Err(Error::IndexOutOfBound);
         }
+        values[idx] = 0u128;
+        Ok(SUDTDistribution::from_vec(values))
     }
 
-    pub fn from_array(a: [u128; 2]) -> Self {
+    pub fn from_vec(values: Vec<u128>) -> Self {
         SUDTDistribution::new_builder()
-            .nth0(a[0].pack())
-            .nth1(a[1].pack())
+            .set(values.iter().map(|v| v.pack()).collect())
             .build()
     }
 
-    pub fn to_array(&self) -> [u128; 2] {
-        [self.nth0().unpack(), self.nth1().unpack()]
+    pub fn to_vec(&self) -> Vec<u128> {
+        self.clone().into_iter().map(|v| v.unpack()).collect()
     }
 }
 
@@ -169,11 +241,11 @@ impl Balances {
     }
 
     pub fn zero_at_index(&self, idx: usize) -> Result<bool, Error> {
-        if self.ckbytes().get(idx)? != 0u64 {
+        if self.ckbytes().get_amount(idx)? != 0u64 {
             return Ok(false);
         }
         for sb in self.sudts().into_iter() {
-            if sb.distribution().get(idx)? != 0u128 {
+            if sb.distribution().get_amount(idx)? != 0u128 {
                 return Ok(false);
             }
         }
@@ -181,7 +253,7 @@ impl Balances {
     }
 
     pub fn equal_at_index(&self, other: &Balances, idx: usize) -> Result<bool, Error> {
-        if self.ckbytes().get(idx)? != other.ckbytes().get(idx)? {
+        if self.ckbytes().get_amount(idx)? != other.ckbytes().get_amount(idx)? {
             return Ok(false);
         }
         if self.sudts().len() != other.sudts().len() {
@@ -192,7 +264,7 @@ impl Balances {
             if sb.asset().as_slice() != other_sb.as_slice() {
                 return Ok(false);
             }
-            if sb.distribution().get(idx)? != other_sb.distribution().get(idx)? {
+            if sb.distribution().get_amount(idx)? != other_sb.distribution().get_amount(idx)? {
                 return Ok(false);
             }
         }
@@ -200,7 +272,7 @@ impl Balances {
     }
 
     pub fn equal_in_sum(&self, other: &Balances) -> Result<bool, Error> {
-        if self.ckbytes().sum() != other.ckbytes().sum() {
+        if self.total_ckbytes() != other.total_ckbytes() {
             return Ok(false);
         }
         if self.sudts().len() != other.sudts().len() {
@@ -211,16 +283,222 @@ impl Balances {
             if sb.asset().as_slice() != other_sb.asset().as_slice() {
                 return Ok(false);
             }
-            if sb.distribution().sum() != other_sb.distribution().sum() {
+            if self.total_sudt(i)? != other.total_sudt(i)? {
                 return Ok(false);
             }
         }
         return Ok(true);
     }
 
+    /// total_ckbytes returns the sum of all CKBytes, including the CKBytes locked for sub-channels
+    /// and HTLCs.
+    pub fn total_ckbytes(&self) -> u64 {
+        let sum = self
+            .locked()
+            .into_iter()
+            .fold(self.ckbytes().sum(), |acc, sub_alloc| {
+                acc + Unpack::<u64>::unpack(&sub_alloc.ckbytes())
+            });
+        self.htlcs().into_iter().fold(sum, |acc, htlc| {
+            acc + Unpack::<u64>::unpack(&htlc.ckbytes())
+        })
+    }
+
+    /// total_sudt returns the sum of the sudt at index `idx`, including the amount locked for
+    /// sub-channels and HTLCs.
+    pub fn total_sudt(&self, idx: usize) -> Result<u128, Error> {
+        let mut sum = self
+            .sudts()
+            .get(idx)
+            .ok_or(Error::IndexOutOfBound)?
+            .distribution()
+            .sum();
+        for sub_alloc in self.locked().into_iter() {
+            sum +=
+                Unpack::<u128>::unpack(&sub_alloc.sudts().get(idx).ok_or(Error::InvalidSubAlloc)?);
+        }
+        for htlc in self.htlcs().into_iter() {
+            sum += Unpack::<u128>::unpack(&htlc.sudts().get(idx).ok_or(Error::InvalidHTLC)?);
+        }
+        Ok(sum)
+    }
+
+    /// has_valid_htlcs returns true, if the sender and receiver of every HTLC are distinct
+    /// participants out of `num_parties` and every HTLC holds an amount for each sudt.
+    pub fn has_valid_htlcs(&self, num_parties: usize) -> bool {
+        self.htlcs().into_iter().all(|htlc| {
+            let sender = u8::from(htlc.sender()) as usize;
+            let receiver = u8::from(htlc.receiver()) as usize;
+            sender < num_parties
+                && receiver < num_parties
+                && sender != receiver
+                && htlc.sudts().len() == self.sudts().len()
+        })
+    }
+
+    /// settle_htlcs returns the balances in which the funds locked in each HTLC are payed to its
+    /// receiver, if `preimages` holds the preimage of its hash lock, or back to its sender, if
+    /// `timed_out` reports its timeout as passed. It fails with HTLCNotSettled, if an HTLC can be
+    /// settled in neither way.
+    pub fn settle_htlcs(
+        &self,
+        preimages: &BytesVec,
+        timed_out: impl Fn(u64) -> bool,
+    ) -> Result<Balances, Error> {
+        let mut ckbytes = self.ckbytes().to_vec();
+        let mut sudts: Vec<Vec<u128>> = self
+            .sudts()
+            .into_iter()
+            .map(|sb| sb.distribution().to_vec())
+            .collect();
+        for htlc in self.htlcs().into_iter() {
+            let idx = htlc.settled_to(preimages, &timed_out)?;
+            let ckbytes_entry = ckbytes.get_mut(idx).ok_or(Error::InvalidHTLC)?;
+            *ckbytes_entry += Unpack::<u64>::unpack(&htlc.ckbytes());
+            for (i, sudt) in sudts.iter_mut().enumerate() {
+                let amount: u128 = htlc.sudts().get(i).ok_or(Error::InvalidHTLC)?.unpack();
+                *sudt.get_mut(idx).ok_or(Error::InvalidHTLC)? += amount;
+            }
+        }
+        let mut sudt_balances: Vec<SUDTBalances> = Vec::new();
+        for (sb, distribution) in self.sudts().into_iter().zip(sudts) {
+            sudt_balances.push(
+                sb.as_builder()
+                    .distribution(SUDTDistribution::from_vec(distribution))
+                    .build(),
+            );
+        }
+        Ok(self
+            .clone()
+            .as_builder()
+            .ckbytes(CKByteDistribution::from_vec(ckbytes))
+            .sudts(SUDTAllocation::new_builder().set(sudt_balances).build())
+            .htlcs(Default::default())
+            .build())
+    }
+
+    /// settle_sub_allocs returns the balances in which the funds locked for each sub-channel are
+    /// distributed to the participants according to the sub-channel's state in `sub_states`.
+    pub fn settle_sub_allocs(&self, sub_states: &ChannelStates) -> Result<Balances, Error> {
+        let mut ckbytes = self.ckbytes().to_vec();
+        let mut sudts: Vec<Vec<u128>> = self
+            .sudts()
+            .into_iter()
+            .map(|sb| sb.distribution().to_vec())
+            .collect();
+        for sub_alloc in self.locked().into_iter() {
+            let sub_balances = sub_states
+                .clone()
+                .into_iter()
+                .find(|s| s.channel_id().as_slice() == sub_alloc.id().as_slice())
+                .ok_or(Error::SubChannelNotRegistered)?
+                .balances();
+            for (sub_idx, idx) in sub_alloc.index_map().into_iter().enumerate() {
+                let idx = u8::from(idx) as usize;
+                let ckbytes_entry = ckbytes.get_mut(idx).ok_or(Error::InvalidSubAlloc)?;
+                *ckbytes_entry += sub_balances.ckbytes().get_amount(sub_idx)?;
+                for (i, sudt) in sudts.iter_mut().enumerate() {
+                    let sub_sudt = sub_balances
+                        .sudts()
+                        .get(i)
+                        .ok_or(Error::InvalidSubChannelState)?;
+                    let sudt_entry = sudt.get_mut(idx).ok_or(Error::InvalidSubAlloc)?;
+                    *sudt_entry += sub_sudt.distribution().get_amount(sub_idx)?;
+                }
+            }
+        }
+        let mut sudt_balances: Vec<SUDTBalances> = Vec::new();
+        for (sb, distribution) in self.sudts().into_iter().zip(sudts) {
+            sudt_balances.push(
+                sb.as_builder()
+                    .distribution(SUDTDistribution::from_vec(distribution))
+                    .build(),
+            );
+        }
+        Ok(self
+            .clone()
+            .as_builder()
+            .ckbytes(CKByteDistribution::from_vec(ckbytes))
+            .sudts(SUDTAllocation::new_builder().set(sudt_balances).build())
+            .locked(Default::default())
+            .build())
+    }
+
     pub fn equal(&self, other: &Balances) -> bool {
         self.as_slice()[..] == other.as_slice()[..]
     }
+
+    /// has_num_parties returns true, if every distribution in the balances holds exactly one
+    /// entry for each of the `num_parties` participants.
+    pub fn has_num_parties(&self, num_parties: usize) -> bool {
+        if self.ckbytes().len() != num_parties {
+            return false;
+        }
+        self.sudts()
+            .into_iter()
+            .all(|sb| sb.distribution().len() == num_parties)
+    }
+}
+
+impl HTLC {
+    /// is_unlocked_by returns true, if `preimage` hashes to the hash lock of the HTLC.
+    pub fn is_unlocked_by(&self, preimage: &[u8]) -> bool {
+        blake2b256(preimage)[..] == self.hash_lock().as_slice()[..]
+    }
+
+    /// settled_to returns the index of the participant the HTLC is settled to: The receiver, if
+    /// `preimages` holds the preimage of the hash lock, the sender, if `timed_out` reports the
+    /// timeout as passed.
+    pub fn settled_to(
+        &self,
+        preimages: &BytesVec,
+        timed_out: impl Fn(u64) -> bool,
+    ) -> Result<usize, Error> {
+        if preimages
+            .clone()
+            .into_iter()
+            .any(|p| self.is_unlocked_by(&p.raw_data()))
+        {
+            return Ok(u8::from(self.receiver()) as usize);
+        }
+        if timed_out(self.timeout().unpack()) {
+            return Ok(u8::from(self.sender()) as usize);
+        }
+        Err(Error::HTLCNotSettled)
+    }
+}
+
+impl BoolVec {
+    pub fn get_bool(&self, i: usize) -> Result<bool, Error> {
+        match self.get(i) {
+            Some(b) => Ok(b.to_bool()),
+            None => Err(Error::IndexOutOfBound),
+        }
+    }
+
+    /// all returns true, if every entry is set to true.
+    pub fn all(&self) -> bool {
+        self.clone().into_iter().all(|b| b.to_bool())
+    }
+
+    pub fn set_index(&self, idx: usize) -> Result<BoolVec, Error> {
+        let mut values = self.to_vec();
+        if idx >= values.len() {
+            return Err(Error::IndexOutOfBound);
+        }
+        values[idx] = true;
+        Ok(BoolVec::from_vec(values))
+    }
+
+    pub fn from_vec(values: Vec<bool>) -> Self {
+        BoolVec::new_builder()
+            .set(values.into_iter().map(Bool::from_bool).collect())
+            .build()
+    }
+
+    pub fn to_vec(&self) -> Vec<bool> {
+        self.clone().into_iter().map(|b| b.to_bool()).collect()
+    }
 }
 
 impl SUDTAllocation {
@@ -247,7 +525,7 @@ impl SUDTAllocation {
             return Ok(false);
         }
         for (i, sb) in self.clone().into_iter().enumerate() {
-            let v = sb.distribution().get(idx)?;
+            let v = sb.distribution().get_amount(idx)?;
             if values[i] < v {
                 return Ok(false);
             }
@@ -258,49 +536,133 @@ impl SUDTAllocation {
 
 impl CKByteDistribution {
     pub fn sum(&self) -> u64 {
-        let a: u64 = self.nth0().unpack();
-        let b: u64 = self.nth1().unpack();
-        a + b
+        self.clone()
+            .into_iter()
+            .fold(0u64, |acc, x| acc + Unpack::<u64>::unpack(&x))
     }
 
     pub fn equal(&self, other: &Balances) -> bool {
         self.as_slice()[..] == other.as_slice()[..]
     }
 
-    pub fn get(&self, i: usize) -> Result<u64, Error> {
-        match i {
-            0 => Ok(self.nth0().unpack()),
-            1 => Ok(self.nth1().unpack()),
-            _ => Err(Error::IndexOutOfBound),
+    pub fn get_amount(&self, i: usize) -> Result<u64, Error> {
+        match self.get(i) {
+            Some(v) => Ok(v.unpack()),
+            None => Err(Error::IndexOutOfBound),
         }
     }
 
     pub fn clear_index(&self, idx: usize) -> Result<CKByteDistribution, Error> {
-        match idx {
-            0 => Ok(self.clone().as_builder().nth0(0u64.pack()).build()),
-            1 => Ok(self.clone().as_builder().nth1(0u64.pack()).build()),
-            _ => Err(Error::IndexOutOfBound),
+        let mut values = self.to_vec();
+        if idx >= values.len() {
+            return Err(Error::IndexOutOfBound);
         }
+        values[idx] = 0u64;
+        Ok(CKByteDistribution::from_vec(values))
     }
 
-    pub fn from_array(array: [u64; 2]) -> Self {
+    pub fn from_vec(values: Vec<u64>) -> Self {
         CKByteDistribution::new_builder()
-            .nth0(array[0].pack())
-            .nth1(array[1].pack())
+            .set(values.iter().map(|v| v.pack()).collect())
             .build()
     }
 
-    pub fn to_array(&self) -> [u64; 2] {
-        [self.nth0().unpack(), self.nth1().unpack()]
+    pub fn to_vec(&self) -> Vec<u64> {
+        self.clone().into_iter().map(|v| v.unpack()).collect()
     }
 }
 
 pub fn geq_components(fst: &CKByteDistribution, snd: &CKByteDistribution) -> bool {
-    let a_fst: u64 = fst.nth0().unpack();
-    let a_snd: u64 = snd.nth0().unpack();
-    let b_fst: u64 = fst.nth1().unpack();
-    let b_snd: u64 = snd.nth1().unpack();
-    a_fst >= a_snd && b_fst >= b_snd
+    if fst.len() != snd.len() {
+        return false;
+    }
+    fst.clone()
+        .into_iter()
+        .zip(snd.clone())
+        .all(|(f, s)| Unpack::<u64>::unpack(&f) >= Unpack::<u64>::unpack(&s))
+}
+
+/// CHALLENGE_DURATION_METRIC_TIMESTAMP measures the challenge duration of a channel in milliseconds
+/// of block time.
+pub const CHALLENGE_DURATION_METRIC_TIMESTAMP: u8 = 0;
+/// CHALLENGE_DURATION_METRIC_BLOCK_NUMBER measures the challenge duration of a channel in blocks.
+pub const CHALLENGE_DURATION_METRIC_BLOCK_NUMBER: u8 = 1;
+/// CHALLENGE_DURATION_METRIC_EPOCH measures the challenge duration of a channel in epochs.
+pub const CHALLENGE_DURATION_METRIC_EPOCH: u8 = 2;
+
+/// DUST_POLICY_FEE adds the CKBytes of parties whose payout is below their payment_min_capacity to the
+/// fee budget of the closing transaction.
+pub const DUST_POLICY_FEE: u8 = 0;
+/// DUST_POLICY_COUNTERPARTY pays the CKBytes of parties whose payout is below their
+/// payment_min_capacity to the party with the largest payout.
+pub const DUST_POLICY_COUNTERPARTY: u8 = 1;
+/// DUST_POLICY_ROUND_UP rounds payouts below the payment_min_capacity of their party up from the
+/// channel capacity.
+pub const DUST_POLICY_ROUND_UP: u8 = 2;
+
+/// apply_dust_policy applies the dust policy of the channel to the CKByte payouts of the parties. A
+/// payout is dust if it is not empty, but below the payment_min_capacity of its party. It returns the
+/// amount by which dust was rounded up from `capacity_share`, the share in the channel capacity of the
+/// party or sponsor that provided it. Dust that the policy can not place is left to the fee budget.
+pub fn apply_dust_policy(
+    ckbytes_balances: &mut [u64],
+    capacity_share: u64,
+    channel_constants: &ChannelConstants,
+) -> Result<u64, Error> {
+    let refund_party = channel_constants.refund_party();
+    let minimum_payments: Vec<u64> = channel_constants
+        .params()
+        .parties()
+        .into_iter()
+        .map(|party| party.payment_min_capacity().unpack())
+        .collect();
+    let is_dust = |i: usize, balance: u64| balance > 0 && balance < minimum_payments[i];
+    match u8::from(channel_constants.params().dust_policy()) {
+        DUST_POLICY_FEE => Ok(0),
+        DUST_POLICY_COUNTERPARTY => {
+            // The first party with the largest payout receives the dust of all other parties.
+            let recipient = (0..ckbytes_balances.len()).fold(0, |r, i| {
+                if ckbytes_balances[i] > ckbytes_balances[r] {
+                    i
+                } else {
+                    r
+                }
+            });
+            for i in 0..ckbytes_balances.len() {
+                if i != recipient && is_dust(i, ckbytes_balances[i]) {
+                    ckbytes_balances[recipient] += ckbytes_balances[i];
+                    ckbytes_balances[i] = 0;
+                }
+            }
+            Ok(0)
+        }
+        DUST_POLICY_ROUND_UP => {
+            // The dust of the parties is rounded up in the order of their index from the channel
+            // capacity. A party that provided it keeps at least its payment_min_capacity.
+            let mut rounded_up = 0u64;
+            for i in 0..ckbytes_balances.len() {
+                if Some(i) == refund_party || !is_dust(i, ckbytes_balances[i]) {
+                    continue;
+                }
+                let shortfall = minimum_payments[i] - ckbytes_balances[i];
+                let available = match refund_party {
+                    Some(r) => (capacity_share - rounded_up)
+                        .min(ckbytes_balances[r].saturating_sub(minimum_payments[r])),
+                    None => capacity_share - rounded_up,
+                };
+                if shortfall > available {
+                    continue;
+                }
+                if let Some(r) = refund_party {
+                    ckbytes_balances[r] -= shortfall;
+                }
+                ckbytes_balances[i] = minimum_payments[i];
+                rounded_up += shortfall;
+            }
+            Ok(rounded_up)
+        }
+        _ => Err(Error::InvalidDustPolicy),
+    }
 }
 
 pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
@@ -315,10 +677,73 @@ pub fn blake2b256(data: &[u8]) -> [u8; 32] {
     result
 }
 
+/// STATE_SIGNING_DOMAIN is the domain tag of the digest which is signed for a channel state. It
+/// separates state signatures from signatures on any other message.
+pub const STATE_SIGNING_DOMAIN: &[u8] = b"perun-ckb/channel-state";
+
+/// STATE_SIGNING_VERSION is the version of the layout of the state signing digest.
+pub const STATE_SIGNING_VERSION: u8 = 1;
+
+/// state_signing_digest returns the digest the participants sign for a channel state:
+///
+///   blake2b256(STATE_SIGNING_DOMAIN || STATE_SIGNING_VERSION || pcts_hash || channel_id || state)
+///
+/// For ledger channels `pcts_hash` is the script hash of the channel's pcts. It commits to the pcts
+/// deployment and to the thread token, so a signature can not be replayed on another deployment or
+/// network. Virtual channel states are registered in the disputes of several parent channels, so
+/// for them `pcts_hash` is the code hash of the pcts.
+pub fn state_signing_digest(pcts_hash: &[u8; 32], state: &ChannelState) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32).build();
+    blake2b.update(STATE_SIGNING_DOMAIN);
+    blake2b.update(&[STATE_SIGNING_VERSION]);
+    blake2b.update(pcts_hash);
+    blake2b.update(state.channel_id().as_slice());
+    blake2b.update(state.as_slice());
+    blake2b.finalize(&mut result);
+    result
+}
+
+/// TX_SIGNING_DOMAIN is the domain tag of the digest which a participant signs to authorize a
+/// transaction consuming channel cells. It separates these signatures from state signatures.
+pub const TX_SIGNING_DOMAIN: &[u8] = b"perun-ckb/channel-tx";
+
+/// tx_signing_digest returns the digest a participant signs to authorize a transaction consuming
+/// channel cells:
+///
+///   blake2b256(TX_SIGNING_DOMAIN || tx_hash || len(w_1) || w_1 || ... || len(w_n) || w_n)
+///
+/// where w_1, ..., w_n are the serialized witnesses of the channel cells in the inputs, i.e. of the
+/// script group of the pcls, with an empty lock field, and each length is encoded as 8 byte little
+/// endian. The transaction hash does not cover the witnesses, so the channel actions in them could
+/// be replaced otherwise. The lock fields are left out, as they hold the signatures themselves.
+pub fn tx_signing_digest<'a>(
+    tx_hash: &[u8; 32],
+    witnesses: impl IntoIterator<Item = &'a [u8]>,
+) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32).build();
+    blake2b.update(TX_SIGNING_DOMAIN);
+    blake2b.update(tx_hash);
+    for witness in witnesses {
+        blake2b.update(&(witness.len() as u64).to_le_bytes());
+        blake2b.update(witness);
+    }
+    blake2b.finalize(&mut result);
+    result
+}
+
 impl ChannelStatus {
-    // mk_funded creates a new ChannelStatus with the funded flag set to true.
-    pub fn mk_funded(self) -> ChannelStatus {
-        self.clone().as_builder().funded(ctrue!()).build()
+    // mk_funded_by creates a new ChannelStatus in which the participant at `idx` has funded the
+    // channel. The funded flag is set to true, if this completes the funding.
+    pub fn mk_funded_by(self, idx: usize) -> Result<ChannelStatus, Error> {
+        let funded_parties = self.funded_parties().set_index(idx)?;
+        Ok(self
+            .clone()
+            .as_builder()
+            .funded(Bool::from_bool(funded_parties.all()))
+            .funded_parties(funded_parties)
+            .build())
     }
 
     #[cfg(feature = "std")]
@@ -338,7 +763,8 @@ impl ChannelState {
         self,
         mk_lock_script: impl FnMut(u8) -> Script,
     ) -> Vec<(CellOutput, bytes::Bytes)> {
-        return self.balances().mk_outputs(mk_lock_script, vec![0, 1]);
+        let indices = (0..self.balances().ckbytes().len() as u8).collect();
+        return self.balances().mk_outputs(mk_lock_script, indices);
     }
 }
 
@@ -369,7 +795,9 @@ impl CKByteDistribution {
         indices
             .iter()
             .fold(vec![], |mut acc: Vec<(CellOutput, bytes::Bytes)>, index| {
-                let cap = self.get(index.clone() as usize).expect("invalid index");
+                let cap = self
+                    .get_amount(index.clone() as usize)
+                    .expect("invalid index");
                 acc.push((
                     CellOutput::new_builder()
                         .capacity(cap.pack())
@@ -397,7 +825,7 @@ impl SUDTAllocation {
             for f in indices.iter() {
                 if balance
                     .distribution()
-                    .get(*f as usize)
+                    .get_amount(*f as usize)
                     .expect("invalid index")
                     == 0u128
                 {
@@ -418,7 +846,7 @@ impl SUDTAllocation {
                         bytes::Bytes::from(
                             balance
                                 .distribution()
-                                .get(*f as usize)
+                                .get_amount(*f as usize)
                                 .expect("invalid index")
                                 .to_le_bytes()
                                 .to_vec(),
@@ -431,13 +859,24 @@ impl SUDTAllocation {
     }
 }
 
+impl ChannelConstants {
+    /// refund_party returns the index of the party that is refunded the capacity of the channel
+    /// cell, or None if an outside sponsor is refunded.
+    pub fn refund_party(&self) -> Option<usize> {
+        match self.capacity_refund().to_enum() {
+            CapacityRefundUnion::RefundParty(p) => Some(u8::from(p.nth0()) as usize),
+            CapacityRefundUnion::RefundSponsor(_) => None,
+        }
+    }
+}
+
 impl ChannelParameters {
     /// mk_party_pubkeys creates a vector of each participants public key in the correct order.
     pub fn mk_party_pubkeys(self) -> Vec<Vec<u8>> {
-        vec![
-            self.party_a().pub_key().to_vec(),
-            self.party_b().pub_key().to_vec(),
-        ]
+        self.parties()
+            .into_iter()
+            .map(|p| p.pub_key().to_vec())
+            .collect()
     }
 }
 
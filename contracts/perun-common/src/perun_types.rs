@@ -615,26 +615,47 @@ impl ::core::fmt::Debug for CKByteDistribution {
 impl ::core::fmt::Display for CKByteDistribution {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
-        write!(f, "{}", self.nth0())?;
-        write!(f, ", {}", self.nth1())?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
         write!(f, "]")
     }
 }
 impl ::core::default::Default for CKByteDistribution {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let v: Vec<u8> = vec![0, 0, 0, 0];
         CKByteDistribution::new_unchecked(v.into())
     }
 }
 impl CKByteDistribution {
-    pub const TOTAL_SIZE: usize = 16;
     pub const ITEM_SIZE: usize = 8;
-    pub const ITEM_COUNT: usize = 2;
-    pub fn nth0(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(0..8))
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Uint64> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn nth1(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(8..16))
+    pub fn get_unchecked(&self, idx: usize) -> Uint64 {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
     pub fn as_reader<'r>(&'r self) -> CKByteDistributionReader<'r> {
         CKByteDistributionReader::new_unchecked(self.as_slice())
@@ -662,7 +683,7 @@ impl molecule::prelude::Entity for CKByteDistribution {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0(), self.nth1()])
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
@@ -684,20 +705,41 @@ impl<'r> ::core::fmt::Debug for CKByteDistributionReader<'r> {
 impl<'r> ::core::fmt::Display for CKByteDistributionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
-        write!(f, "{}", self.nth0())?;
-        write!(f, ", {}", self.nth1())?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
         write!(f, "]")
     }
 }
 impl<'r> CKByteDistributionReader<'r> {
-    pub const TOTAL_SIZE: usize = 16;
     pub const ITEM_SIZE: usize = 8;
-    pub const ITEM_COUNT: usize = 2;
-    pub fn nth0(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Uint64Reader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn nth1(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[8..16])
+    pub fn get_unchecked(&self, idx: usize) -> Uint64Reader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
 }
 impl<'r> molecule::prelude::Reader<'r> for CKByteDistributionReader<'r> {
@@ -715,49 +757,58 @@ impl<'r> molecule::prelude::Reader<'r> for CKByteDistributionReader<'r> {
     fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
         Ok(())
     }
 }
-pub struct CKByteDistributionBuilder(pub(crate) [Uint64; 2]);
-impl ::core::fmt::Debug for CKByteDistributionBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
-}
-impl ::core::default::Default for CKByteDistributionBuilder {
-    fn default() -> Self {
-        CKByteDistributionBuilder([Uint64::default(), Uint64::default()])
-    }
-}
+#[derive(Debug, Default)]
+pub struct CKByteDistributionBuilder(pub(crate) Vec<Uint64>);
 impl CKByteDistributionBuilder {
-    pub const TOTAL_SIZE: usize = 16;
     pub const ITEM_SIZE: usize = 8;
-    pub const ITEM_COUNT: usize = 2;
-    pub fn set(mut self, v: [Uint64; 2]) -> Self {
+    pub fn set(mut self, v: Vec<Uint64>) -> Self {
         self.0 = v;
         self
     }
-    pub fn nth0(mut self, v: Uint64) -> Self {
-        self.0[0] = v;
+    pub fn push(mut self, v: Uint64) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn nth1(mut self, v: Uint64) -> Self {
-        self.0[1] = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Uint64>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: Uint64) -> Option<Uint64> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
 impl molecule::prelude::Builder for CKByteDistributionBuilder {
     type Entity = CKByteDistribution;
     const NAME: &'static str = "CKByteDistributionBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -767,6 +818,55 @@ impl molecule::prelude::Builder for CKByteDistributionBuilder {
         CKByteDistribution::new_unchecked(inner.into())
     }
 }
+pub struct CKByteDistributionIterator(CKByteDistribution, usize, usize);
+impl ::core::iter::Iterator for CKByteDistributionIterator {
+    type Item = Uint64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for CKByteDistributionIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for CKByteDistribution {
+    type Item = Uint64;
+    type IntoIter = CKByteDistributionIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        CKByteDistributionIterator(self, 0, len)
+    }
+}
+impl<'r> CKByteDistributionReader<'r> {
+    pub fn iter<'t>(&'t self) -> CKByteDistributionReaderIterator<'t, 'r> {
+        CKByteDistributionReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct CKByteDistributionReaderIterator<'t, 'r>(&'t CKByteDistributionReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for CKByteDistributionReaderIterator<'t, 'r> {
+    type Item = Uint64Reader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for CKByteDistributionReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
 #[derive(Clone)]
 pub struct SUDTDistribution(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for SUDTDistribution {
@@ -786,29 +886,47 @@ impl ::core::fmt::Debug for SUDTDistribution {
 impl ::core::fmt::Display for SUDTDistribution {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
-        write!(f, "{}", self.nth0())?;
-        write!(f, ", {}", self.nth1())?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
         write!(f, "]")
     }
 }
 impl ::core::default::Default for SUDTDistribution {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0,
-        ];
+        let v: Vec<u8> = vec![0, 0, 0, 0];
         SUDTDistribution::new_unchecked(v.into())
     }
 }
 impl SUDTDistribution {
-    pub const TOTAL_SIZE: usize = 32;
     pub const ITEM_SIZE: usize = 16;
-    pub const ITEM_COUNT: usize = 2;
-    pub fn nth0(&self) -> Uint128 {
-        Uint128::new_unchecked(self.0.slice(0..16))
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Uint128> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn nth1(&self) -> Uint128 {
-        Uint128::new_unchecked(self.0.slice(16..32))
+    pub fn get_unchecked(&self, idx: usize) -> Uint128 {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint128::new_unchecked(self.0.slice(start..end))
     }
     pub fn as_reader<'r>(&'r self) -> SUDTDistributionReader<'r> {
         SUDTDistributionReader::new_unchecked(self.as_slice())
@@ -836,7 +954,7 @@ impl molecule::prelude::Entity for SUDTDistribution {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0(), self.nth1()])
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
@@ -858,20 +976,41 @@ impl<'r> ::core::fmt::Debug for SUDTDistributionReader<'r> {
 impl<'r> ::core::fmt::Display for SUDTDistributionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
-        write!(f, "{}", self.nth0())?;
-        write!(f, ", {}", self.nth1())?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
         write!(f, "]")
     }
 }
 impl<'r> SUDTDistributionReader<'r> {
-    pub const TOTAL_SIZE: usize = 32;
     pub const ITEM_SIZE: usize = 16;
-    pub const ITEM_COUNT: usize = 2;
-    pub fn nth0(&self) -> Uint128Reader<'r> {
-        Uint128Reader::new_unchecked(&self.as_slice()[0..16])
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Uint128Reader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn nth1(&self) -> Uint128Reader<'r> {
-        Uint128Reader::new_unchecked(&self.as_slice()[16..32])
+    pub fn get_unchecked(&self, idx: usize) -> Uint128Reader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
     }
 }
 impl<'r> molecule::prelude::Reader<'r> for SUDTDistributionReader<'r> {
@@ -889,49 +1028,58 @@ impl<'r> molecule::prelude::Reader<'r> for SUDTDistributionReader<'r> {
     fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
         Ok(())
     }
 }
-pub struct SUDTDistributionBuilder(pub(crate) [Uint128; 2]);
-impl ::core::fmt::Debug for SUDTDistributionBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
-}
-impl ::core::default::Default for SUDTDistributionBuilder {
-    fn default() -> Self {
-        SUDTDistributionBuilder([Uint128::default(), Uint128::default()])
-    }
-}
+#[derive(Debug, Default)]
+pub struct SUDTDistributionBuilder(pub(crate) Vec<Uint128>);
 impl SUDTDistributionBuilder {
-    pub const TOTAL_SIZE: usize = 32;
     pub const ITEM_SIZE: usize = 16;
-    pub const ITEM_COUNT: usize = 2;
-    pub fn set(mut self, v: [Uint128; 2]) -> Self {
+    pub fn set(mut self, v: Vec<Uint128>) -> Self {
         self.0 = v;
         self
     }
-    pub fn nth0(mut self, v: Uint128) -> Self {
-        self.0[0] = v;
+    pub fn push(mut self, v: Uint128) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn nth1(mut self, v: Uint128) -> Self {
-        self.0[1] = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Uint128>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: Uint128) -> Option<Uint128> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
 impl molecule::prelude::Builder for SUDTDistributionBuilder {
     type Entity = SUDTDistribution;
     const NAME: &'static str = "SUDTDistributionBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -941,6 +1089,55 @@ impl molecule::prelude::Builder for SUDTDistributionBuilder {
         SUDTDistribution::new_unchecked(inner.into())
     }
 }
+pub struct SUDTDistributionIterator(SUDTDistribution, usize, usize);
+impl ::core::iter::Iterator for SUDTDistributionIterator {
+    type Item = Uint128;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for SUDTDistributionIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for SUDTDistribution {
+    type Item = Uint128;
+    type IntoIter = SUDTDistributionIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SUDTDistributionIterator(self, 0, len)
+    }
+}
+impl<'r> SUDTDistributionReader<'r> {
+    pub fn iter<'t>(&'t self) -> SUDTDistributionReaderIterator<'t, 'r> {
+        SUDTDistributionReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct SUDTDistributionReaderIterator<'t, 'r>(&'t SUDTDistributionReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SUDTDistributionReaderIterator<'t, 'r> {
+    type Item = Uint128Reader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SUDTDistributionReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
 #[derive(Clone)]
 pub struct SUDTAllocation(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for SUDTAllocation {
@@ -1577,9 +1774,8 @@ impl ::core::fmt::Display for SUDTBalances {
 impl ::core::default::Default for SUDTBalances {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            117, 0, 0, 0, 12, 0, 0, 0, 85, 0, 0, 0, 73, 0, 0, 0, 12, 0, 0, 0, 65, 0, 0, 0, 53, 0,
-            0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            89, 0, 0, 0, 12, 0, 0, 0, 85, 0, 0, 0, 73, 0, 0, 0, 12, 0, 0, 0, 65, 0, 0, 0, 53, 0, 0,
+            0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0,
         ];
@@ -1815,8 +2011,8 @@ impl molecule::prelude::Builder for SUDTBalancesBuilder {
     }
 }
 #[derive(Clone)]
-pub struct Balances(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Balances {
+pub struct SUDTAmounts(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SUDTAmounts {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1825,75 +2021,65 @@ impl ::core::fmt::LowerHex for Balances {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Balances {
+impl ::core::fmt::Debug for SUDTAmounts {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Balances {
+impl ::core::fmt::Display for SUDTAmounts {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "ckbytes", self.ckbytes())?;
-        write!(f, ", {}: {}", "sudts", self.sudts())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl ::core::default::Default for Balances {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            32, 0, 0, 0, 12, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            4, 0, 0, 0,
-        ];
-        Balances::new_unchecked(v.into())
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl Balances {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl ::core::default::Default for SUDTAmounts {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        SUDTAmounts::new_unchecked(v.into())
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+}
+impl SUDTAmounts {
+    pub const ITEM_SIZE: usize = 16;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn ckbytes(&self) -> CKByteDistribution {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        CKByteDistribution::new_unchecked(self.0.slice(start..end))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn sudts(&self) -> SUDTAllocation {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            SUDTAllocation::new_unchecked(self.0.slice(start..end))
+    pub fn get(&self, idx: usize) -> Option<Uint128> {
+        if idx >= self.len() {
+            None
         } else {
-            SUDTAllocation::new_unchecked(self.0.slice(start..))
+            Some(self.get_unchecked(idx))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BalancesReader<'r> {
-        BalancesReader::new_unchecked(self.as_slice())
+    pub fn get_unchecked(&self, idx: usize) -> Uint128 {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> SUDTAmountsReader<'r> {
+        SUDTAmountsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Balances {
-    type Builder = BalancesBuilder;
-    const NAME: &'static str = "Balances";
+impl molecule::prelude::Entity for SUDTAmounts {
+    type Builder = SUDTAmountsBuilder;
+    const NAME: &'static str = "SUDTAmounts";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Balances(data)
+        SUDTAmounts(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1902,23 +2088,21 @@ impl molecule::prelude::Entity for Balances {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BalancesReader::from_slice(slice).map(|reader| reader.to_entity())
+        SUDTAmountsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BalancesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SUDTAmountsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .ckbytes(self.ckbytes())
-            .sudts(self.sudts())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BalancesReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BalancesReader<'r> {
+pub struct SUDTAmountsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SUDTAmountsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1927,161 +2111,179 @@ impl<'r> ::core::fmt::LowerHex for BalancesReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BalancesReader<'r> {
+impl<'r> ::core::fmt::Debug for SUDTAmountsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BalancesReader<'r> {
+impl<'r> ::core::fmt::Display for SUDTAmountsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "ckbytes", self.ckbytes())?;
-        write!(f, ", {}: {}", "sudts", self.sudts())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> BalancesReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> SUDTAmountsReader<'r> {
+    pub const ITEM_SIZE: usize = 16;
     pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn ckbytes(&self) -> CKByteDistributionReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        CKByteDistributionReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn sudts(&self) -> SUDTAllocationReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            SUDTAllocationReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<Uint128Reader<'r>> {
+        if idx >= self.len() {
+            None
         } else {
-            SUDTAllocationReader::new_unchecked(&self.as_slice()[start..])
+            Some(self.get_unchecked(idx))
         }
     }
+    pub fn get_unchecked(&self, idx: usize) -> Uint128Reader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
 }
-impl<'r> molecule::prelude::Reader<'r> for BalancesReader<'r> {
-    type Entity = Balances;
-    const NAME: &'static str = "BalancesReader";
+impl<'r> molecule::prelude::Reader<'r> for SUDTAmountsReader<'r> {
+    type Entity = SUDTAmounts;
+    const NAME: &'static str = "SUDTAmountsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BalancesReader(slice)
+        SUDTAmountsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
             return Ok(());
         }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        CKByteDistributionReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SUDTAllocationReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct BalancesBuilder {
-    pub(crate) ckbytes: CKByteDistribution,
-    pub(crate) sudts: SUDTAllocation,
-}
-impl BalancesBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn ckbytes(mut self, v: CKByteDistribution) -> Self {
-        self.ckbytes = v;
+pub struct SUDTAmountsBuilder(pub(crate) Vec<Uint128>);
+impl SUDTAmountsBuilder {
+    pub const ITEM_SIZE: usize = 16;
+    pub fn set(mut self, v: Vec<Uint128>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn sudts(mut self, v: SUDTAllocation) -> Self {
-        self.sudts = v;
+    pub fn push(mut self, v: Uint128) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Uint128>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: Uint128) -> Option<Uint128> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for BalancesBuilder {
-    type Entity = Balances;
-    const NAME: &'static str = "BalancesBuilder";
+impl molecule::prelude::Builder for SUDTAmountsBuilder {
+    type Entity = SUDTAmounts;
+    const NAME: &'static str = "SUDTAmountsBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.ckbytes.as_slice().len()
-            + self.sudts.as_slice().len()
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.ckbytes.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.sudts.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
         }
-        writer.write_all(self.ckbytes.as_slice())?;
-        writer.write_all(self.sudts.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Balances::new_unchecked(inner.into())
+        SUDTAmounts::new_unchecked(inner.into())
+    }
+}
+pub struct SUDTAmountsIterator(SUDTAmounts, usize, usize);
+impl ::core::iter::Iterator for SUDTAmountsIterator {
+    type Item = Uint128;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for SUDTAmountsIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for SUDTAmounts {
+    type Item = Uint128;
+    type IntoIter = SUDTAmountsIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SUDTAmountsIterator(self, 0, len)
+    }
+}
+impl<'r> SUDTAmountsReader<'r> {
+    pub fn iter<'t>(&'t self) -> SUDTAmountsReaderIterator<'t, 'r> {
+        SUDTAmountsReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct SUDTAmountsReaderIterator<'t, 'r>(&'t SUDTAmountsReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SUDTAmountsReaderIterator<'t, 'r> {
+    type Item = Uint128Reader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SUDTAmountsReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct True(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for True {
+pub struct IndexMap(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for IndexMap {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2090,43 +2292,62 @@ impl ::core::fmt::LowerHex for True {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for True {
+impl ::core::fmt::Debug for IndexMap {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for True {
+impl ::core::fmt::Display for IndexMap {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for True {
+impl ::core::default::Default for IndexMap {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        True::new_unchecked(v.into())
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        IndexMap::new_unchecked(v.into())
     }
 }
-impl True {
-    pub const TOTAL_SIZE: usize = 1;
+impl IndexMap {
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn as_reader<'r>(&'r self) -> TrueReader<'r> {
-        TrueReader::new_unchecked(self.as_slice())
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-}
-impl molecule::prelude::Entity for True {
-    type Builder = TrueBuilder;
-    const NAME: &'static str = "True";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        True(data)
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Byte> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Byte {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.0.slice(molecule::NUMBER_SIZE..)
+    }
+    pub fn as_reader<'r>(&'r self) -> IndexMapReader<'r> {
+        IndexMapReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for IndexMap {
+    type Builder = IndexMapBuilder;
+    const NAME: &'static str = "IndexMap";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        IndexMap(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2135,21 +2356,21 @@ impl molecule::prelude::Entity for True {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TrueReader::from_slice(slice).map(|reader| reader.to_entity())
+        IndexMapReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TrueReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        IndexMapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TrueReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TrueReader<'r> {
+pub struct IndexMapReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IndexMapReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2158,37 +2379,56 @@ impl<'r> ::core::fmt::LowerHex for TrueReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TrueReader<'r> {
+impl<'r> ::core::fmt::Debug for IndexMapReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TrueReader<'r> {
+impl<'r> ::core::fmt::Display for IndexMapReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> TrueReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
+impl<'r> IndexMapReader<'r> {
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ByteReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ByteReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
     pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+        &self.as_slice()[molecule::NUMBER_SIZE..]
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TrueReader<'r> {
-    type Entity = True;
-    const NAME: &'static str = "TrueReader";
+impl<'r> molecule::prelude::Reader<'r> for IndexMapReader<'r> {
+    type Entity = IndexMap;
+    const NAME: &'static str = "IndexMapReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TrueReader(slice)
+        IndexMapReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2196,56 +2436,96 @@ impl<'r> molecule::prelude::Reader<'r> for TrueReader<'r> {
     fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
         Ok(())
     }
 }
-pub struct TrueBuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for TrueBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
-}
-impl ::core::default::Default for TrueBuilder {
-    fn default() -> Self {
-        TrueBuilder([Byte::default()])
-    }
-}
-impl TrueBuilder {
-    pub const TOTAL_SIZE: usize = 1;
+#[derive(Debug, Default)]
+pub struct IndexMapBuilder(pub(crate) Vec<Byte>);
+impl IndexMapBuilder {
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
+    pub fn set(mut self, v: Vec<Byte>) -> Self {
         self.0 = v;
         self
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
+    pub fn push(mut self, v: Byte) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Byte>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: Byte) -> Option<Byte> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for TrueBuilder {
-    type Entity = True;
-    const NAME: &'static str = "TrueBuilder";
+impl molecule::prelude::Builder for IndexMapBuilder {
+    type Entity = IndexMap;
+    const NAME: &'static str = "IndexMapBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        True::new_unchecked(inner.into())
+        IndexMap::new_unchecked(inner.into())
+    }
+}
+pub struct IndexMapIterator(IndexMap, usize, usize);
+impl ::core::iter::Iterator for IndexMapIterator {
+    type Item = Byte;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for IndexMapIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for IndexMap {
+    type Item = Byte;
+    type IntoIter = IndexMapIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IndexMapIterator(self, 0, len)
     }
 }
 #[derive(Clone)]
-pub struct False(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for False {
+pub struct SubAlloc(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SubAlloc {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2254,43 +2534,90 @@ impl ::core::fmt::LowerHex for False {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for False {
+impl ::core::fmt::Debug for SubAlloc {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for False {
+impl ::core::fmt::Display for SubAlloc {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "ckbytes", self.ckbytes())?;
+        write!(f, ", {}: {}", "sudts", self.sudts())?;
+        write!(f, ", {}: {}", "index_map", self.index_map())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for False {
+impl ::core::default::Default for SubAlloc {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        False::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            68, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SubAlloc::new_unchecked(v.into())
     }
 }
-impl False {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+impl SubAlloc {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> FalseReader<'r> {
-        FalseReader::new_unchecked(self.as_slice())
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn ckbytes(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sudts(&self) -> SUDTAmounts {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SUDTAmounts::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn index_map(&self) -> IndexMap {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            IndexMap::new_unchecked(self.0.slice(start..end))
+        } else {
+            IndexMap::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SubAllocReader<'r> {
+        SubAllocReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for False {
-    type Builder = FalseBuilder;
-    const NAME: &'static str = "False";
+impl molecule::prelude::Entity for SubAlloc {
+    type Builder = SubAllocBuilder;
+    const NAME: &'static str = "SubAlloc";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        False(data)
+        SubAlloc(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2299,21 +2626,25 @@ impl molecule::prelude::Entity for False {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        FalseReader::from_slice(slice).map(|reader| reader.to_entity())
+        SubAllocReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        FalseReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SubAllocReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+        Self::new_builder()
+            .id(self.id())
+            .ckbytes(self.ckbytes())
+            .sudts(self.sudts())
+            .index_map(self.index_map())
     }
 }
 #[derive(Clone, Copy)]
-pub struct FalseReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for FalseReader<'r> {
+pub struct SubAllocReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SubAllocReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2322,94 +2653,195 @@ impl<'r> ::core::fmt::LowerHex for FalseReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for FalseReader<'r> {
+impl<'r> ::core::fmt::Debug for SubAllocReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for FalseReader<'r> {
+impl<'r> ::core::fmt::Display for SubAllocReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "ckbytes", self.ckbytes())?;
+        write!(f, ", {}: {}", "sudts", self.sudts())?;
+        write!(f, ", {}: {}", "index_map", self.index_map())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> FalseReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> SubAllocReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ckbytes(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sudts(&self) -> SUDTAmountsReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SUDTAmountsReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn index_map(&self) -> IndexMapReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            IndexMapReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            IndexMapReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for FalseReader<'r> {
-    type Entity = False;
-    const NAME: &'static str = "FalseReader";
+impl<'r> molecule::prelude::Reader<'r> for SubAllocReader<'r> {
+    type Entity = SubAlloc;
+    const NAME: &'static str = "SubAllocReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        FalseReader(slice)
+        SubAllocReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SUDTAmountsReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        IndexMapReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
-pub struct FalseBuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for FalseBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
+#[derive(Debug, Default)]
+pub struct SubAllocBuilder {
+    pub(crate) id: Byte32,
+    pub(crate) ckbytes: Uint64,
+    pub(crate) sudts: SUDTAmounts,
+    pub(crate) index_map: IndexMap,
 }
-impl ::core::default::Default for FalseBuilder {
-    fn default() -> Self {
-        FalseBuilder([Byte::default()])
+impl SubAllocBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn id(mut self, v: Byte32) -> Self {
+        self.id = v;
+        self
     }
-}
-impl FalseBuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
-        self.0 = v;
+    pub fn ckbytes(mut self, v: Uint64) -> Self {
+        self.ckbytes = v;
         self
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
+    pub fn sudts(mut self, v: SUDTAmounts) -> Self {
+        self.sudts = v;
+        self
+    }
+    pub fn index_map(mut self, v: IndexMap) -> Self {
+        self.index_map = v;
         self
     }
 }
-impl molecule::prelude::Builder for FalseBuilder {
-    type Entity = False;
-    const NAME: &'static str = "FalseBuilder";
+impl molecule::prelude::Builder for SubAllocBuilder {
+    type Entity = SubAlloc;
+    const NAME: &'static str = "SubAllocBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.id.as_slice().len()
+            + self.ckbytes.as_slice().len()
+            + self.sudts.as_slice().len()
+            + self.index_map.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ckbytes.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sudts.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.index_map.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.ckbytes.as_slice())?;
+        writer.write_all(self.sudts.as_slice())?;
+        writer.write_all(self.index_map.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        False::new_unchecked(inner.into())
+        SubAlloc::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Bool(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Bool {
+pub struct SubAllocs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SubAllocs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2418,46 +2850,75 @@ impl ::core::fmt::LowerHex for Bool {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Bool {
+impl ::core::fmt::Debug for SubAllocs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Bool {
+impl ::core::fmt::Display for SubAllocs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for Bool {
+impl ::core::default::Default for SubAllocs {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0, 0];
-        Bool::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        SubAllocs::new_unchecked(v.into())
     }
 }
-impl Bool {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl SubAllocs {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> BoolUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => True::new_unchecked(inner).into(),
-            1 => False::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BoolReader<'r> {
-        BoolReader::new_unchecked(self.as_slice())
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SubAlloc> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SubAlloc {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SubAlloc::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SubAlloc::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SubAllocsReader<'r> {
+        SubAllocsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Bool {
-    type Builder = BoolBuilder;
-    const NAME: &'static str = "Bool";
+impl molecule::prelude::Entity for SubAllocs {
+    type Builder = SubAllocsBuilder;
+    const NAME: &'static str = "SubAllocs";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Bool(data)
+        SubAllocs(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2466,21 +2927,21 @@ impl molecule::prelude::Entity for Bool {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BoolReader::from_slice(slice).map(|reader| reader.to_entity())
+        SubAllocsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BoolReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SubAllocsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BoolReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BoolReader<'r> {
+pub struct SubAllocsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SubAllocsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2489,40 +2950,69 @@ impl<'r> ::core::fmt::LowerHex for BoolReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BoolReader<'r> {
+impl<'r> ::core::fmt::Debug for SubAllocsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BoolReader<'r> {
+impl<'r> ::core::fmt::Display for SubAllocsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl<'r> BoolReader<'r> {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl<'r> SubAllocsReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> BoolUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => TrueReader::new_unchecked(inner).into(),
-            1 => FalseReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SubAllocReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SubAllocReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SubAllocReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SubAllocReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BoolReader<'r> {
-    type Entity = Bool;
-    const NAME: &'static str = "BoolReader";
+impl<'r> molecule::prelude::Reader<'r> for SubAllocsReader<'r> {
+    type Entity = SubAllocs;
+    const NAME: &'static str = "SubAllocsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BoolReader(slice)
+        SubAllocsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2533,177 +3023,299 @@ impl<'r> molecule::prelude::Reader<'r> for BoolReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => TrueReader::verify(inner_slice, compatible),
-            1 => FalseReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-        }?;
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            SubAllocReader::verify(&slice[start..end], compatible)?;
+        }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct BoolBuilder(pub(crate) BoolUnion);
-impl BoolBuilder {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<BoolUnion>,
-    {
-        self.0 = v.into();
+pub struct SubAllocsBuilder(pub(crate) Vec<SubAlloc>);
+impl SubAllocsBuilder {
+    pub fn set(mut self, v: Vec<SubAlloc>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: SubAlloc) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = SubAlloc>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: SubAlloc) -> Option<SubAlloc> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for BoolBuilder {
-    type Entity = Bool;
-    const NAME: &'static str = "BoolBuilder";
+impl molecule::prelude::Builder for SubAllocsBuilder {
+    type Entity = SubAllocs;
+    const NAME: &'static str = "SubAllocsBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Bool::new_unchecked(inner.into())
+        SubAllocs::new_unchecked(inner.into())
     }
 }
-#[derive(Debug, Clone)]
-pub enum BoolUnion {
-    True(True),
-    False(False),
+pub struct SubAllocsIterator(SubAllocs, usize, usize);
+impl ::core::iter::Iterator for SubAllocsIterator {
+    type Item = SubAlloc;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
 }
-#[derive(Debug, Clone, Copy)]
-pub enum BoolUnionReader<'r> {
-    True(TrueReader<'r>),
-    False(FalseReader<'r>),
+impl ::core::iter::ExactSizeIterator for SubAllocsIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
 }
-impl ::core::default::Default for BoolUnion {
-    fn default() -> Self {
-        BoolUnion::True(::core::default::Default::default())
+impl ::core::iter::IntoIterator for SubAllocs {
+    type Item = SubAlloc;
+    type IntoIter = SubAllocsIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SubAllocsIterator(self, 0, len)
     }
 }
-impl ::core::fmt::Display for BoolUnion {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BoolUnion::True(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, True::NAME, item)
-            }
-            BoolUnion::False(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, False::NAME, item)
-            }
-        }
+impl<'r> SubAllocsReader<'r> {
+    pub fn iter<'t>(&'t self) -> SubAllocsReaderIterator<'t, 'r> {
+        SubAllocsReaderIterator(&self, 0, self.len())
     }
 }
-impl<'r> ::core::fmt::Display for BoolUnionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BoolUnionReader::True(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, True::NAME, item)
-            }
-            BoolUnionReader::False(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, False::NAME, item)
-            }
+pub struct SubAllocsReaderIterator<'t, 'r>(&'t SubAllocsReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SubAllocsReaderIterator<'t, 'r> {
+    type Item = SubAllocReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
         }
     }
 }
-impl BoolUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BoolUnion::True(ref item) => write!(f, "{}", item),
-            BoolUnion::False(ref item) => write!(f, "{}", item),
-        }
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SubAllocsReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
-impl<'r> BoolUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BoolUnionReader::True(ref item) => write!(f, "{}", item),
-            BoolUnionReader::False(ref item) => write!(f, "{}", item),
+#[derive(Clone)]
+pub struct HTLC(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HTLC {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
         }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::convert::From<True> for BoolUnion {
-    fn from(item: True) -> Self {
-        BoolUnion::True(item)
+impl ::core::fmt::Debug for HTLC {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::convert::From<False> for BoolUnion {
-    fn from(item: False) -> Self {
-        BoolUnion::False(item)
+impl ::core::fmt::Display for HTLC {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sender", self.sender())?;
+        write!(f, ", {}: {}", "receiver", self.receiver())?;
+        write!(f, ", {}: {}", "hash_lock", self.hash_lock())?;
+        write!(f, ", {}: {}", "timeout", self.timeout())?;
+        write!(f, ", {}: {}", "ckbytes", self.ckbytes())?;
+        write!(f, ", {}: {}", "sudts", self.sudts())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> ::core::convert::From<TrueReader<'r>> for BoolUnionReader<'r> {
-    fn from(item: TrueReader<'r>) -> Self {
-        BoolUnionReader::True(item)
+impl ::core::default::Default for HTLC {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            82, 0, 0, 0, 28, 0, 0, 0, 29, 0, 0, 0, 30, 0, 0, 0, 62, 0, 0, 0, 70, 0, 0, 0, 78, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        HTLC::new_unchecked(v.into())
     }
 }
-impl<'r> ::core::convert::From<FalseReader<'r>> for BoolUnionReader<'r> {
-    fn from(item: FalseReader<'r>) -> Self {
-        BoolUnionReader::False(item)
+impl HTLC {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-}
-impl BoolUnion {
-    pub const NAME: &'static str = "BoolUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            BoolUnion::True(item) => item.as_bytes(),
-            BoolUnion::False(item) => item.as_bytes(),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            BoolUnion::True(item) => item.as_slice(),
-            BoolUnion::False(item) => item.as_slice(),
-        }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            BoolUnion::True(_) => 0,
-            BoolUnion::False(_) => 1,
-        }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            BoolUnion::True(_) => "True",
-            BoolUnion::False(_) => "False",
-        }
+    pub fn sender(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
     }
-    pub fn as_reader<'r>(&'r self) -> BoolUnionReader<'r> {
-        match self {
-            BoolUnion::True(item) => item.as_reader().into(),
-            BoolUnion::False(item) => item.as_reader().into(),
+    pub fn receiver(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn hash_lock(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn timeout(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn ckbytes(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sudts(&self) -> SUDTAmounts {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            SUDTAmounts::new_unchecked(self.0.slice(start..end))
+        } else {
+            SUDTAmounts::new_unchecked(self.0.slice(start..))
         }
     }
+    pub fn as_reader<'r>(&'r self) -> HTLCReader<'r> {
+        HTLCReader::new_unchecked(self.as_slice())
+    }
 }
-impl<'r> BoolUnionReader<'r> {
-    pub const NAME: &'r str = "BoolUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            BoolUnionReader::True(item) => item.as_slice(),
-            BoolUnionReader::False(item) => item.as_slice(),
-        }
+impl molecule::prelude::Entity for HTLC {
+    type Builder = HTLCBuilder;
+    const NAME: &'static str = "HTLC";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HTLC(data)
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            BoolUnionReader::True(_) => 0,
-            BoolUnionReader::False(_) => 1,
-        }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            BoolUnionReader::True(_) => "True",
-            BoolUnionReader::False(_) => "False",
-        }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HTLCReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HTLCReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .sender(self.sender())
+            .receiver(self.receiver())
+            .hash_lock(self.hash_lock())
+            .timeout(self.timeout())
+            .ckbytes(self.ckbytes())
+            .sudts(self.sudts())
     }
 }
-#[derive(Clone)]
-pub struct A(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for A {
+#[derive(Clone, Copy)]
+pub struct HTLCReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HTLCReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2712,162 +3324,229 @@ impl ::core::fmt::LowerHex for A {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for A {
+impl<'r> ::core::fmt::Debug for HTLCReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for A {
+impl<'r> ::core::fmt::Display for HTLCReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
-    }
-}
-impl ::core::default::Default for A {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        A::new_unchecked(v.into())
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sender", self.sender())?;
+        write!(f, ", {}: {}", "receiver", self.receiver())?;
+        write!(f, ", {}: {}", "hash_lock", self.hash_lock())?;
+        write!(f, ", {}: {}", "timeout", self.timeout())?;
+        write!(f, ", {}: {}", "ckbytes", self.ckbytes())?;
+        write!(f, ", {}: {}", "sudts", self.sudts())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl A {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
-    }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+impl<'r> HTLCReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn as_reader<'r>(&'r self) -> AReader<'r> {
-        AReader::new_unchecked(self.as_slice())
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-}
-impl molecule::prelude::Entity for A {
-    type Builder = ABuilder;
-    const NAME: &'static str = "A";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        A(data)
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn sender(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn receiver(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn hash_lock(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn timeout(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+    pub fn ckbytes(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-}
-#[derive(Clone, Copy)]
-pub struct AReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
+    pub fn sudts(&self) -> SUDTAmountsReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            SUDTAmountsReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SUDTAmountsReader::new_unchecked(&self.as_slice()[start..])
         }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl<'r> ::core::fmt::Debug for AReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
-    }
-}
-impl<'r> ::core::fmt::Display for AReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
-    }
-}
-impl<'r> AReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
-    }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AReader<'r> {
-    type Entity = A;
-    const NAME: &'static str = "AReader";
+impl<'r> molecule::prelude::Reader<'r> for HTLCReader<'r> {
+    type Entity = HTLC;
+    const NAME: &'static str = "HTLCReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AReader(slice)
+        HTLCReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        SUDTAmountsReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
-pub struct ABuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for ABuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+#[derive(Debug, Default)]
+pub struct HTLCBuilder {
+    pub(crate) sender: Byte,
+    pub(crate) receiver: Byte,
+    pub(crate) hash_lock: Byte32,
+    pub(crate) timeout: Uint64,
+    pub(crate) ckbytes: Uint64,
+    pub(crate) sudts: SUDTAmounts,
+}
+impl HTLCBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn sender(mut self, v: Byte) -> Self {
+        self.sender = v;
+        self
     }
-}
-impl ::core::default::Default for ABuilder {
-    fn default() -> Self {
-        ABuilder([Byte::default()])
+    pub fn receiver(mut self, v: Byte) -> Self {
+        self.receiver = v;
+        self
     }
-}
-impl ABuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
-        self.0 = v;
+    pub fn hash_lock(mut self, v: Byte32) -> Self {
+        self.hash_lock = v;
         self
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
+    pub fn timeout(mut self, v: Uint64) -> Self {
+        self.timeout = v;
+        self
+    }
+    pub fn ckbytes(mut self, v: Uint64) -> Self {
+        self.ckbytes = v;
+        self
+    }
+    pub fn sudts(mut self, v: SUDTAmounts) -> Self {
+        self.sudts = v;
         self
     }
 }
-impl molecule::prelude::Builder for ABuilder {
-    type Entity = A;
-    const NAME: &'static str = "ABuilder";
+impl molecule::prelude::Builder for HTLCBuilder {
+    type Entity = HTLC;
+    const NAME: &'static str = "HTLCBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.sender.as_slice().len()
+            + self.receiver.as_slice().len()
+            + self.hash_lock.as_slice().len()
+            + self.timeout.as_slice().len()
+            + self.ckbytes.as_slice().len()
+            + self.sudts.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.sender.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.receiver.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.hash_lock.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.timeout.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ckbytes.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sudts.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.sender.as_slice())?;
+        writer.write_all(self.receiver.as_slice())?;
+        writer.write_all(self.hash_lock.as_slice())?;
+        writer.write_all(self.timeout.as_slice())?;
+        writer.write_all(self.ckbytes.as_slice())?;
+        writer.write_all(self.sudts.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        A::new_unchecked(inner.into())
+        HTLC::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct B(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for B {
+pub struct HTLCs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HTLCs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2876,43 +3555,75 @@ impl ::core::fmt::LowerHex for B {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for B {
+impl ::core::fmt::Debug for HTLCs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for B {
+impl ::core::fmt::Display for HTLCs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for B {
+impl ::core::default::Default for HTLCs {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        B::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        HTLCs::new_unchecked(v.into())
     }
 }
-impl B {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
-    }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+impl HTLCs {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn as_reader<'r>(&'r self) -> BReader<'r> {
-        BReader::new_unchecked(self.as_slice())
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-}
-impl molecule::prelude::Entity for B {
-    type Builder = BBuilder;
-    const NAME: &'static str = "B";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        B(data)
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HTLC> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HTLC {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            HTLC::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            HTLC::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> HTLCsReader<'r> {
+        HTLCsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for HTLCs {
+    type Builder = HTLCsBuilder;
+    const NAME: &'static str = "HTLCs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HTLCs(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2921,21 +3632,21 @@ impl molecule::prelude::Entity for B {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BReader::from_slice(slice).map(|reader| reader.to_entity())
+        HTLCsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        HTLCsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BReader<'r> {
+pub struct HTLCsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HTLCsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2944,94 +3655,237 @@ impl<'r> ::core::fmt::LowerHex for BReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BReader<'r> {
+impl<'r> ::core::fmt::Debug for HTLCsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BReader<'r> {
+impl<'r> ::core::fmt::Display for HTLCsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl<'r> BReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> HTLCsReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HTLCReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HTLCReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            HTLCReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            HTLCReader::new_unchecked(&self.as_slice()[start..end])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BReader<'r> {
-    type Entity = B;
-    const NAME: &'static str = "BReader";
+impl<'r> molecule::prelude::Reader<'r> for HTLCsReader<'r> {
+    type Entity = HTLCs;
+    const NAME: &'static str = "HTLCsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BReader(slice)
+        HTLCsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            HTLCReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
-pub struct BBuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for BBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
-}
-impl ::core::default::Default for BBuilder {
-    fn default() -> Self {
-        BBuilder([Byte::default()])
-    }
-}
-impl BBuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
+#[derive(Debug, Default)]
+pub struct HTLCsBuilder(pub(crate) Vec<HTLC>);
+impl HTLCsBuilder {
+    pub fn set(mut self, v: Vec<HTLC>) -> Self {
         self.0 = v;
         self
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
+    pub fn push(mut self, v: HTLC) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = HTLC>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: HTLC) -> Option<HTLC> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for BBuilder {
-    type Entity = B;
-    const NAME: &'static str = "BBuilder";
+impl molecule::prelude::Builder for HTLCsBuilder {
+    type Entity = HTLCs;
+    const NAME: &'static str = "HTLCsBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        B::new_unchecked(inner.into())
+        HTLCs::new_unchecked(inner.into())
+    }
+}
+pub struct HTLCsIterator(HTLCs, usize, usize);
+impl ::core::iter::Iterator for HTLCsIterator {
+    type Item = HTLC;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for HTLCsIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for HTLCs {
+    type Item = HTLC;
+    type IntoIter = HTLCsIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        HTLCsIterator(self, 0, len)
+    }
+}
+impl<'r> HTLCsReader<'r> {
+    pub fn iter<'t>(&'t self) -> HTLCsReaderIterator<'t, 'r> {
+        HTLCsReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct HTLCsReaderIterator<'t, 'r>(&'t HTLCsReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for HTLCsReaderIterator<'t, 'r> {
+    type Item = HTLCReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for HTLCsReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct App(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for App {
+pub struct Balances(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Balances {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3040,219 +3894,38 @@ impl ::core::fmt::LowerHex for App {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for App {
+impl ::core::fmt::Debug for Balances {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for App {
+impl ::core::fmt::Display for Balances {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        if let Some(v) = self.to_opt() {
-            write!(f, "{}(Some({}))", Self::NAME, v)
-        } else {
-            write!(f, "{}(None)", Self::NAME)
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "ckbytes", self.ckbytes())?;
+        write!(f, ", {}: {}", "sudts", self.sudts())?;
+        write!(f, ", {}: {}", "locked", self.locked())?;
+        write!(f, ", {}: {}", "htlcs", self.htlcs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for App {
+impl ::core::default::Default for Balances {
     fn default() -> Self {
-        let v: Vec<u8> = vec![];
-        App::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            36, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+            0, 4, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        Balances::new_unchecked(v.into())
     }
 }
-impl App {
-    pub fn is_none(&self) -> bool {
-        self.0.is_empty()
-    }
-    pub fn is_some(&self) -> bool {
-        !self.0.is_empty()
-    }
-    pub fn to_opt(&self) -> Option<Bytes> {
-        if self.is_none() {
-            None
-        } else {
-            Some(Bytes::new_unchecked(self.0.clone()))
-        }
-    }
-    pub fn as_reader<'r>(&'r self) -> AppReader<'r> {
-        AppReader::new_unchecked(self.as_slice())
-    }
-}
-impl molecule::prelude::Entity for App {
-    type Builder = AppBuilder;
-    const NAME: &'static str = "App";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        App(data)
-    }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
-    }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
-    }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AppReader::from_slice(slice).map(|reader| reader.to_entity())
-    }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AppReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
-    }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
-    }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_opt())
-    }
-}
-#[derive(Clone, Copy)]
-pub struct AppReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AppReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl<'r> ::core::fmt::Debug for AppReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
-    }
-}
-impl<'r> ::core::fmt::Display for AppReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        if let Some(v) = self.to_opt() {
-            write!(f, "{}(Some({}))", Self::NAME, v)
-        } else {
-            write!(f, "{}(None)", Self::NAME)
-        }
-    }
-}
-impl<'r> AppReader<'r> {
-    pub fn is_none(&self) -> bool {
-        self.0.is_empty()
-    }
-    pub fn is_some(&self) -> bool {
-        !self.0.is_empty()
-    }
-    pub fn to_opt(&self) -> Option<BytesReader<'r>> {
-        if self.is_none() {
-            None
-        } else {
-            Some(BytesReader::new_unchecked(self.as_slice()))
-        }
-    }
-}
-impl<'r> molecule::prelude::Reader<'r> for AppReader<'r> {
-    type Entity = App;
-    const NAME: &'static str = "AppReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
-    }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        AppReader(slice)
-    }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
-    }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        if !slice.is_empty() {
-            BytesReader::verify(&slice[..], compatible)?;
-        }
-        Ok(())
-    }
-}
-#[derive(Debug, Default)]
-pub struct AppBuilder(pub(crate) Option<Bytes>);
-impl AppBuilder {
-    pub fn set(mut self, v: Option<Bytes>) -> Self {
-        self.0 = v;
-        self
-    }
-}
-impl molecule::prelude::Builder for AppBuilder {
-    type Entity = App;
-    const NAME: &'static str = "AppBuilder";
-    fn expected_length(&self) -> usize {
-        self.0
-            .as_ref()
-            .map(|ref inner| inner.as_slice().len())
-            .unwrap_or(0)
-    }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        self.0
-            .as_ref()
-            .map(|ref inner| writer.write_all(inner.as_slice()))
-            .unwrap_or(Ok(()))
-    }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        App::new_unchecked(inner.into())
-    }
-}
-#[derive(Clone)]
-pub struct Participant(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Participant {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl ::core::fmt::Debug for Participant {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
-    }
-}
-impl ::core::fmt::Display for Participant {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "payment_script_hash",
-            self.payment_script_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "payment_min_capacity",
-            self.payment_min_capacity()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "unlock_script_hash",
-            self.unlock_script_hash()
-        )?;
-        write!(f, ", {}: {}", "pub_key", self.pub_key())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl ::core::default::Default for Participant {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            125, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        Participant::new_unchecked(v.into())
-    }
-}
-impl Participant {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl Balances {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
     pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
@@ -3267,43 +3940,43 @@ impl Participant {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn payment_script_hash(&self) -> Byte32 {
+    pub fn ckbytes(&self) -> CKByteDistribution {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+        CKByteDistribution::new_unchecked(self.0.slice(start..end))
     }
-    pub fn payment_min_capacity(&self) -> Uint64 {
+    pub fn sudts(&self) -> SUDTAllocation {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        SUDTAllocation::new_unchecked(self.0.slice(start..end))
     }
-    pub fn unlock_script_hash(&self) -> Byte32 {
+    pub fn locked(&self) -> SubAllocs {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+        SubAllocs::new_unchecked(self.0.slice(start..end))
     }
-    pub fn pub_key(&self) -> SEC1EncodedPubKey {
+    pub fn htlcs(&self) -> HTLCs {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[20..]) as usize;
-            SEC1EncodedPubKey::new_unchecked(self.0.slice(start..end))
+            HTLCs::new_unchecked(self.0.slice(start..end))
         } else {
-            SEC1EncodedPubKey::new_unchecked(self.0.slice(start..))
+            HTLCs::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ParticipantReader<'r> {
-        ParticipantReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BalancesReader<'r> {
+        BalancesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Participant {
-    type Builder = ParticipantBuilder;
-    const NAME: &'static str = "Participant";
+impl molecule::prelude::Entity for Balances {
+    type Builder = BalancesBuilder;
+    const NAME: &'static str = "Balances";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Participant(data)
+        Balances(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3312,25 +3985,25 @@ impl molecule::prelude::Entity for Participant {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ParticipantReader::from_slice(slice).map(|reader| reader.to_entity())
+        BalancesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ParticipantReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BalancesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .payment_script_hash(self.payment_script_hash())
-            .payment_min_capacity(self.payment_min_capacity())
-            .unlock_script_hash(self.unlock_script_hash())
-            .pub_key(self.pub_key())
+            .ckbytes(self.ckbytes())
+            .sudts(self.sudts())
+            .locked(self.locked())
+            .htlcs(self.htlcs())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ParticipantReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ParticipantReader<'r> {
+pub struct BalancesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BalancesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3339,33 +4012,18 @@ impl<'r> ::core::fmt::LowerHex for ParticipantReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ParticipantReader<'r> {
+impl<'r> ::core::fmt::Debug for BalancesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ParticipantReader<'r> {
+impl<'r> ::core::fmt::Display for BalancesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "payment_script_hash",
-            self.payment_script_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "payment_min_capacity",
-            self.payment_min_capacity()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "unlock_script_hash",
-            self.unlock_script_hash()
-        )?;
-        write!(f, ", {}: {}", "pub_key", self.pub_key())?;
+        write!(f, "{}: {}", "ckbytes", self.ckbytes())?;
+        write!(f, ", {}: {}", "sudts", self.sudts())?;
+        write!(f, ", {}: {}", "locked", self.locked())?;
+        write!(f, ", {}: {}", "htlcs", self.htlcs())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -3373,7 +4031,7 @@ impl<'r> ::core::fmt::Display for ParticipantReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ParticipantReader<'r> {
+impl<'r> BalancesReader<'r> {
     pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -3391,43 +4049,43 @@ impl<'r> ParticipantReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn payment_script_hash(&self) -> Byte32Reader<'r> {
+    pub fn ckbytes(&self) -> CKByteDistributionReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        CKByteDistributionReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn payment_min_capacity(&self) -> Uint64Reader<'r> {
+    pub fn sudts(&self) -> SUDTAllocationReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        SUDTAllocationReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn unlock_script_hash(&self) -> Byte32Reader<'r> {
+    pub fn locked(&self) -> SubAllocsReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        SubAllocsReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn pub_key(&self) -> SEC1EncodedPubKeyReader<'r> {
+    pub fn htlcs(&self) -> HTLCsReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[20..]) as usize;
-            SEC1EncodedPubKeyReader::new_unchecked(&self.as_slice()[start..end])
+            HTLCsReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            SEC1EncodedPubKeyReader::new_unchecked(&self.as_slice()[start..])
+            HTLCsReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ParticipantReader<'r> {
-    type Entity = Participant;
-    const NAME: &'static str = "ParticipantReader";
+impl<'r> molecule::prelude::Reader<'r> for BalancesReader<'r> {
+    type Entity = Balances;
+    const NAME: &'static str = "BalancesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ParticipantReader(slice)
+        BalancesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -3469,80 +4127,80 @@ impl<'r> molecule::prelude::Reader<'r> for ParticipantReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        SEC1EncodedPubKeyReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        CKByteDistributionReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SUDTAllocationReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SubAllocsReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        HTLCsReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ParticipantBuilder {
-    pub(crate) payment_script_hash: Byte32,
-    pub(crate) payment_min_capacity: Uint64,
-    pub(crate) unlock_script_hash: Byte32,
-    pub(crate) pub_key: SEC1EncodedPubKey,
+pub struct BalancesBuilder {
+    pub(crate) ckbytes: CKByteDistribution,
+    pub(crate) sudts: SUDTAllocation,
+    pub(crate) locked: SubAllocs,
+    pub(crate) htlcs: HTLCs,
 }
-impl ParticipantBuilder {
+impl BalancesBuilder {
     pub const FIELD_COUNT: usize = 4;
-    pub fn payment_script_hash(mut self, v: Byte32) -> Self {
-        self.payment_script_hash = v;
+    pub fn ckbytes(mut self, v: CKByteDistribution) -> Self {
+        self.ckbytes = v;
         self
     }
-    pub fn payment_min_capacity(mut self, v: Uint64) -> Self {
-        self.payment_min_capacity = v;
+    pub fn sudts(mut self, v: SUDTAllocation) -> Self {
+        self.sudts = v;
         self
     }
-    pub fn unlock_script_hash(mut self, v: Byte32) -> Self {
-        self.unlock_script_hash = v;
+    pub fn locked(mut self, v: SubAllocs) -> Self {
+        self.locked = v;
         self
     }
-    pub fn pub_key(mut self, v: SEC1EncodedPubKey) -> Self {
-        self.pub_key = v;
+    pub fn htlcs(mut self, v: HTLCs) -> Self {
+        self.htlcs = v;
         self
     }
 }
-impl molecule::prelude::Builder for ParticipantBuilder {
-    type Entity = Participant;
-    const NAME: &'static str = "ParticipantBuilder";
+impl molecule::prelude::Builder for BalancesBuilder {
+    type Entity = Balances;
+    const NAME: &'static str = "BalancesBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.payment_script_hash.as_slice().len()
-            + self.payment_min_capacity.as_slice().len()
-            + self.unlock_script_hash.as_slice().len()
-            + self.pub_key.as_slice().len()
+            + self.ckbytes.as_slice().len()
+            + self.sudts.as_slice().len()
+            + self.locked.as_slice().len()
+            + self.htlcs.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.payment_script_hash.as_slice().len();
+        total_size += self.ckbytes.as_slice().len();
         offsets.push(total_size);
-        total_size += self.payment_min_capacity.as_slice().len();
+        total_size += self.sudts.as_slice().len();
         offsets.push(total_size);
-        total_size += self.unlock_script_hash.as_slice().len();
+        total_size += self.locked.as_slice().len();
         offsets.push(total_size);
-        total_size += self.pub_key.as_slice().len();
+        total_size += self.htlcs.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.payment_script_hash.as_slice())?;
-        writer.write_all(self.payment_min_capacity.as_slice())?;
-        writer.write_all(self.unlock_script_hash.as_slice())?;
-        writer.write_all(self.pub_key.as_slice())?;
+        writer.write_all(self.ckbytes.as_slice())?;
+        writer.write_all(self.sudts.as_slice())?;
+        writer.write_all(self.locked.as_slice())?;
+        writer.write_all(self.htlcs.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Participant::new_unchecked(inner.into())
+        Balances::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ChannelParameters(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelParameters {
+pub struct True(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for True {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3551,130 +4209,43 @@ impl ::core::fmt::LowerHex for ChannelParameters {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelParameters {
+impl ::core::fmt::Debug for True {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelParameters {
+impl ::core::fmt::Display for True {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "party_a", self.party_a())?;
-        write!(f, ", {}: {}", "party_b", self.party_b())?;
-        write!(f, ", {}: {}", "nonce", self.nonce())?;
-        write!(
-            f,
-            ", {}: {}",
-            "challenge_duration",
-            self.challenge_duration()
-        )?;
-        write!(f, ", {}: {}", "app", self.app())?;
-        write!(f, ", {}: {}", "is_ledger_channel", self.is_ledger_channel())?;
-        write!(
-            f,
-            ", {}: {}",
-            "is_virtual_channel",
-            self.is_virtual_channel()
-        )?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for ChannelParameters {
+impl ::core::default::Default for True {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            76, 1, 0, 0, 32, 0, 0, 0, 157, 0, 0, 0, 26, 1, 0, 0, 58, 1, 0, 0, 66, 1, 0, 0, 66, 1,
-            0, 0, 71, 1, 0, 0, 125, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0,
-            0, 60, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        ChannelParameters::new_unchecked(v.into())
+        let v: Vec<u8> = vec![0];
+        True::new_unchecked(v.into())
     }
 }
-impl ChannelParameters {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn party_a(&self) -> Participant {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Participant::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn party_b(&self) -> Participant {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Participant::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn nonce(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn challenge_duration(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn app(&self) -> App {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        App::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn is_ledger_channel(&self) -> Bool {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Bool::new_unchecked(self.0.slice(start..end))
+impl True {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn is_virtual_channel(&self) -> Bool {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            Bool::new_unchecked(self.0.slice(start..end))
-        } else {
-            Bool::new_unchecked(self.0.slice(start..))
-        }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelParametersReader<'r> {
-        ChannelParametersReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> TrueReader<'r> {
+        TrueReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelParameters {
-    type Builder = ChannelParametersBuilder;
-    const NAME: &'static str = "ChannelParameters";
+impl molecule::prelude::Entity for True {
+    type Builder = TrueBuilder;
+    const NAME: &'static str = "True";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelParameters(data)
+        True(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3683,28 +4254,21 @@ impl molecule::prelude::Entity for ChannelParameters {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelParametersReader::from_slice(slice).map(|reader| reader.to_entity())
+        TrueReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelParametersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TrueReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .party_a(self.party_a())
-            .party_b(self.party_b())
-            .nonce(self.nonce())
-            .challenge_duration(self.challenge_duration())
-            .app(self.app())
-            .is_ledger_channel(self.is_ledger_channel())
-            .is_virtual_channel(self.is_virtual_channel())
+        Self::new_builder().set([self.nth0()])
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelParametersReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelParametersReader<'r> {
+pub struct TrueReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TrueReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3713,111 +4277,6897 @@ impl<'r> ::core::fmt::LowerHex for ChannelParametersReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelParametersReader<'r> {
+impl<'r> ::core::fmt::Debug for TrueReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelParametersReader<'r> {
+impl<'r> ::core::fmt::Display for TrueReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "party_a", self.party_a())?;
-        write!(f, ", {}: {}", "party_b", self.party_b())?;
-        write!(f, ", {}: {}", "nonce", self.nonce())?;
-        write!(
-            f,
-            ", {}: {}",
-            "challenge_duration",
-            self.challenge_duration()
-        )?;
-        write!(f, ", {}: {}", "app", self.app())?;
-        write!(f, ", {}: {}", "is_ledger_channel", self.is_ledger_channel())?;
-        write!(
-            f,
-            ", {}: {}",
-            "is_virtual_channel",
-            self.is_virtual_channel()
-        )?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> ChannelParametersReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl<'r> TrueReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+}
+impl<'r> molecule::prelude::Reader<'r> for TrueReader<'r> {
+    type Entity = True;
+    const NAME: &'static str = "TrueReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TrueReader(slice)
     }
-    pub fn party_a(&self) -> ParticipantReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct TrueBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for TrueBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for TrueBuilder {
+    fn default() -> Self {
+        TrueBuilder([Byte::default()])
+    }
+}
+impl TrueBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TrueBuilder {
+    type Entity = True;
+    const NAME: &'static str = "TrueBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        True::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct False(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for False {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for False {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for False {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for False {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        False::new_unchecked(v.into())
+    }
+}
+impl False {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> FalseReader<'r> {
+        FalseReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for False {
+    type Builder = FalseBuilder;
+    const NAME: &'static str = "False";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        False(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FalseReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FalseReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FalseReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FalseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FalseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FalseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> FalseReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FalseReader<'r> {
+    type Entity = False;
+    const NAME: &'static str = "FalseReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FalseReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct FalseBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for FalseBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for FalseBuilder {
+    fn default() -> Self {
+        FalseBuilder([Byte::default()])
+    }
+}
+impl FalseBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FalseBuilder {
+    type Entity = False;
+    const NAME: &'static str = "FalseBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        False::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Bool(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Bool {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Bool {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Bool {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for Bool {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0];
+        Bool::new_unchecked(v.into())
+    }
+}
+impl Bool {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> BoolUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => True::new_unchecked(inner).into(),
+            1 => False::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BoolReader<'r> {
+        BoolReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Bool {
+    type Builder = BoolBuilder;
+    const NAME: &'static str = "Bool";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Bool(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BoolReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BoolReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BoolReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BoolReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BoolReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BoolReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> BoolReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> BoolUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => TrueReader::new_unchecked(inner).into(),
+            1 => FalseReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BoolReader<'r> {
+    type Entity = Bool;
+    const NAME: &'static str = "BoolReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BoolReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => TrueReader::verify(inner_slice, compatible),
+            1 => FalseReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BoolBuilder(pub(crate) BoolUnion);
+impl BoolBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<BoolUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for BoolBuilder {
+    type Entity = Bool;
+    const NAME: &'static str = "BoolBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Bool::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum BoolUnion {
+    True(True),
+    False(False),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum BoolUnionReader<'r> {
+    True(TrueReader<'r>),
+    False(FalseReader<'r>),
+}
+impl ::core::default::Default for BoolUnion {
+    fn default() -> Self {
+        BoolUnion::True(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for BoolUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BoolUnion::True(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, True::NAME, item)
+            }
+            BoolUnion::False(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, False::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for BoolUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BoolUnionReader::True(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, True::NAME, item)
+            }
+            BoolUnionReader::False(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, False::NAME, item)
+            }
+        }
+    }
+}
+impl BoolUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BoolUnion::True(ref item) => write!(f, "{}", item),
+            BoolUnion::False(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> BoolUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BoolUnionReader::True(ref item) => write!(f, "{}", item),
+            BoolUnionReader::False(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<True> for BoolUnion {
+    fn from(item: True) -> Self {
+        BoolUnion::True(item)
+    }
+}
+impl ::core::convert::From<False> for BoolUnion {
+    fn from(item: False) -> Self {
+        BoolUnion::False(item)
+    }
+}
+impl<'r> ::core::convert::From<TrueReader<'r>> for BoolUnionReader<'r> {
+    fn from(item: TrueReader<'r>) -> Self {
+        BoolUnionReader::True(item)
+    }
+}
+impl<'r> ::core::convert::From<FalseReader<'r>> for BoolUnionReader<'r> {
+    fn from(item: FalseReader<'r>) -> Self {
+        BoolUnionReader::False(item)
+    }
+}
+impl BoolUnion {
+    pub const NAME: &'static str = "BoolUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            BoolUnion::True(item) => item.as_bytes(),
+            BoolUnion::False(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            BoolUnion::True(item) => item.as_slice(),
+            BoolUnion::False(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            BoolUnion::True(_) => 0,
+            BoolUnion::False(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            BoolUnion::True(_) => "True",
+            BoolUnion::False(_) => "False",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BoolUnionReader<'r> {
+        match self {
+            BoolUnion::True(item) => item.as_reader().into(),
+            BoolUnion::False(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> BoolUnionReader<'r> {
+    pub const NAME: &'r str = "BoolUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            BoolUnionReader::True(item) => item.as_slice(),
+            BoolUnionReader::False(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            BoolUnionReader::True(_) => 0,
+            BoolUnionReader::False(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            BoolUnionReader::True(_) => "True",
+            BoolUnionReader::False(_) => "False",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct A(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for A {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for A {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for A {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for A {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        A::new_unchecked(v.into())
+    }
+}
+impl A {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> AReader<'r> {
+        AReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for A {
+    type Builder = ABuilder;
+    const NAME: &'static str = "A";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        A(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> AReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AReader<'r> {
+    type Entity = A;
+    const NAME: &'static str = "AReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct ABuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for ABuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for ABuilder {
+    fn default() -> Self {
+        ABuilder([Byte::default()])
+    }
+}
+impl ABuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ABuilder {
+    type Entity = A;
+    const NAME: &'static str = "ABuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        A::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct B(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for B {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for B {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for B {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for B {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        B::new_unchecked(v.into())
+    }
+}
+impl B {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> BReader<'r> {
+        BReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for B {
+    type Builder = BBuilder;
+    const NAME: &'static str = "B";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        B(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> BReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BReader<'r> {
+    type Entity = B;
+    const NAME: &'static str = "BReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct BBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for BBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for BBuilder {
+    fn default() -> Self {
+        BBuilder([Byte::default()])
+    }
+}
+impl BBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BBuilder {
+    type Entity = B;
+    const NAME: &'static str = "BBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        B::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct AppScript(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AppScript {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for AppScript {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for AppScript {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "code_hash", self.code_hash())?;
+        write!(f, ", {}: {}", "hash_type", self.hash_type())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for AppScript {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        AppScript::new_unchecked(v.into())
+    }
+}
+impl AppScript {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn code_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn hash_type(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn as_reader<'r>(&'r self) -> AppScriptReader<'r> {
+        AppScriptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for AppScript {
+    type Builder = AppScriptBuilder;
+    const NAME: &'static str = "AppScript";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        AppScript(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AppScriptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AppScriptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .code_hash(self.code_hash())
+            .hash_type(self.hash_type())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AppScriptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AppScriptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AppScriptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AppScriptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "code_hash", self.code_hash())?;
+        write!(f, ", {}: {}", "hash_type", self.hash_type())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> AppScriptReader<'r> {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn code_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn hash_type(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AppScriptReader<'r> {
+    type Entity = AppScript;
+    const NAME: &'static str = "AppScriptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AppScriptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AppScriptBuilder {
+    pub(crate) code_hash: Byte32,
+    pub(crate) hash_type: Byte,
+}
+impl AppScriptBuilder {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn code_hash(mut self, v: Byte32) -> Self {
+        self.code_hash = v;
+        self
+    }
+    pub fn hash_type(mut self, v: Byte) -> Self {
+        self.hash_type = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AppScriptBuilder {
+    type Entity = AppScript;
+    const NAME: &'static str = "AppScriptBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.code_hash.as_slice())?;
+        writer.write_all(self.hash_type.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AppScript::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct App(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for App {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for App {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for App {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for App {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        App::new_unchecked(v.into())
+    }
+}
+impl App {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<AppScript> {
+        if self.is_none() {
+            None
+        } else {
+            Some(AppScript::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AppReader<'r> {
+        AppReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for App {
+    type Builder = AppBuilder;
+    const NAME: &'static str = "App";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        App(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AppReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AppReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AppReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AppReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AppReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AppReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> AppReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<AppScriptReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(AppScriptReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AppReader<'r> {
+    type Entity = App;
+    const NAME: &'static str = "AppReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AppReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            AppScriptReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AppBuilder(pub(crate) Option<AppScript>);
+impl AppBuilder {
+    pub fn set(mut self, v: Option<AppScript>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AppBuilder {
+    type Entity = App;
+    const NAME: &'static str = "AppBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        App::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Participant(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Participant {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Participant {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Participant {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "payment_script_hash",
+            self.payment_script_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "payment_min_capacity",
+            self.payment_min_capacity()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "unlock_script_hash",
+            self.unlock_script_hash()
+        )?;
+        write!(f, ", {}: {}", "pub_key", self.pub_key())?;
+        write!(f, ", {}: {}", "delegates", self.delegates())?;
+        write!(f, ", {}: {}", "sig_scheme", self.sig_scheme())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Participant {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            138, 0, 0, 0, 28, 0, 0, 0, 60, 0, 0, 0, 68, 0, 0, 0, 100, 0, 0, 0, 133, 0, 0, 0, 137,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Participant::new_unchecked(v.into())
+    }
+}
+impl Participant {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn payment_script_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn payment_min_capacity(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn unlock_script_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pub_key(&self) -> SEC1EncodedPubKey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        SEC1EncodedPubKey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegates(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_scheme(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ParticipantReader<'r> {
+        ParticipantReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Participant {
+    type Builder = ParticipantBuilder;
+    const NAME: &'static str = "Participant";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Participant(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ParticipantReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ParticipantReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .payment_script_hash(self.payment_script_hash())
+            .payment_min_capacity(self.payment_min_capacity())
+            .unlock_script_hash(self.unlock_script_hash())
+            .pub_key(self.pub_key())
+            .delegates(self.delegates())
+            .sig_scheme(self.sig_scheme())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ParticipantReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ParticipantReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ParticipantReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ParticipantReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "payment_script_hash",
+            self.payment_script_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "payment_min_capacity",
+            self.payment_min_capacity()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "unlock_script_hash",
+            self.unlock_script_hash()
+        )?;
+        write!(f, ", {}: {}", "pub_key", self.pub_key())?;
+        write!(f, ", {}: {}", "delegates", self.delegates())?;
+        write!(f, ", {}: {}", "sig_scheme", self.sig_scheme())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ParticipantReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn payment_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn payment_min_capacity(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn unlock_script_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pub_key(&self) -> SEC1EncodedPubKeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        SEC1EncodedPubKeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegates(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_scheme(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ParticipantReader<'r> {
+    type Entity = Participant;
+    const NAME: &'static str = "ParticipantReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ParticipantReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        SEC1EncodedPubKeyReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ParticipantBuilder {
+    pub(crate) payment_script_hash: Byte32,
+    pub(crate) payment_min_capacity: Uint64,
+    pub(crate) unlock_script_hash: Byte32,
+    pub(crate) pub_key: SEC1EncodedPubKey,
+    pub(crate) delegates: Byte32Vec,
+    pub(crate) sig_scheme: Byte,
+}
+impl ParticipantBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn payment_script_hash(mut self, v: Byte32) -> Self {
+        self.payment_script_hash = v;
+        self
+    }
+    pub fn payment_min_capacity(mut self, v: Uint64) -> Self {
+        self.payment_min_capacity = v;
+        self
+    }
+    pub fn unlock_script_hash(mut self, v: Byte32) -> Self {
+        self.unlock_script_hash = v;
+        self
+    }
+    pub fn pub_key(mut self, v: SEC1EncodedPubKey) -> Self {
+        self.pub_key = v;
+        self
+    }
+    pub fn delegates(mut self, v: Byte32Vec) -> Self {
+        self.delegates = v;
+        self
+    }
+    pub fn sig_scheme(mut self, v: Byte) -> Self {
+        self.sig_scheme = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ParticipantBuilder {
+    type Entity = Participant;
+    const NAME: &'static str = "ParticipantBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.payment_script_hash.as_slice().len()
+            + self.payment_min_capacity.as_slice().len()
+            + self.unlock_script_hash.as_slice().len()
+            + self.pub_key.as_slice().len()
+            + self.delegates.as_slice().len()
+            + self.sig_scheme.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.payment_script_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.payment_min_capacity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.unlock_script_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pub_key.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegates.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_scheme.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.payment_script_hash.as_slice())?;
+        writer.write_all(self.payment_min_capacity.as_slice())?;
+        writer.write_all(self.unlock_script_hash.as_slice())?;
+        writer.write_all(self.pub_key.as_slice())?;
+        writer.write_all(self.delegates.as_slice())?;
+        writer.write_all(self.sig_scheme.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Participant::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Participants(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Participants {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Participants {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Participants {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for Participants {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        Participants::new_unchecked(v.into())
+    }
+}
+impl Participants {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Participant> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Participant {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            Participant::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            Participant::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ParticipantsReader<'r> {
+        ParticipantsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Participants {
+    type Builder = ParticipantsBuilder;
+    const NAME: &'static str = "Participants";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Participants(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ParticipantsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ParticipantsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ParticipantsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ParticipantsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ParticipantsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ParticipantsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> ParticipantsReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ParticipantReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ParticipantReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            ParticipantReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            ParticipantReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ParticipantsReader<'r> {
+    type Entity = Participants;
+    const NAME: &'static str = "ParticipantsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ParticipantsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            ParticipantReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ParticipantsBuilder(pub(crate) Vec<Participant>);
+impl ParticipantsBuilder {
+    pub fn set(mut self, v: Vec<Participant>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Participant) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Participant>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Participant) -> Option<Participant> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for ParticipantsBuilder {
+    type Entity = Participants;
+    const NAME: &'static str = "ParticipantsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Participants::new_unchecked(inner.into())
+    }
+}
+pub struct ParticipantsIterator(Participants, usize, usize);
+impl ::core::iter::Iterator for ParticipantsIterator {
+    type Item = Participant;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for ParticipantsIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for Participants {
+    type Item = Participant;
+    type IntoIter = ParticipantsIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        ParticipantsIterator(self, 0, len)
+    }
+}
+impl<'r> ParticipantsReader<'r> {
+    pub fn iter<'t>(&'t self) -> ParticipantsReaderIterator<'t, 'r> {
+        ParticipantsReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct ParticipantsReaderIterator<'t, 'r>(&'t ParticipantsReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for ParticipantsReaderIterator<'t, 'r> {
+    type Item = ParticipantReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for ParticipantsReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct FundingDeadline(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for FundingDeadline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for FundingDeadline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for FundingDeadline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for FundingDeadline {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        FundingDeadline::new_unchecked(v.into())
+    }
+}
+impl FundingDeadline {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Uint64> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint64::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> FundingDeadlineReader<'r> {
+        FundingDeadlineReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for FundingDeadline {
+    type Builder = FundingDeadlineBuilder;
+    const NAME: &'static str = "FundingDeadline";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        FundingDeadline(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FundingDeadlineReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FundingDeadlineReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FundingDeadlineReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FundingDeadlineReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FundingDeadlineReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FundingDeadlineReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> FundingDeadlineReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Uint64Reader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint64Reader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FundingDeadlineReader<'r> {
+    type Entity = FundingDeadline;
+    const NAME: &'static str = "FundingDeadlineReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FundingDeadlineReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            Uint64Reader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct FundingDeadlineBuilder(pub(crate) Option<Uint64>);
+impl FundingDeadlineBuilder {
+    pub fn set(mut self, v: Option<Uint64>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FundingDeadlineBuilder {
+    type Entity = FundingDeadline;
+    const NAME: &'static str = "FundingDeadlineBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        FundingDeadline::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct DisputeBond(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DisputeBond {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for DisputeBond {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for DisputeBond {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for DisputeBond {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        DisputeBond::new_unchecked(v.into())
+    }
+}
+impl DisputeBond {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Uint64> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint64::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> DisputeBondReader<'r> {
+        DisputeBondReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for DisputeBond {
+    type Builder = DisputeBondBuilder;
+    const NAME: &'static str = "DisputeBond";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        DisputeBond(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DisputeBondReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DisputeBondReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DisputeBondReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DisputeBondReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DisputeBondReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DisputeBondReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> DisputeBondReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Uint64Reader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint64Reader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DisputeBondReader<'r> {
+    type Entity = DisputeBond;
+    const NAME: &'static str = "DisputeBondReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DisputeBondReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            Uint64Reader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct DisputeBondBuilder(pub(crate) Option<Uint64>);
+impl DisputeBondBuilder {
+    pub fn set(mut self, v: Option<Uint64>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DisputeBondBuilder {
+    type Entity = DisputeBond;
+    const NAME: &'static str = "DisputeBondBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        DisputeBond::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ChannelParameters(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelParameters {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ChannelParameters {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ChannelParameters {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "parties", self.parties())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(
+            f,
+            ", {}: {}",
+            "challenge_duration",
+            self.challenge_duration()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "challenge_duration_metric",
+            self.challenge_duration_metric()
+        )?;
+        write!(f, ", {}: {}", "app", self.app())?;
+        write!(f, ", {}: {}", "is_ledger_channel", self.is_ledger_channel())?;
+        write!(
+            f,
+            ", {}: {}",
+            "is_virtual_channel",
+            self.is_virtual_channel()
+        )?;
+        write!(f, ", {}: {}", "funding_deadline", self.funding_deadline())?;
+        write!(f, ", {}: {}", "dispute_bond", self.dispute_bond())?;
+        write!(f, ", {}: {}", "dust_policy", self.dust_policy())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ChannelParameters {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            100, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 80, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 89, 0,
+            0, 0, 94, 0, 0, 0, 99, 0, 0, 0, 99, 0, 0, 0, 99, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ChannelParameters::new_unchecked(v.into())
+    }
+}
+impl ChannelParameters {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn parties(&self) -> Participants {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Participants::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn nonce(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn challenge_duration(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn challenge_duration_metric(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn app(&self) -> App {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        App::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn is_ledger_channel(&self) -> Bool {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Bool::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn is_virtual_channel(&self) -> Bool {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Bool::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn funding_deadline(&self) -> FundingDeadline {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        FundingDeadline::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn dispute_bond(&self) -> DisputeBond {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        DisputeBond::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn dust_policy(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ChannelParametersReader<'r> {
+        ChannelParametersReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ChannelParameters {
+    type Builder = ChannelParametersBuilder;
+    const NAME: &'static str = "ChannelParameters";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ChannelParameters(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelParametersReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelParametersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .parties(self.parties())
+            .nonce(self.nonce())
+            .challenge_duration(self.challenge_duration())
+            .challenge_duration_metric(self.challenge_duration_metric())
+            .app(self.app())
+            .is_ledger_channel(self.is_ledger_channel())
+            .is_virtual_channel(self.is_virtual_channel())
+            .funding_deadline(self.funding_deadline())
+            .dispute_bond(self.dispute_bond())
+            .dust_policy(self.dust_policy())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ChannelParametersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelParametersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ChannelParametersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ChannelParametersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "parties", self.parties())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(
+            f,
+            ", {}: {}",
+            "challenge_duration",
+            self.challenge_duration()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "challenge_duration_metric",
+            self.challenge_duration_metric()
+        )?;
+        write!(f, ", {}: {}", "app", self.app())?;
+        write!(f, ", {}: {}", "is_ledger_channel", self.is_ledger_channel())?;
+        write!(
+            f,
+            ", {}: {}",
+            "is_virtual_channel",
+            self.is_virtual_channel()
+        )?;
+        write!(f, ", {}: {}", "funding_deadline", self.funding_deadline())?;
+        write!(f, ", {}: {}", "dispute_bond", self.dispute_bond())?;
+        write!(f, ", {}: {}", "dust_policy", self.dust_policy())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ChannelParametersReader<'r> {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn parties(&self) -> ParticipantsReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ParticipantsReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn nonce(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn challenge_duration(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn challenge_duration_metric(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn app(&self) -> AppReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        AppReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn is_ledger_channel(&self) -> BoolReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BoolReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn is_virtual_channel(&self) -> BoolReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        BoolReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn funding_deadline(&self) -> FundingDeadlineReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        FundingDeadlineReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn dispute_bond(&self) -> DisputeBondReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        DisputeBondReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn dust_policy(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ChannelParametersReader<'r> {
+    type Entity = ChannelParameters;
+    const NAME: &'static str = "ChannelParametersReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ChannelParametersReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ParticipantsReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ByteReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        AppReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BoolReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        BoolReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        FundingDeadlineReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        DisputeBondReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        ByteReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ChannelParametersBuilder {
+    pub(crate) parties: Participants,
+    pub(crate) nonce: Byte32,
+    pub(crate) challenge_duration: Uint64,
+    pub(crate) challenge_duration_metric: Byte,
+    pub(crate) app: App,
+    pub(crate) is_ledger_channel: Bool,
+    pub(crate) is_virtual_channel: Bool,
+    pub(crate) funding_deadline: FundingDeadline,
+    pub(crate) dispute_bond: DisputeBond,
+    pub(crate) dust_policy: Byte,
+}
+impl ChannelParametersBuilder {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn parties(mut self, v: Participants) -> Self {
+        self.parties = v;
+        self
+    }
+    pub fn nonce(mut self, v: Byte32) -> Self {
+        self.nonce = v;
+        self
+    }
+    pub fn challenge_duration(mut self, v: Uint64) -> Self {
+        self.challenge_duration = v;
+        self
+    }
+    pub fn challenge_duration_metric(mut self, v: Byte) -> Self {
+        self.challenge_duration_metric = v;
+        self
+    }
+    pub fn app(mut self, v: App) -> Self {
+        self.app = v;
+        self
+    }
+    pub fn is_ledger_channel(mut self, v: Bool) -> Self {
+        self.is_ledger_channel = v;
+        self
+    }
+    pub fn is_virtual_channel(mut self, v: Bool) -> Self {
+        self.is_virtual_channel = v;
+        self
+    }
+    pub fn funding_deadline(mut self, v: FundingDeadline) -> Self {
+        self.funding_deadline = v;
+        self
+    }
+    pub fn dispute_bond(mut self, v: DisputeBond) -> Self {
+        self.dispute_bond = v;
+        self
+    }
+    pub fn dust_policy(mut self, v: Byte) -> Self {
+        self.dust_policy = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ChannelParametersBuilder {
+    type Entity = ChannelParameters;
+    const NAME: &'static str = "ChannelParametersBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.parties.as_slice().len()
+            + self.nonce.as_slice().len()
+            + self.challenge_duration.as_slice().len()
+            + self.challenge_duration_metric.as_slice().len()
+            + self.app.as_slice().len()
+            + self.is_ledger_channel.as_slice().len()
+            + self.is_virtual_channel.as_slice().len()
+            + self.funding_deadline.as_slice().len()
+            + self.dispute_bond.as_slice().len()
+            + self.dust_policy.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.parties.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.challenge_duration.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.challenge_duration_metric.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.app.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.is_ledger_channel.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.is_virtual_channel.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.funding_deadline.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.dispute_bond.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.dust_policy.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.parties.as_slice())?;
+        writer.write_all(self.nonce.as_slice())?;
+        writer.write_all(self.challenge_duration.as_slice())?;
+        writer.write_all(self.challenge_duration_metric.as_slice())?;
+        writer.write_all(self.app.as_slice())?;
+        writer.write_all(self.is_ledger_channel.as_slice())?;
+        writer.write_all(self.is_virtual_channel.as_slice())?;
+        writer.write_all(self.funding_deadline.as_slice())?;
+        writer.write_all(self.dispute_bond.as_slice())?;
+        writer.write_all(self.dust_policy.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ChannelParameters::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Predecessor(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Predecessor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Predecessor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Predecessor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for Predecessor {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        Predecessor::new_unchecked(v.into())
+    }
+}
+impl Predecessor {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Byte32> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Byte32::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> PredecessorReader<'r> {
+        PredecessorReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Predecessor {
+    type Builder = PredecessorBuilder;
+    const NAME: &'static str = "Predecessor";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Predecessor(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        PredecessorReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        PredecessorReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct PredecessorReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for PredecessorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for PredecessorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for PredecessorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> PredecessorReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Byte32Reader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Byte32Reader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for PredecessorReader<'r> {
+    type Entity = Predecessor;
+    const NAME: &'static str = "PredecessorReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        PredecessorReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            Byte32Reader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct PredecessorBuilder(pub(crate) Option<Byte32>);
+impl PredecessorBuilder {
+    pub fn set(mut self, v: Option<Byte32>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for PredecessorBuilder {
+    type Entity = Predecessor;
+    const NAME: &'static str = "PredecessorBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Predecessor::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RefundParty(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RefundParty {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RefundParty {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RefundParty {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for RefundParty {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        RefundParty::new_unchecked(v.into())
+    }
+}
+impl RefundParty {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> RefundPartyReader<'r> {
+        RefundPartyReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RefundParty {
+    type Builder = RefundPartyBuilder;
+    const NAME: &'static str = "RefundParty";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RefundParty(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RefundPartyReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RefundPartyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RefundPartyReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RefundPartyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RefundPartyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RefundPartyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> RefundPartyReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RefundPartyReader<'r> {
+    type Entity = RefundParty;
+    const NAME: &'static str = "RefundPartyReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RefundPartyReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct RefundPartyBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for RefundPartyBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for RefundPartyBuilder {
+    fn default() -> Self {
+        RefundPartyBuilder([Byte::default()])
+    }
+}
+impl RefundPartyBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RefundPartyBuilder {
+    type Entity = RefundParty;
+    const NAME: &'static str = "RefundPartyBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RefundParty::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RefundSponsor(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RefundSponsor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RefundSponsor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RefundSponsor {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for RefundSponsor {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ];
+        RefundSponsor::new_unchecked(v.into())
+    }
+}
+impl RefundSponsor {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 32;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
+    }
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
+    }
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3..4))
+    }
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4..5))
+    }
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5..6))
+    }
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6..7))
+    }
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7..8))
+    }
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8..9))
+    }
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9..10))
+    }
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10..11))
+    }
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11..12))
+    }
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12..13))
+    }
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13..14))
+    }
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14..15))
+    }
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15..16))
+    }
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16..17))
+    }
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17..18))
+    }
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18..19))
+    }
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19..20))
+    }
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20..21))
+    }
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21..22))
+    }
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22..23))
+    }
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23..24))
+    }
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24..25))
+    }
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25..26))
+    }
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26..27))
+    }
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27..28))
+    }
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28..29))
+    }
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29..30))
+    }
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30..31))
+    }
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31..32))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> RefundSponsorReader<'r> {
+        RefundSponsorReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RefundSponsor {
+    type Builder = RefundSponsorBuilder;
+    const NAME: &'static str = "RefundSponsor";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RefundSponsor(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RefundSponsorReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RefundSponsorReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RefundSponsorReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RefundSponsorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RefundSponsorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RefundSponsorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> RefundSponsorReader<'r> {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 32;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    }
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    }
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    }
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
+    }
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    }
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    }
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    }
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    }
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    }
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    }
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    }
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    }
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
+    }
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    }
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    }
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    }
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    }
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RefundSponsorReader<'r> {
+    type Entity = RefundSponsor;
+    const NAME: &'static str = "RefundSponsorReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RefundSponsorReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct RefundSponsorBuilder(pub(crate) [Byte; 32]);
+impl ::core::fmt::Debug for RefundSponsorBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for RefundSponsorBuilder {
+    fn default() -> Self {
+        RefundSponsorBuilder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl RefundSponsorBuilder {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 32;
+    pub fn set(mut self, v: [Byte; 32]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
+    }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
+    }
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
+    }
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
+    }
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
+    }
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
+    }
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
+    }
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
+    }
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
+    }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
+    }
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
+    }
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
+    }
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
+    }
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
+    }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
+    }
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
+    }
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
+    }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
+    }
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
+    }
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
+    }
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
+    }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RefundSponsorBuilder {
+    type Entity = RefundSponsor;
+    const NAME: &'static str = "RefundSponsorBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RefundSponsor::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CapacityRefund(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CapacityRefund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CapacityRefund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CapacityRefund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for CapacityRefund {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0];
+        CapacityRefund::new_unchecked(v.into())
+    }
+}
+impl CapacityRefund {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> CapacityRefundUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => RefundParty::new_unchecked(inner).into(),
+            1 => RefundSponsor::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CapacityRefundReader<'r> {
+        CapacityRefundReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CapacityRefund {
+    type Builder = CapacityRefundBuilder;
+    const NAME: &'static str = "CapacityRefund";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CapacityRefund(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CapacityRefundReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CapacityRefundReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CapacityRefundReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CapacityRefundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CapacityRefundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CapacityRefundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> CapacityRefundReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> CapacityRefundUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => RefundPartyReader::new_unchecked(inner).into(),
+            1 => RefundSponsorReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CapacityRefundReader<'r> {
+    type Entity = CapacityRefund;
+    const NAME: &'static str = "CapacityRefundReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CapacityRefundReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => RefundPartyReader::verify(inner_slice, compatible),
+            1 => RefundSponsorReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CapacityRefundBuilder(pub(crate) CapacityRefundUnion);
+impl CapacityRefundBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<CapacityRefundUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for CapacityRefundBuilder {
+    type Entity = CapacityRefund;
+    const NAME: &'static str = "CapacityRefundBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CapacityRefund::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum CapacityRefundUnion {
+    RefundParty(RefundParty),
+    RefundSponsor(RefundSponsor),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum CapacityRefundUnionReader<'r> {
+    RefundParty(RefundPartyReader<'r>),
+    RefundSponsor(RefundSponsorReader<'r>),
+}
+impl ::core::default::Default for CapacityRefundUnion {
+    fn default() -> Self {
+        CapacityRefundUnion::RefundParty(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for CapacityRefundUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CapacityRefundUnion::RefundParty(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RefundParty::NAME, item)
+            }
+            CapacityRefundUnion::RefundSponsor(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RefundSponsor::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for CapacityRefundUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CapacityRefundUnionReader::RefundParty(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RefundParty::NAME, item)
+            }
+            CapacityRefundUnionReader::RefundSponsor(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RefundSponsor::NAME, item)
+            }
+        }
+    }
+}
+impl CapacityRefundUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CapacityRefundUnion::RefundParty(ref item) => write!(f, "{}", item),
+            CapacityRefundUnion::RefundSponsor(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> CapacityRefundUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CapacityRefundUnionReader::RefundParty(ref item) => write!(f, "{}", item),
+            CapacityRefundUnionReader::RefundSponsor(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<RefundParty> for CapacityRefundUnion {
+    fn from(item: RefundParty) -> Self {
+        CapacityRefundUnion::RefundParty(item)
+    }
+}
+impl ::core::convert::From<RefundSponsor> for CapacityRefundUnion {
+    fn from(item: RefundSponsor) -> Self {
+        CapacityRefundUnion::RefundSponsor(item)
+    }
+}
+impl<'r> ::core::convert::From<RefundPartyReader<'r>> for CapacityRefundUnionReader<'r> {
+    fn from(item: RefundPartyReader<'r>) -> Self {
+        CapacityRefundUnionReader::RefundParty(item)
+    }
+}
+impl<'r> ::core::convert::From<RefundSponsorReader<'r>> for CapacityRefundUnionReader<'r> {
+    fn from(item: RefundSponsorReader<'r>) -> Self {
+        CapacityRefundUnionReader::RefundSponsor(item)
+    }
+}
+impl CapacityRefundUnion {
+    pub const NAME: &'static str = "CapacityRefundUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            CapacityRefundUnion::RefundParty(item) => item.as_bytes(),
+            CapacityRefundUnion::RefundSponsor(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            CapacityRefundUnion::RefundParty(item) => item.as_slice(),
+            CapacityRefundUnion::RefundSponsor(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            CapacityRefundUnion::RefundParty(_) => 0,
+            CapacityRefundUnion::RefundSponsor(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            CapacityRefundUnion::RefundParty(_) => "RefundParty",
+            CapacityRefundUnion::RefundSponsor(_) => "RefundSponsor",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CapacityRefundUnionReader<'r> {
+        match self {
+            CapacityRefundUnion::RefundParty(item) => item.as_reader().into(),
+            CapacityRefundUnion::RefundSponsor(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> CapacityRefundUnionReader<'r> {
+    pub const NAME: &'r str = "CapacityRefundUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            CapacityRefundUnionReader::RefundParty(item) => item.as_slice(),
+            CapacityRefundUnionReader::RefundSponsor(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            CapacityRefundUnionReader::RefundParty(_) => 0,
+            CapacityRefundUnionReader::RefundSponsor(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            CapacityRefundUnionReader::RefundParty(_) => "RefundParty",
+            CapacityRefundUnionReader::RefundSponsor(_) => "RefundSponsor",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct ChannelConstants(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelConstants {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ChannelConstants {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ChannelConstants {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "params", self.params())?;
+        write!(f, ", {}: {}", "pfls_code_hash", self.pfls_code_hash())?;
+        write!(f, ", {}: {}", "pfls_hash_type", self.pfls_hash_type())?;
+        write!(f, ", {}: {}", "pfls_min_capacity", self.pfls_min_capacity())?;
+        write!(f, ", {}: {}", "pcls_code_hash", self.pcls_code_hash())?;
+        write!(f, ", {}: {}", "pcls_hash_type", self.pcls_hash_type())?;
+        write!(f, ", {}: {}", "thread_token", self.thread_token())?;
+        write!(f, ", {}: {}", "predecessor", self.predecessor())?;
+        write!(f, ", {}: {}", "max_fee", self.max_fee())?;
+        write!(f, ", {}: {}", "max_sudt_assets", self.max_sudt_assets())?;
+        write!(f, ", {}: {}", "capacity_refund", self.capacity_refund())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ChannelConstants {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            16, 1, 0, 0, 48, 0, 0, 0, 148, 0, 0, 0, 180, 0, 0, 0, 181, 0, 0, 0, 189, 0, 0, 0, 221,
+            0, 0, 0, 222, 0, 0, 0, 2, 1, 0, 0, 2, 1, 0, 0, 10, 1, 0, 0, 11, 1, 0, 0, 100, 0, 0, 0,
+            44, 0, 0, 0, 48, 0, 0, 0, 80, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 89, 0, 0, 0, 94, 0, 0,
+            0, 99, 0, 0, 0, 99, 0, 0, 0, 99, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ChannelConstants::new_unchecked(v.into())
+    }
+}
+impl ChannelConstants {
+    pub const FIELD_COUNT: usize = 11;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn params(&self) -> ChannelParameters {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelParameters::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pfls_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pfls_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pfls_min_capacity(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pcls_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pcls_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn thread_token(&self) -> ChannelToken {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ChannelToken::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn predecessor(&self) -> Predecessor {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Predecessor::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn max_fee(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn max_sudt_assets(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn capacity_refund(&self) -> CapacityRefund {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[48..]) as usize;
+            CapacityRefund::new_unchecked(self.0.slice(start..end))
+        } else {
+            CapacityRefund::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ChannelConstantsReader<'r> {
+        ChannelConstantsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ChannelConstants {
+    type Builder = ChannelConstantsBuilder;
+    const NAME: &'static str = "ChannelConstants";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ChannelConstants(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelConstantsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelConstantsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .params(self.params())
+            .pfls_code_hash(self.pfls_code_hash())
+            .pfls_hash_type(self.pfls_hash_type())
+            .pfls_min_capacity(self.pfls_min_capacity())
+            .pcls_code_hash(self.pcls_code_hash())
+            .pcls_hash_type(self.pcls_hash_type())
+            .thread_token(self.thread_token())
+            .predecessor(self.predecessor())
+            .max_fee(self.max_fee())
+            .max_sudt_assets(self.max_sudt_assets())
+            .capacity_refund(self.capacity_refund())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ChannelConstantsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelConstantsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ChannelConstantsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ChannelConstantsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "params", self.params())?;
+        write!(f, ", {}: {}", "pfls_code_hash", self.pfls_code_hash())?;
+        write!(f, ", {}: {}", "pfls_hash_type", self.pfls_hash_type())?;
+        write!(f, ", {}: {}", "pfls_min_capacity", self.pfls_min_capacity())?;
+        write!(f, ", {}: {}", "pcls_code_hash", self.pcls_code_hash())?;
+        write!(f, ", {}: {}", "pcls_hash_type", self.pcls_hash_type())?;
+        write!(f, ", {}: {}", "thread_token", self.thread_token())?;
+        write!(f, ", {}: {}", "predecessor", self.predecessor())?;
+        write!(f, ", {}: {}", "max_fee", self.max_fee())?;
+        write!(f, ", {}: {}", "max_sudt_assets", self.max_sudt_assets())?;
+        write!(f, ", {}: {}", "capacity_refund", self.capacity_refund())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ChannelConstantsReader<'r> {
+    pub const FIELD_COUNT: usize = 11;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn params(&self) -> ChannelParametersReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelParametersReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pfls_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pfls_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pfls_min_capacity(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pcls_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pcls_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn thread_token(&self) -> ChannelTokenReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ChannelTokenReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn predecessor(&self) -> PredecessorReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        PredecessorReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn max_fee(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn max_sudt_assets(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn capacity_refund(&self) -> CapacityRefundReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[48..]) as usize;
+            CapacityRefundReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            CapacityRefundReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ChannelConstantsReader<'r> {
+    type Entity = ChannelConstants;
+    const NAME: &'static str = "ChannelConstantsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ChannelConstantsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ChannelParametersReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        ChannelTokenReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        PredecessorReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        ByteReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        CapacityRefundReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ChannelConstantsBuilder {
+    pub(crate) params: ChannelParameters,
+    pub(crate) pfls_code_hash: Byte32,
+    pub(crate) pfls_hash_type: Byte,
+    pub(crate) pfls_min_capacity: Uint64,
+    pub(crate) pcls_code_hash: Byte32,
+    pub(crate) pcls_hash_type: Byte,
+    pub(crate) thread_token: ChannelToken,
+    pub(crate) predecessor: Predecessor,
+    pub(crate) max_fee: Uint64,
+    pub(crate) max_sudt_assets: Byte,
+    pub(crate) capacity_refund: CapacityRefund,
+}
+impl ChannelConstantsBuilder {
+    pub const FIELD_COUNT: usize = 11;
+    pub fn params(mut self, v: ChannelParameters) -> Self {
+        self.params = v;
+        self
+    }
+    pub fn pfls_code_hash(mut self, v: Byte32) -> Self {
+        self.pfls_code_hash = v;
+        self
+    }
+    pub fn pfls_hash_type(mut self, v: Byte) -> Self {
+        self.pfls_hash_type = v;
+        self
+    }
+    pub fn pfls_min_capacity(mut self, v: Uint64) -> Self {
+        self.pfls_min_capacity = v;
+        self
+    }
+    pub fn pcls_code_hash(mut self, v: Byte32) -> Self {
+        self.pcls_code_hash = v;
+        self
+    }
+    pub fn pcls_hash_type(mut self, v: Byte) -> Self {
+        self.pcls_hash_type = v;
+        self
+    }
+    pub fn thread_token(mut self, v: ChannelToken) -> Self {
+        self.thread_token = v;
+        self
+    }
+    pub fn predecessor(mut self, v: Predecessor) -> Self {
+        self.predecessor = v;
+        self
+    }
+    pub fn max_fee(mut self, v: Uint64) -> Self {
+        self.max_fee = v;
+        self
+    }
+    pub fn max_sudt_assets(mut self, v: Byte) -> Self {
+        self.max_sudt_assets = v;
+        self
+    }
+    pub fn capacity_refund(mut self, v: CapacityRefund) -> Self {
+        self.capacity_refund = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ChannelConstantsBuilder {
+    type Entity = ChannelConstants;
+    const NAME: &'static str = "ChannelConstantsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.params.as_slice().len()
+            + self.pfls_code_hash.as_slice().len()
+            + self.pfls_hash_type.as_slice().len()
+            + self.pfls_min_capacity.as_slice().len()
+            + self.pcls_code_hash.as_slice().len()
+            + self.pcls_hash_type.as_slice().len()
+            + self.thread_token.as_slice().len()
+            + self.predecessor.as_slice().len()
+            + self.max_fee.as_slice().len()
+            + self.max_sudt_assets.as_slice().len()
+            + self.capacity_refund.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.params.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pfls_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pfls_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pfls_min_capacity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pcls_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pcls_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.thread_token.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.predecessor.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.max_fee.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.max_sudt_assets.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.capacity_refund.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.params.as_slice())?;
+        writer.write_all(self.pfls_code_hash.as_slice())?;
+        writer.write_all(self.pfls_hash_type.as_slice())?;
+        writer.write_all(self.pfls_min_capacity.as_slice())?;
+        writer.write_all(self.pcls_code_hash.as_slice())?;
+        writer.write_all(self.pcls_hash_type.as_slice())?;
+        writer.write_all(self.thread_token.as_slice())?;
+        writer.write_all(self.predecessor.as_slice())?;
+        writer.write_all(self.max_fee.as_slice())?;
+        writer.write_all(self.max_sudt_assets.as_slice())?;
+        writer.write_all(self.capacity_refund.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ChannelConstants::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Fund(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Fund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Fund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Fund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for Fund {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        Fund::new_unchecked(v.into())
+    }
+}
+impl Fund {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> FundReader<'r> {
+        FundReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Fund {
+    type Builder = FundBuilder;
+    const NAME: &'static str = "Fund";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Fund(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FundReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FundReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FundReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> FundReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FundReader<'r> {
+    type Entity = Fund;
+    const NAME: &'static str = "FundReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FundReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct FundBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for FundBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for FundBuilder {
+    fn default() -> Self {
+        FundBuilder([Byte::default()])
+    }
+}
+impl FundBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FundBuilder {
+    type Entity = Fund;
+    const NAME: &'static str = "FundBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Fund::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Abort(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Abort {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Abort {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Abort {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for Abort {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        Abort::new_unchecked(v.into())
+    }
+}
+impl Abort {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> AbortReader<'r> {
+        AbortReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Abort {
+    type Builder = AbortBuilder;
+    const NAME: &'static str = "Abort";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Abort(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AbortReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AbortReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AbortReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AbortReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AbortReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AbortReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> AbortReader<'r> {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AbortReader<'r> {
+    type Entity = Abort;
+    const NAME: &'static str = "AbortReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AbortReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct AbortBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for AbortBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for AbortBuilder {
+    fn default() -> Self {
+        AbortBuilder([Byte::default()])
+    }
+}
+impl AbortBuilder {
+    pub const TOTAL_SIZE: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 1;
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AbortBuilder {
+    type Entity = Abort;
+    const NAME: &'static str = "AbortBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Abort::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SignedState(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SignedState {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SignedState {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SignedState {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "params", self.params())?;
+        write!(f, ", {}: {}", "state", self.state())?;
+        write!(f, ", {}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SignedState {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            233, 0, 0, 0, 16, 0, 0, 0, 116, 0, 0, 0, 229, 0, 0, 0, 100, 0, 0, 0, 44, 0, 0, 0, 48,
+            0, 0, 0, 80, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 89, 0, 0, 0, 94, 0, 0, 0, 99, 0, 0, 0,
+            99, 0, 0, 0, 99, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 28, 0, 0, 0, 60, 0, 0, 0, 96, 0, 0, 0, 104, 0, 0, 0,
+            109, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0,
+            32, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        SignedState::new_unchecked(v.into())
+    }
+}
+impl SignedState {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn params(&self) -> ChannelParameters {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelParameters::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn state(&self) -> ChannelState {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ChannelState::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SignedStateReader<'r> {
+        SignedStateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SignedState {
+    type Builder = SignedStateBuilder;
+    const NAME: &'static str = "SignedState";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SignedState(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignedStateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignedStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .params(self.params())
+            .state(self.state())
+            .sigs(self.sigs())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SignedStateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SignedStateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SignedStateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SignedStateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "params", self.params())?;
+        write!(f, ", {}: {}", "state", self.state())?;
+        write!(f, ", {}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SignedStateReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn params(&self) -> ChannelParametersReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelParametersReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn state(&self) -> ChannelStateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ChannelStateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SignedStateReader<'r> {
+    type Entity = SignedState;
+    const NAME: &'static str = "SignedStateReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SignedStateReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ChannelParametersReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ChannelStateReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SignedStateBuilder {
+    pub(crate) params: ChannelParameters,
+    pub(crate) state: ChannelState,
+    pub(crate) sigs: BytesVec,
+}
+impl SignedStateBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn params(mut self, v: ChannelParameters) -> Self {
+        self.params = v;
+        self
+    }
+    pub fn state(mut self, v: ChannelState) -> Self {
+        self.state = v;
+        self
+    }
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SignedStateBuilder {
+    type Entity = SignedState;
+    const NAME: &'static str = "SignedStateBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.params.as_slice().len()
+            + self.state.as_slice().len()
+            + self.sigs.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.params.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.state.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sigs.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.params.as_slice())?;
+        writer.write_all(self.state.as_slice())?;
+        writer.write_all(self.sigs.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SignedState::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SignedStates(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SignedStates {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SignedStates {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SignedStates {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for SignedStates {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        SignedStates::new_unchecked(v.into())
+    }
+}
+impl SignedStates {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SignedState> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SignedState {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SignedState::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SignedState::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SignedStatesReader<'r> {
+        SignedStatesReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SignedStates {
+    type Builder = SignedStatesBuilder;
+    const NAME: &'static str = "SignedStates";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SignedStates(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignedStatesReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignedStatesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SignedStatesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SignedStatesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SignedStatesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SignedStatesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> SignedStatesReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SignedStateReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SignedStateReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SignedStateReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SignedStateReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SignedStatesReader<'r> {
+    type Entity = SignedStates;
+    const NAME: &'static str = "SignedStatesReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SignedStatesReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            SignedStateReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SignedStatesBuilder(pub(crate) Vec<SignedState>);
+impl SignedStatesBuilder {
+    pub fn set(mut self, v: Vec<SignedState>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: SignedState) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = SignedState>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: SignedState) -> Option<SignedState> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for SignedStatesBuilder {
+    type Entity = SignedStates;
+    const NAME: &'static str = "SignedStatesBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SignedStates::new_unchecked(inner.into())
+    }
+}
+pub struct SignedStatesIterator(SignedStates, usize, usize);
+impl ::core::iter::Iterator for SignedStatesIterator {
+    type Item = SignedState;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for SignedStatesIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for SignedStates {
+    type Item = SignedState;
+    type IntoIter = SignedStatesIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SignedStatesIterator(self, 0, len)
+    }
+}
+impl<'r> SignedStatesReader<'r> {
+    pub fn iter<'t>(&'t self) -> SignedStatesReaderIterator<'t, 'r> {
+        SignedStatesReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct SignedStatesReaderIterator<'t, 'r>(&'t SignedStatesReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SignedStatesReaderIterator<'t, 'r> {
+    type Item = SignedStateReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SignedStatesReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct Dispute(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Dispute {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Dispute {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Dispute {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
+        write!(f, ", {}: {}", "sub_channels", self.sub_channels())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Dispute {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        Dispute::new_unchecked(v.into())
+    }
+}
+impl Dispute {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sub_channels(&self) -> SignedStates {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SignedStates::new_unchecked(self.0.slice(start..end))
+        } else {
+            SignedStates::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> DisputeReader<'r> {
+        DisputeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Dispute {
+    type Builder = DisputeBuilder;
+    const NAME: &'static str = "Dispute";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Dispute(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DisputeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DisputeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .sigs(self.sigs())
+            .sub_channels(self.sub_channels())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DisputeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DisputeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DisputeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DisputeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
+        write!(f, ", {}: {}", "sub_channels", self.sub_channels())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> DisputeReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sub_channels(&self) -> SignedStatesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SignedStatesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SignedStatesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DisputeReader<'r> {
+    type Entity = Dispute;
+    const NAME: &'static str = "DisputeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DisputeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SignedStatesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct DisputeBuilder {
+    pub(crate) sigs: BytesVec,
+    pub(crate) sub_channels: SignedStates,
+}
+impl DisputeBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
+        self
+    }
+    pub fn sub_channels(mut self, v: SignedStates) -> Self {
+        self.sub_channels = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DisputeBuilder {
+    type Entity = Dispute;
+    const NAME: &'static str = "DisputeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.sigs.as_slice().len()
+            + self.sub_channels.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.sigs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sub_channels.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.sigs.as_slice())?;
+        writer.write_all(self.sub_channels.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Dispute::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Close(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Close {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Close {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Close {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "state", self.state())?;
+        write!(f, ", {}: {}", "sigs", self.sigs())?;
+        write!(f, ", {}: {}", "preimages", self.preimages())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Close {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            137, 0, 0, 0, 16, 0, 0, 0, 129, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 28, 0, 0, 0, 60,
+            0, 0, 0, 96, 0, 0, 0, 104, 0, 0, 0, 109, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0,
+            20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
+            4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        Close::new_unchecked(v.into())
+    }
+}
+impl Close {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn state(&self) -> ChannelState {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelState::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn preimages(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CloseReader<'r> {
+        CloseReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Close {
+    type Builder = CloseBuilder;
+    const NAME: &'static str = "Close";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Close(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CloseReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CloseReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .state(self.state())
+            .sigs(self.sigs())
+            .preimages(self.preimages())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CloseReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CloseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CloseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CloseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "state", self.state())?;
+        write!(f, ", {}: {}", "sigs", self.sigs())?;
+        write!(f, ", {}: {}", "preimages", self.preimages())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> CloseReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn state(&self) -> ChannelStateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelStateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn preimages(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CloseReader<'r> {
+    type Entity = Close;
+    const NAME: &'static str = "CloseReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CloseReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ChannelStateReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CloseBuilder {
+    pub(crate) state: ChannelState,
+    pub(crate) sigs: BytesVec,
+    pub(crate) preimages: BytesVec,
+}
+impl CloseBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn state(mut self, v: ChannelState) -> Self {
+        self.state = v;
+        self
+    }
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
+        self
+    }
+    pub fn preimages(mut self, v: BytesVec) -> Self {
+        self.preimages = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CloseBuilder {
+    type Entity = Close;
+    const NAME: &'static str = "CloseBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.state.as_slice().len()
+            + self.sigs.as_slice().len()
+            + self.preimages.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.state.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sigs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.preimages.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.state.as_slice())?;
+        writer.write_all(self.sigs.as_slice())?;
+        writer.write_all(self.preimages.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Close::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ForceClose(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ForceClose {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ForceClose {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ForceClose {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "preimages", self.preimages())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ForceClose {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+        ForceClose::new_unchecked(v.into())
+    }
+}
+impl ForceClose {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn preimages(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ForceCloseReader<'r> {
+        ForceCloseReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ForceClose {
+    type Builder = ForceCloseBuilder;
+    const NAME: &'static str = "ForceClose";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ForceClose(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ForceCloseReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ForceCloseReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().preimages(self.preimages())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ForceCloseReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ForceCloseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ForceCloseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ForceCloseReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "preimages", self.preimages())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ForceCloseReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn preimages(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ForceCloseReader<'r> {
+    type Entity = ForceClose;
+    const NAME: &'static str = "ForceCloseReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ForceCloseReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ForceCloseBuilder {
+    pub(crate) preimages: BytesVec,
+}
+impl ForceCloseBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn preimages(mut self, v: BytesVec) -> Self {
+        self.preimages = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ForceCloseBuilder {
+    type Entity = ForceClose;
+    const NAME: &'static str = "ForceCloseBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.preimages.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.preimages.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.preimages.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ForceClose::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Deposit(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Deposit {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Deposit {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Deposit {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Deposit {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+        Deposit::new_unchecked(v.into())
+    }
+}
+impl Deposit {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> DepositReader<'r> {
+        DepositReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Deposit {
+    type Builder = DepositBuilder;
+    const NAME: &'static str = "Deposit";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Deposit(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DepositReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DepositReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().sigs(self.sigs())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DepositReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DepositReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DepositReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DepositReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> DepositReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DepositReader<'r> {
+    type Entity = Deposit;
+    const NAME: &'static str = "DepositReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DepositReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct DepositBuilder {
+    pub(crate) sigs: BytesVec,
+}
+impl DepositBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DepositBuilder {
+    type Entity = Deposit;
+    const NAME: &'static str = "DepositBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.sigs.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.sigs.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.sigs.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Deposit::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Withdraw(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Withdraw {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Withdraw {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Withdraw {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "state", self.state())?;
+        write!(f, ", {}: {}", "state_sigs", self.state_sigs())?;
+        write!(f, ", {}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Withdraw {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            137, 0, 0, 0, 16, 0, 0, 0, 129, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 28, 0, 0, 0, 60,
+            0, 0, 0, 96, 0, 0, 0, 104, 0, 0, 0, 109, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0,
+            20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
+            4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        Withdraw::new_unchecked(v.into())
+    }
+}
+impl Withdraw {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn state(&self) -> ChannelState {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ChannelState::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn state_sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawReader<'r> {
+        WithdrawReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Withdraw {
+    type Builder = WithdrawBuilder;
+    const NAME: &'static str = "Withdraw";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Withdraw(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .state(self.state())
+            .state_sigs(self.state_sigs())
+            .sigs(self.sigs())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "state", self.state())?;
+        write!(f, ", {}: {}", "state_sigs", self.state_sigs())?;
+        write!(f, ", {}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> WithdrawReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn state(&self) -> ChannelStateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        ParticipantReader::new_unchecked(&self.as_slice()[start..end])
+        ChannelStateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn state_sigs(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawReader<'r> {
+    type Entity = Withdraw;
+    const NAME: &'static str = "WithdrawReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ChannelStateReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct WithdrawBuilder {
+    pub(crate) state: ChannelState,
+    pub(crate) state_sigs: BytesVec,
+    pub(crate) sigs: BytesVec,
+}
+impl WithdrawBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn state(mut self, v: ChannelState) -> Self {
+        self.state = v;
+        self
+    }
+    pub fn state_sigs(mut self, v: BytesVec) -> Self {
+        self.state_sigs = v;
+        self
+    }
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawBuilder {
+    type Entity = Withdraw;
+    const NAME: &'static str = "WithdrawBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.state.as_slice().len()
+            + self.state_sigs.as_slice().len()
+            + self.sigs.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.state.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.state_sigs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sigs.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.state.as_slice())?;
+        writer.write_all(self.state_sigs.as_slice())?;
+        writer.write_all(self.sigs.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Withdraw::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Rotate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Rotate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Rotate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Rotate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Rotate {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+        Rotate::new_unchecked(v.into())
+    }
+}
+impl Rotate {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RotateReader<'r> {
+        RotateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Rotate {
+    type Builder = RotateBuilder;
+    const NAME: &'static str = "Rotate";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Rotate(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RotateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RotateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().sigs(self.sigs())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RotateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RotateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn party_b(&self) -> ParticipantReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        ParticipantReader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> ::core::fmt::Debug for RotateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nonce(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> ::core::fmt::Display for RotateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn challenge_duration(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> RotateReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn app(&self) -> AppReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        AppReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn is_ledger_channel(&self) -> BoolReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        BoolReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_virtual_channel(&self) -> BoolReader<'r> {
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            BoolReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BoolReader::new_unchecked(&self.as_slice()[start..])
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelParametersReader<'r> {
-    type Entity = ChannelParameters;
-    const NAME: &'static str = "ChannelParametersReader";
+impl<'r> molecule::prelude::Reader<'r> for RotateReader<'r> {
+    type Entity = Rotate;
+    const NAME: &'static str = "RotateReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelParametersReader(slice)
+        RotateReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -3859,110 +11209,49 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelParametersReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ParticipantReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ParticipantReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        AppReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        BoolReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        BoolReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ChannelParametersBuilder {
-    pub(crate) party_a: Participant,
-    pub(crate) party_b: Participant,
-    pub(crate) nonce: Byte32,
-    pub(crate) challenge_duration: Uint64,
-    pub(crate) app: App,
-    pub(crate) is_ledger_channel: Bool,
-    pub(crate) is_virtual_channel: Bool,
+pub struct RotateBuilder {
+    pub(crate) sigs: BytesVec,
 }
-impl ChannelParametersBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn party_a(mut self, v: Participant) -> Self {
-        self.party_a = v;
-        self
-    }
-    pub fn party_b(mut self, v: Participant) -> Self {
-        self.party_b = v;
-        self
-    }
-    pub fn nonce(mut self, v: Byte32) -> Self {
-        self.nonce = v;
-        self
-    }
-    pub fn challenge_duration(mut self, v: Uint64) -> Self {
-        self.challenge_duration = v;
-        self
-    }
-    pub fn app(mut self, v: App) -> Self {
-        self.app = v;
-        self
-    }
-    pub fn is_ledger_channel(mut self, v: Bool) -> Self {
-        self.is_ledger_channel = v;
-        self
-    }
-    pub fn is_virtual_channel(mut self, v: Bool) -> Self {
-        self.is_virtual_channel = v;
+impl RotateBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelParametersBuilder {
-    type Entity = ChannelParameters;
-    const NAME: &'static str = "ChannelParametersBuilder";
+impl molecule::prelude::Builder for RotateBuilder {
+    type Entity = Rotate;
+    const NAME: &'static str = "RotateBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.party_a.as_slice().len()
-            + self.party_b.as_slice().len()
-            + self.nonce.as_slice().len()
-            + self.challenge_duration.as_slice().len()
-            + self.app.as_slice().len()
-            + self.is_ledger_channel.as_slice().len()
-            + self.is_virtual_channel.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.sigs.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.party_a.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.party_b.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.nonce.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.challenge_duration.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.app.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.is_ledger_channel.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.is_virtual_channel.as_slice().len();
+        total_size += self.sigs.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.party_a.as_slice())?;
-        writer.write_all(self.party_b.as_slice())?;
-        writer.write_all(self.nonce.as_slice())?;
-        writer.write_all(self.challenge_duration.as_slice())?;
-        writer.write_all(self.app.as_slice())?;
-        writer.write_all(self.is_ledger_channel.as_slice())?;
-        writer.write_all(self.is_virtual_channel.as_slice())?;
+        writer.write_all(self.sigs.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelParameters::new_unchecked(inner.into())
+        Rotate::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ChannelConstants(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelConstants {
+pub struct Checkpoint(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Checkpoint {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3971,21 +11260,15 @@ impl ::core::fmt::LowerHex for ChannelConstants {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelConstants {
+impl ::core::fmt::Debug for Checkpoint {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelConstants {
+impl ::core::fmt::Display for Checkpoint {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "params", self.params())?;
-        write!(f, ", {}: {}", "pfls_code_hash", self.pfls_code_hash())?;
-        write!(f, ", {}: {}", "pfls_hash_type", self.pfls_hash_type())?;
-        write!(f, ", {}: {}", "pfls_min_capacity", self.pfls_min_capacity())?;
-        write!(f, ", {}: {}", "pcls_code_hash", self.pcls_code_hash())?;
-        write!(f, ", {}: {}", "pcls_hash_type", self.pcls_hash_type())?;
-        write!(f, ", {}: {}", "thread_token", self.thread_token())?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -3993,32 +11276,14 @@ impl ::core::fmt::Display for ChannelConstants {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ChannelConstants {
+impl ::core::default::Default for Checkpoint {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            218, 1, 0, 0, 32, 0, 0, 0, 108, 1, 0, 0, 140, 1, 0, 0, 141, 1, 0, 0, 149, 1, 0, 0, 181,
-            1, 0, 0, 182, 1, 0, 0, 76, 1, 0, 0, 32, 0, 0, 0, 157, 0, 0, 0, 26, 1, 0, 0, 58, 1, 0,
-            0, 66, 1, 0, 0, 66, 1, 0, 0, 71, 1, 0, 0, 125, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 60,
-            0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0,
-            0, 20, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        ChannelConstants::new_unchecked(v.into())
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+        Checkpoint::new_unchecked(v.into())
     }
 }
-impl ChannelConstants {
-    pub const FIELD_COUNT: usize = 7;
+impl Checkpoint {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4035,61 +11300,25 @@ impl ChannelConstants {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn params(&self) -> ChannelParameters {
+    pub fn sigs(&self) -> BytesVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        ChannelParameters::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn pfls_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn pfls_hash_type(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn pfls_min_capacity(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn pcls_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn pcls_hash_type(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn thread_token(&self) -> ChannelToken {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            ChannelToken::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
         } else {
-            ChannelToken::new_unchecked(self.0.slice(start..))
+            BytesVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelConstantsReader<'r> {
-        ChannelConstantsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> CheckpointReader<'r> {
+        CheckpointReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelConstants {
-    type Builder = ChannelConstantsBuilder;
-    const NAME: &'static str = "ChannelConstants";
+impl molecule::prelude::Entity for Checkpoint {
+    type Builder = CheckpointBuilder;
+    const NAME: &'static str = "Checkpoint";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelConstants(data)
+        Checkpoint(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4098,28 +11327,21 @@ impl molecule::prelude::Entity for ChannelConstants {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelConstantsReader::from_slice(slice).map(|reader| reader.to_entity())
+        CheckpointReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelConstantsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        CheckpointReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .params(self.params())
-            .pfls_code_hash(self.pfls_code_hash())
-            .pfls_hash_type(self.pfls_hash_type())
-            .pfls_min_capacity(self.pfls_min_capacity())
-            .pcls_code_hash(self.pcls_code_hash())
-            .pcls_hash_type(self.pcls_hash_type())
-            .thread_token(self.thread_token())
+        Self::new_builder().sigs(self.sigs())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelConstantsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelConstantsReader<'r> {
+pub struct CheckpointReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckpointReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4128,101 +11350,59 @@ impl<'r> ::core::fmt::LowerHex for ChannelConstantsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelConstantsReader<'r> {
+impl<'r> ::core::fmt::Debug for CheckpointReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelConstantsReader<'r> {
+impl<'r> ::core::fmt::Display for CheckpointReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "params", self.params())?;
-        write!(f, ", {}: {}", "pfls_code_hash", self.pfls_code_hash())?;
-        write!(f, ", {}: {}", "pfls_hash_type", self.pfls_hash_type())?;
-        write!(f, ", {}: {}", "pfls_min_capacity", self.pfls_min_capacity())?;
-        write!(f, ", {}: {}", "pcls_code_hash", self.pcls_code_hash())?;
-        write!(f, ", {}: {}", "pcls_hash_type", self.pcls_hash_type())?;
-        write!(f, ", {}: {}", "thread_token", self.thread_token())?;
+        write!(f, "{}: {}", "sigs", self.sigs())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl<'r> ChannelConstantsReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn params(&self) -> ChannelParametersReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        ChannelParametersReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn pfls_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn pfls_hash_type(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn pfls_min_capacity(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> CheckpointReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn pcls_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn pcls_hash_type(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn thread_token(&self) -> ChannelTokenReader<'r> {
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sigs(&self) -> BytesVecReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            ChannelTokenReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ChannelTokenReader::new_unchecked(&self.as_slice()[start..])
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelConstantsReader<'r> {
-    type Entity = ChannelConstants;
-    const NAME: &'static str = "ChannelConstantsReader";
+impl<'r> molecule::prelude::Reader<'r> for CheckpointReader<'r> {
+    type Entity = Checkpoint;
+    const NAME: &'static str = "CheckpointReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelConstantsReader(slice)
+        CheckpointReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -4264,110 +11444,49 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelConstantsReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ChannelParametersReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        ChannelTokenReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ChannelConstantsBuilder {
-    pub(crate) params: ChannelParameters,
-    pub(crate) pfls_code_hash: Byte32,
-    pub(crate) pfls_hash_type: Byte,
-    pub(crate) pfls_min_capacity: Uint64,
-    pub(crate) pcls_code_hash: Byte32,
-    pub(crate) pcls_hash_type: Byte,
-    pub(crate) thread_token: ChannelToken,
+pub struct CheckpointBuilder {
+    pub(crate) sigs: BytesVec,
 }
-impl ChannelConstantsBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn params(mut self, v: ChannelParameters) -> Self {
-        self.params = v;
-        self
-    }
-    pub fn pfls_code_hash(mut self, v: Byte32) -> Self {
-        self.pfls_code_hash = v;
-        self
-    }
-    pub fn pfls_hash_type(mut self, v: Byte) -> Self {
-        self.pfls_hash_type = v;
-        self
-    }
-    pub fn pfls_min_capacity(mut self, v: Uint64) -> Self {
-        self.pfls_min_capacity = v;
-        self
-    }
-    pub fn pcls_code_hash(mut self, v: Byte32) -> Self {
-        self.pcls_code_hash = v;
-        self
-    }
-    pub fn pcls_hash_type(mut self, v: Byte) -> Self {
-        self.pcls_hash_type = v;
-        self
-    }
-    pub fn thread_token(mut self, v: ChannelToken) -> Self {
-        self.thread_token = v;
+impl CheckpointBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn sigs(mut self, v: BytesVec) -> Self {
+        self.sigs = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelConstantsBuilder {
-    type Entity = ChannelConstants;
-    const NAME: &'static str = "ChannelConstantsBuilder";
+impl molecule::prelude::Builder for CheckpointBuilder {
+    type Entity = Checkpoint;
+    const NAME: &'static str = "CheckpointBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.params.as_slice().len()
-            + self.pfls_code_hash.as_slice().len()
-            + self.pfls_hash_type.as_slice().len()
-            + self.pfls_min_capacity.as_slice().len()
-            + self.pcls_code_hash.as_slice().len()
-            + self.pcls_hash_type.as_slice().len()
-            + self.thread_token.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.sigs.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.params.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.pfls_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.pfls_hash_type.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.pfls_min_capacity.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.pcls_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.pcls_hash_type.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.thread_token.as_slice().len();
+        total_size += self.sigs.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.params.as_slice())?;
-        writer.write_all(self.pfls_code_hash.as_slice())?;
-        writer.write_all(self.pfls_hash_type.as_slice())?;
-        writer.write_all(self.pfls_min_capacity.as_slice())?;
-        writer.write_all(self.pcls_code_hash.as_slice())?;
-        writer.write_all(self.pcls_hash_type.as_slice())?;
-        writer.write_all(self.thread_token.as_slice())?;
+        writer.write_all(self.sigs.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelConstants::new_unchecked(inner.into())
+        Checkpoint::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Fund(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Fund {
+pub struct ChannelWitness(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4376,43 +11495,53 @@ impl ::core::fmt::LowerHex for Fund {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Fund {
+impl ::core::fmt::Debug for ChannelWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Fund {
+impl ::core::fmt::Display for ChannelWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl ::core::default::Default for Fund {
+impl ::core::default::Default for ChannelWitness {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        Fund::new_unchecked(v.into())
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0];
+        ChannelWitness::new_unchecked(v.into())
     }
 }
-impl Fund {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+impl ChannelWitness {
+    pub const ITEMS_COUNT: usize = 9;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+    pub fn to_enum(&self) -> ChannelWitnessUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => Fund::new_unchecked(inner).into(),
+            1 => Abort::new_unchecked(inner).into(),
+            2 => Dispute::new_unchecked(inner).into(),
+            3 => Close::new_unchecked(inner).into(),
+            4 => ForceClose::new_unchecked(inner).into(),
+            5 => Deposit::new_unchecked(inner).into(),
+            6 => Withdraw::new_unchecked(inner).into(),
+            7 => Rotate::new_unchecked(inner).into(),
+            8 => Checkpoint::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> FundReader<'r> {
-        FundReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ChannelWitnessReader<'r> {
+        ChannelWitnessReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Fund {
-    type Builder = FundBuilder;
-    const NAME: &'static str = "Fund";
+impl molecule::prelude::Entity for ChannelWitness {
+    type Builder = ChannelWitnessBuilder;
+    const NAME: &'static str = "ChannelWitness";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Fund(data)
+        ChannelWitness(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4421,21 +11550,21 @@ impl molecule::prelude::Entity for Fund {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        FundReader::from_slice(slice).map(|reader| reader.to_entity())
+        ChannelWitnessReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        FundReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ChannelWitnessReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+        Self::new_builder().set(self.to_enum())
     }
 }
 #[derive(Clone, Copy)]
-pub struct FundReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for FundReader<'r> {
+pub struct ChannelWitnessReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4444,258 +11573,431 @@ impl<'r> ::core::fmt::LowerHex for FundReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for FundReader<'r> {
+impl<'r> ::core::fmt::Debug for ChannelWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for FundReader<'r> {
+impl<'r> ::core::fmt::Display for ChannelWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl<'r> FundReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> ChannelWitnessReader<'r> {
+    pub const ITEMS_COUNT: usize = 9;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+    pub fn to_enum(&self) -> ChannelWitnessUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => FundReader::new_unchecked(inner).into(),
+            1 => AbortReader::new_unchecked(inner).into(),
+            2 => DisputeReader::new_unchecked(inner).into(),
+            3 => CloseReader::new_unchecked(inner).into(),
+            4 => ForceCloseReader::new_unchecked(inner).into(),
+            5 => DepositReader::new_unchecked(inner).into(),
+            6 => WithdrawReader::new_unchecked(inner).into(),
+            7 => RotateReader::new_unchecked(inner).into(),
+            8 => CheckpointReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for FundReader<'r> {
-    type Entity = Fund;
-    const NAME: &'static str = "FundReader";
+impl<'r> molecule::prelude::Reader<'r> for ChannelWitnessReader<'r> {
+    type Entity = ChannelWitness;
+    const NAME: &'static str = "ChannelWitnessReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        FundReader(slice)
+        ChannelWitnessReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => FundReader::verify(inner_slice, compatible),
+            1 => AbortReader::verify(inner_slice, compatible),
+            2 => DisputeReader::verify(inner_slice, compatible),
+            3 => CloseReader::verify(inner_slice, compatible),
+            4 => ForceCloseReader::verify(inner_slice, compatible),
+            5 => DepositReader::verify(inner_slice, compatible),
+            6 => WithdrawReader::verify(inner_slice, compatible),
+            7 => RotateReader::verify(inner_slice, compatible),
+            8 => CheckpointReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
         Ok(())
     }
 }
-pub struct FundBuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for FundBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
-}
-impl ::core::default::Default for FundBuilder {
-    fn default() -> Self {
-        FundBuilder([Byte::default()])
-    }
-}
-impl FundBuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
-        self.0 = v;
-        self
-    }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
+#[derive(Debug, Default)]
+pub struct ChannelWitnessBuilder(pub(crate) ChannelWitnessUnion);
+impl ChannelWitnessBuilder {
+    pub const ITEMS_COUNT: usize = 9;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<ChannelWitnessUnion>,
+    {
+        self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for FundBuilder {
-    type Entity = Fund;
-    const NAME: &'static str = "FundBuilder";
+impl molecule::prelude::Builder for ChannelWitnessBuilder {
+    type Entity = ChannelWitness;
+    const NAME: &'static str = "ChannelWitnessBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        Ok(())
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Fund::new_unchecked(inner.into())
+        ChannelWitness::new_unchecked(inner.into())
     }
 }
-#[derive(Clone)]
-pub struct Abort(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Abort {
+#[derive(Debug, Clone)]
+pub enum ChannelWitnessUnion {
+    Fund(Fund),
+    Abort(Abort),
+    Dispute(Dispute),
+    Close(Close),
+    ForceClose(ForceClose),
+    Deposit(Deposit),
+    Withdraw(Withdraw),
+    Rotate(Rotate),
+    Checkpoint(Checkpoint),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum ChannelWitnessUnionReader<'r> {
+    Fund(FundReader<'r>),
+    Abort(AbortReader<'r>),
+    Dispute(DisputeReader<'r>),
+    Close(CloseReader<'r>),
+    ForceClose(ForceCloseReader<'r>),
+    Deposit(DepositReader<'r>),
+    Withdraw(WithdrawReader<'r>),
+    Rotate(RotateReader<'r>),
+    Checkpoint(CheckpointReader<'r>),
+}
+impl ::core::default::Default for ChannelWitnessUnion {
+    fn default() -> Self {
+        ChannelWitnessUnion::Fund(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for ChannelWitnessUnion {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
+        match self {
+            ChannelWitnessUnion::Fund(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Fund::NAME, item)
+            }
+            ChannelWitnessUnion::Abort(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Abort::NAME, item)
+            }
+            ChannelWitnessUnion::Dispute(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Dispute::NAME, item)
+            }
+            ChannelWitnessUnion::Close(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Close::NAME, item)
+            }
+            ChannelWitnessUnion::ForceClose(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ForceClose::NAME, item)
+            }
+            ChannelWitnessUnion::Deposit(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Deposit::NAME, item)
+            }
+            ChannelWitnessUnion::Withdraw(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Withdraw::NAME, item)
+            }
+            ChannelWitnessUnion::Rotate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Rotate::NAME, item)
+            }
+            ChannelWitnessUnion::Checkpoint(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Checkpoint::NAME, item)
+            }
         }
-        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Abort {
+impl<'r> ::core::fmt::Display for ChannelWitnessUnionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+        match self {
+            ChannelWitnessUnionReader::Fund(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Fund::NAME, item)
+            }
+            ChannelWitnessUnionReader::Abort(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Abort::NAME, item)
+            }
+            ChannelWitnessUnionReader::Dispute(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Dispute::NAME, item)
+            }
+            ChannelWitnessUnionReader::Close(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Close::NAME, item)
+            }
+            ChannelWitnessUnionReader::ForceClose(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ForceClose::NAME, item)
+            }
+            ChannelWitnessUnionReader::Deposit(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Deposit::NAME, item)
+            }
+            ChannelWitnessUnionReader::Withdraw(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Withdraw::NAME, item)
+            }
+            ChannelWitnessUnionReader::Rotate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Rotate::NAME, item)
+            }
+            ChannelWitnessUnionReader::Checkpoint(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Checkpoint::NAME, item)
+            }
+        }
     }
 }
-impl ::core::fmt::Display for Abort {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+impl ChannelWitnessUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ChannelWitnessUnion::Fund(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Abort(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Dispute(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Close(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::ForceClose(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Deposit(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Withdraw(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Rotate(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnion::Checkpoint(ref item) => write!(f, "{}", item),
+        }
     }
 }
-impl ::core::default::Default for Abort {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        Abort::new_unchecked(v.into())
+impl<'r> ChannelWitnessUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ChannelWitnessUnionReader::Fund(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Abort(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Dispute(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Close(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::ForceClose(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Deposit(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Withdraw(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Rotate(ref item) => write!(f, "{}", item),
+            ChannelWitnessUnionReader::Checkpoint(ref item) => write!(f, "{}", item),
+        }
     }
 }
-impl Abort {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+impl ::core::convert::From<Fund> for ChannelWitnessUnion {
+    fn from(item: Fund) -> Self {
+        ChannelWitnessUnion::Fund(item)
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+}
+impl ::core::convert::From<Abort> for ChannelWitnessUnion {
+    fn from(item: Abort) -> Self {
+        ChannelWitnessUnion::Abort(item)
     }
-    pub fn as_reader<'r>(&'r self) -> AbortReader<'r> {
-        AbortReader::new_unchecked(self.as_slice())
+}
+impl ::core::convert::From<Dispute> for ChannelWitnessUnion {
+    fn from(item: Dispute) -> Self {
+        ChannelWitnessUnion::Dispute(item)
     }
 }
-impl molecule::prelude::Entity for Abort {
-    type Builder = AbortBuilder;
-    const NAME: &'static str = "Abort";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Abort(data)
+impl ::core::convert::From<Close> for ChannelWitnessUnion {
+    fn from(item: Close) -> Self {
+        ChannelWitnessUnion::Close(item)
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+}
+impl ::core::convert::From<ForceClose> for ChannelWitnessUnion {
+    fn from(item: ForceClose) -> Self {
+        ChannelWitnessUnion::ForceClose(item)
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+}
+impl ::core::convert::From<Deposit> for ChannelWitnessUnion {
+    fn from(item: Deposit) -> Self {
+        ChannelWitnessUnion::Deposit(item)
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AbortReader::from_slice(slice).map(|reader| reader.to_entity())
+}
+impl ::core::convert::From<Withdraw> for ChannelWitnessUnion {
+    fn from(item: Withdraw) -> Self {
+        ChannelWitnessUnion::Withdraw(item)
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AbortReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+}
+impl ::core::convert::From<Rotate> for ChannelWitnessUnion {
+    fn from(item: Rotate) -> Self {
+        ChannelWitnessUnion::Rotate(item)
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+}
+impl ::core::convert::From<Checkpoint> for ChannelWitnessUnion {
+    fn from(item: Checkpoint) -> Self {
+        ChannelWitnessUnion::Checkpoint(item)
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+}
+impl<'r> ::core::convert::From<FundReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: FundReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Fund(item)
     }
 }
-#[derive(Clone, Copy)]
-pub struct AbortReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AbortReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+impl<'r> ::core::convert::From<AbortReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: AbortReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Abort(item)
     }
 }
-impl<'r> ::core::fmt::Debug for AbortReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+impl<'r> ::core::convert::From<DisputeReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: DisputeReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Dispute(item)
     }
 }
-impl<'r> ::core::fmt::Display for AbortReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+impl<'r> ::core::convert::From<CloseReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: CloseReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Close(item)
     }
 }
-impl<'r> AbortReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> ::core::convert::From<ForceCloseReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: ForceCloseReader<'r>) -> Self {
+        ChannelWitnessUnionReader::ForceClose(item)
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+}
+impl<'r> ::core::convert::From<DepositReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: DepositReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Deposit(item)
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AbortReader<'r> {
-    type Entity = Abort;
-    const NAME: &'static str = "AbortReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+impl<'r> ::core::convert::From<WithdrawReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: WithdrawReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Withdraw(item)
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        AbortReader(slice)
+}
+impl<'r> ::core::convert::From<RotateReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: RotateReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Rotate(item)
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+}
+impl<'r> ::core::convert::From<CheckpointReader<'r>> for ChannelWitnessUnionReader<'r> {
+    fn from(item: CheckpointReader<'r>) -> Self {
+        ChannelWitnessUnionReader::Checkpoint(item)
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+}
+impl ChannelWitnessUnion {
+    pub const NAME: &'static str = "ChannelWitnessUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            ChannelWitnessUnion::Fund(item) => item.as_bytes(),
+            ChannelWitnessUnion::Abort(item) => item.as_bytes(),
+            ChannelWitnessUnion::Dispute(item) => item.as_bytes(),
+            ChannelWitnessUnion::Close(item) => item.as_bytes(),
+            ChannelWitnessUnion::ForceClose(item) => item.as_bytes(),
+            ChannelWitnessUnion::Deposit(item) => item.as_bytes(),
+            ChannelWitnessUnion::Withdraw(item) => item.as_bytes(),
+            ChannelWitnessUnion::Rotate(item) => item.as_bytes(),
+            ChannelWitnessUnion::Checkpoint(item) => item.as_bytes(),
         }
-        Ok(())
     }
-}
-pub struct AbortBuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for AbortBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            ChannelWitnessUnion::Fund(item) => item.as_slice(),
+            ChannelWitnessUnion::Abort(item) => item.as_slice(),
+            ChannelWitnessUnion::Dispute(item) => item.as_slice(),
+            ChannelWitnessUnion::Close(item) => item.as_slice(),
+            ChannelWitnessUnion::ForceClose(item) => item.as_slice(),
+            ChannelWitnessUnion::Deposit(item) => item.as_slice(),
+            ChannelWitnessUnion::Withdraw(item) => item.as_slice(),
+            ChannelWitnessUnion::Rotate(item) => item.as_slice(),
+            ChannelWitnessUnion::Checkpoint(item) => item.as_slice(),
+        }
     }
-}
-impl ::core::default::Default for AbortBuilder {
-    fn default() -> Self {
-        AbortBuilder([Byte::default()])
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ChannelWitnessUnion::Fund(_) => 0,
+            ChannelWitnessUnion::Abort(_) => 1,
+            ChannelWitnessUnion::Dispute(_) => 2,
+            ChannelWitnessUnion::Close(_) => 3,
+            ChannelWitnessUnion::ForceClose(_) => 4,
+            ChannelWitnessUnion::Deposit(_) => 5,
+            ChannelWitnessUnion::Withdraw(_) => 6,
+            ChannelWitnessUnion::Rotate(_) => 7,
+            ChannelWitnessUnion::Checkpoint(_) => 8,
+        }
     }
-}
-impl AbortBuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
-        self.0 = v;
-        self
+    pub fn item_name(&self) -> &str {
+        match self {
+            ChannelWitnessUnion::Fund(_) => "Fund",
+            ChannelWitnessUnion::Abort(_) => "Abort",
+            ChannelWitnessUnion::Dispute(_) => "Dispute",
+            ChannelWitnessUnion::Close(_) => "Close",
+            ChannelWitnessUnion::ForceClose(_) => "ForceClose",
+            ChannelWitnessUnion::Deposit(_) => "Deposit",
+            ChannelWitnessUnion::Withdraw(_) => "Withdraw",
+            ChannelWitnessUnion::Rotate(_) => "Rotate",
+            ChannelWitnessUnion::Checkpoint(_) => "Checkpoint",
+        }
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
-        self
+    pub fn as_reader<'r>(&'r self) -> ChannelWitnessUnionReader<'r> {
+        match self {
+            ChannelWitnessUnion::Fund(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Abort(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Dispute(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Close(item) => item.as_reader().into(),
+            ChannelWitnessUnion::ForceClose(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Deposit(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Withdraw(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Rotate(item) => item.as_reader().into(),
+            ChannelWitnessUnion::Checkpoint(item) => item.as_reader().into(),
+        }
     }
 }
-impl molecule::prelude::Builder for AbortBuilder {
-    type Entity = Abort;
-    const NAME: &'static str = "AbortBuilder";
-    fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+impl<'r> ChannelWitnessUnionReader<'r> {
+    pub const NAME: &'r str = "ChannelWitnessUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            ChannelWitnessUnionReader::Fund(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Abort(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Dispute(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Close(item) => item.as_slice(),
+            ChannelWitnessUnionReader::ForceClose(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Deposit(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Withdraw(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Rotate(item) => item.as_slice(),
+            ChannelWitnessUnionReader::Checkpoint(item) => item.as_slice(),
+        }
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        Ok(())
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ChannelWitnessUnionReader::Fund(_) => 0,
+            ChannelWitnessUnionReader::Abort(_) => 1,
+            ChannelWitnessUnionReader::Dispute(_) => 2,
+            ChannelWitnessUnionReader::Close(_) => 3,
+            ChannelWitnessUnionReader::ForceClose(_) => 4,
+            ChannelWitnessUnionReader::Deposit(_) => 5,
+            ChannelWitnessUnionReader::Withdraw(_) => 6,
+            ChannelWitnessUnionReader::Rotate(_) => 7,
+            ChannelWitnessUnionReader::Checkpoint(_) => 8,
+        }
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Abort::new_unchecked(inner.into())
+    pub fn item_name(&self) -> &str {
+        match self {
+            ChannelWitnessUnionReader::Fund(_) => "Fund",
+            ChannelWitnessUnionReader::Abort(_) => "Abort",
+            ChannelWitnessUnionReader::Dispute(_) => "Dispute",
+            ChannelWitnessUnionReader::Close(_) => "Close",
+            ChannelWitnessUnionReader::ForceClose(_) => "ForceClose",
+            ChannelWitnessUnionReader::Deposit(_) => "Deposit",
+            ChannelWitnessUnionReader::Withdraw(_) => "Withdraw",
+            ChannelWitnessUnionReader::Rotate(_) => "Rotate",
+            ChannelWitnessUnionReader::Checkpoint(_) => "Checkpoint",
+        }
     }
 }
 #[derive(Clone)]
-pub struct Dispute(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Dispute {
+pub struct Successor(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Successor {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4704,74 +12006,49 @@ impl ::core::fmt::LowerHex for Dispute {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Dispute {
+impl ::core::fmt::Debug for Successor {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Dispute {
+impl ::core::fmt::Display for Successor {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "sig_a", self.sig_a())?;
-        write!(f, ", {}: {}", "sig_b", self.sig_b())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
         }
-        write!(f, " }}")
     }
 }
-impl ::core::default::Default for Dispute {
+impl ::core::default::Default for Successor {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        Dispute::new_unchecked(v.into())
+        let v: Vec<u8> = vec![];
+        Successor::new_unchecked(v.into())
     }
 }
-impl Dispute {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+impl Successor {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn sig_a(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn sig_b(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+    pub fn to_opt(&self) -> Option<Byte32> {
+        if self.is_none() {
+            None
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Some(Byte32::new_unchecked(self.0.clone()))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> DisputeReader<'r> {
-        DisputeReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SuccessorReader<'r> {
+        SuccessorReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Dispute {
-    type Builder = DisputeBuilder;
-    const NAME: &'static str = "Dispute";
+impl molecule::prelude::Entity for Successor {
+    type Builder = SuccessorBuilder;
+    const NAME: &'static str = "Successor";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Dispute(data)
+        Successor(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4780,21 +12057,21 @@ impl molecule::prelude::Entity for Dispute {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DisputeReader::from_slice(slice).map(|reader| reader.to_entity())
+        SuccessorReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DisputeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SuccessorReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().sig_a(self.sig_a()).sig_b(self.sig_b())
+        Self::new_builder().set(self.to_opt())
     }
 }
 #[derive(Clone, Copy)]
-pub struct DisputeReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for DisputeReader<'r> {
+pub struct SuccessorReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SuccessorReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4803,161 +12080,87 @@ impl<'r> ::core::fmt::LowerHex for DisputeReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for DisputeReader<'r> {
+impl<'r> ::core::fmt::Debug for SuccessorReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for DisputeReader<'r> {
+impl<'r> ::core::fmt::Display for SuccessorReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "sig_a", self.sig_a())?;
-        write!(f, ", {}: {}", "sig_b", self.sig_b())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl<'r> DisputeReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
         } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+            write!(f, "{}(None)", Self::NAME)
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+}
+impl<'r> SuccessorReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn sig_a(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn sig_b(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn to_opt(&self) -> Option<Byte32Reader<'r>> {
+        if self.is_none() {
+            None
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            Some(Byte32Reader::new_unchecked(self.as_slice()))
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for DisputeReader<'r> {
-    type Entity = Dispute;
-    const NAME: &'static str = "DisputeReader";
+impl<'r> molecule::prelude::Reader<'r> for SuccessorReader<'r> {
+    type Entity = Successor;
+    const NAME: &'static str = "SuccessorReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        DisputeReader(slice)
+        SuccessorReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
     fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if !slice.is_empty() {
+            Byte32Reader::verify(&slice[..], compatible)?;
         }
-        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct DisputeBuilder {
-    pub(crate) sig_a: Bytes,
-    pub(crate) sig_b: Bytes,
-}
-impl DisputeBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn sig_a(mut self, v: Bytes) -> Self {
-        self.sig_a = v;
-        self
-    }
-    pub fn sig_b(mut self, v: Bytes) -> Self {
-        self.sig_b = v;
+pub struct SuccessorBuilder(pub(crate) Option<Byte32>);
+impl SuccessorBuilder {
+    pub fn set(mut self, v: Option<Byte32>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for DisputeBuilder {
-    type Entity = Dispute;
-    const NAME: &'static str = "DisputeBuilder";
+impl molecule::prelude::Builder for SuccessorBuilder {
+    type Entity = Successor;
+    const NAME: &'static str = "SuccessorBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.sig_a.as_slice().len()
-            + self.sig_b.as_slice().len()
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.sig_a.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.sig_b.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.sig_a.as_slice())?;
-        writer.write_all(self.sig_b.as_slice())?;
-        Ok(())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Dispute::new_unchecked(inner.into())
+        Successor::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Close(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Close {
+pub struct ChannelState(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4966,17 +12169,20 @@ impl ::core::fmt::LowerHex for Close {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Close {
+impl ::core::fmt::Debug for ChannelState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Close {
+impl ::core::fmt::Display for ChannelState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "state", self.state())?;
-        write!(f, ", {}: {}", "sig_a", self.sig_a())?;
-        write!(f, ", {}: {}", "sig_b", self.sig_b())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "balances", self.balances())?;
+        write!(f, ", {}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "is_final", self.is_final())?;
+        write!(f, ", {}: {}", "data", self.data())?;
+        write!(f, ", {}: {}", "successor", self.successor())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4984,20 +12190,20 @@ impl ::core::fmt::Display for Close {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Close {
+impl ::core::default::Default for ChannelState {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            121, 0, 0, 0, 16, 0, 0, 0, 113, 0, 0, 0, 117, 0, 0, 0, 97, 0, 0, 0, 20, 0, 0, 0, 52, 0,
-            0, 0, 84, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 12, 0, 0, 0, 28, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            113, 0, 0, 0, 28, 0, 0, 0, 60, 0, 0, 0, 96, 0, 0, 0, 104, 0, 0, 0, 109, 0, 0, 0, 113,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0,
+            0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
         ];
-        Close::new_unchecked(v.into())
+        ChannelState::new_unchecked(v.into())
     }
 }
-impl Close {
-    pub const FIELD_COUNT: usize = 3;
+impl ChannelState {
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5014,37 +12220,55 @@ impl Close {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn state(&self) -> ChannelState {
+    pub fn channel_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        ChannelState::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn sig_a(&self) -> Bytes {
+    pub fn balances(&self) -> Balances {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        Balances::new_unchecked(self.0.slice(start..end))
     }
-    pub fn sig_b(&self) -> Bytes {
+    pub fn version(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn is_final(&self) -> Bool {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Bool::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn data(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn successor(&self) -> Successor {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Successor::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Successor::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> CloseReader<'r> {
-        CloseReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ChannelStateReader<'r> {
+        ChannelStateReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Close {
-    type Builder = CloseBuilder;
-    const NAME: &'static str = "Close";
+impl molecule::prelude::Entity for ChannelState {
+    type Builder = ChannelStateBuilder;
+    const NAME: &'static str = "ChannelState";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Close(data)
+        ChannelState(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5053,24 +12277,27 @@ impl molecule::prelude::Entity for Close {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        CloseReader::from_slice(slice).map(|reader| reader.to_entity())
+        ChannelStateReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        CloseReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ChannelStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .state(self.state())
-            .sig_a(self.sig_a())
-            .sig_b(self.sig_b())
+            .channel_id(self.channel_id())
+            .balances(self.balances())
+            .version(self.version())
+            .is_final(self.is_final())
+            .data(self.data())
+            .successor(self.successor())
     }
 }
 #[derive(Clone, Copy)]
-pub struct CloseReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for CloseReader<'r> {
+pub struct ChannelStateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5079,17 +12306,20 @@ impl<'r> ::core::fmt::LowerHex for CloseReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for CloseReader<'r> {
+impl<'r> ::core::fmt::Debug for ChannelStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for CloseReader<'r> {
+impl<'r> ::core::fmt::Display for ChannelStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "state", self.state())?;
-        write!(f, ", {}: {}", "sig_a", self.sig_a())?;
-        write!(f, ", {}: {}", "sig_b", self.sig_b())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "balances", self.balances())?;
+        write!(f, ", {}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "is_final", self.is_final())?;
+        write!(f, ", {}: {}", "data", self.data())?;
+        write!(f, ", {}: {}", "successor", self.successor())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -5097,8 +12327,8 @@ impl<'r> ::core::fmt::Display for CloseReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> CloseReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> ChannelStateReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5115,37 +12345,55 @@ impl<'r> CloseReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn state(&self) -> ChannelStateReader<'r> {
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        ChannelStateReader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn sig_a(&self) -> BytesReader<'r> {
+    pub fn balances(&self) -> BalancesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        BalancesReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn sig_b(&self) -> BytesReader<'r> {
+    pub fn version(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn is_final(&self) -> BoolReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BoolReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn data(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn successor(&self) -> SuccessorReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            SuccessorReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            SuccessorReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for CloseReader<'r> {
-    type Entity = Close;
-    const NAME: &'static str = "CloseReader";
+impl<'r> molecule::prelude::Reader<'r> for ChannelStateReader<'r> {
+    type Entity = ChannelState;
+    const NAME: &'static str = "ChannelStateReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        CloseReader(slice)
+        ChannelStateReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -5187,70 +12435,100 @@ impl<'r> molecule::prelude::Reader<'r> for CloseReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ChannelStateReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BalancesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BoolReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        SuccessorReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct CloseBuilder {
-    pub(crate) state: ChannelState,
-    pub(crate) sig_a: Bytes,
-    pub(crate) sig_b: Bytes,
+pub struct ChannelStateBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) balances: Balances,
+    pub(crate) version: Uint64,
+    pub(crate) is_final: Bool,
+    pub(crate) data: Bytes,
+    pub(crate) successor: Successor,
 }
-impl CloseBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn state(mut self, v: ChannelState) -> Self {
-        self.state = v;
+impl ChannelStateBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
+        self
+    }
+    pub fn balances(mut self, v: Balances) -> Self {
+        self.balances = v;
         self
     }
-    pub fn sig_a(mut self, v: Bytes) -> Self {
-        self.sig_a = v;
+    pub fn version(mut self, v: Uint64) -> Self {
+        self.version = v;
+        self
+    }
+    pub fn is_final(mut self, v: Bool) -> Self {
+        self.is_final = v;
+        self
+    }
+    pub fn data(mut self, v: Bytes) -> Self {
+        self.data = v;
         self
     }
-    pub fn sig_b(mut self, v: Bytes) -> Self {
-        self.sig_b = v;
+    pub fn successor(mut self, v: Successor) -> Self {
+        self.successor = v;
         self
     }
 }
-impl molecule::prelude::Builder for CloseBuilder {
-    type Entity = Close;
-    const NAME: &'static str = "CloseBuilder";
+impl molecule::prelude::Builder for ChannelStateBuilder {
+    type Entity = ChannelState;
+    const NAME: &'static str = "ChannelStateBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.state.as_slice().len()
-            + self.sig_a.as_slice().len()
-            + self.sig_b.as_slice().len()
+            + self.channel_id.as_slice().len()
+            + self.balances.as_slice().len()
+            + self.version.as_slice().len()
+            + self.is_final.as_slice().len()
+            + self.data.as_slice().len()
+            + self.successor.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.state.as_slice().len();
+        total_size += self.channel_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.balances.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.version.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.is_final.as_slice().len();
         offsets.push(total_size);
-        total_size += self.sig_a.as_slice().len();
+        total_size += self.data.as_slice().len();
         offsets.push(total_size);
-        total_size += self.sig_b.as_slice().len();
+        total_size += self.successor.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.state.as_slice())?;
-        writer.write_all(self.sig_a.as_slice())?;
-        writer.write_all(self.sig_b.as_slice())?;
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.balances.as_slice())?;
+        writer.write_all(self.version.as_slice())?;
+        writer.write_all(self.is_final.as_slice())?;
+        writer.write_all(self.data.as_slice())?;
+        writer.write_all(self.successor.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Close::new_unchecked(inner.into())
+        ChannelState::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ForceClose(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ForceClose {
+pub struct BoolVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BoolVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5259,43 +12537,75 @@ impl ::core::fmt::LowerHex for ForceClose {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ForceClose {
+impl ::core::fmt::Debug for BoolVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ForceClose {
+impl ::core::fmt::Display for BoolVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for ForceClose {
+impl ::core::default::Default for BoolVec {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0];
-        ForceClose::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        BoolVec::new_unchecked(v.into())
     }
 }
-impl ForceClose {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+impl BoolVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> ForceCloseReader<'r> {
-        ForceCloseReader::new_unchecked(self.as_slice())
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Bool> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Bool {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            Bool::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            Bool::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BoolVecReader<'r> {
+        BoolVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ForceClose {
-    type Builder = ForceCloseBuilder;
-    const NAME: &'static str = "ForceClose";
+impl molecule::prelude::Entity for BoolVec {
+    type Builder = BoolVecBuilder;
+    const NAME: &'static str = "BoolVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ForceClose(data)
+        BoolVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5304,117 +12614,260 @@ impl molecule::prelude::Entity for ForceClose {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ForceCloseReader::from_slice(slice).map(|reader| reader.to_entity())
+        BoolVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ForceCloseReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BoolVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([self.nth0()])
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ForceCloseReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ForceCloseReader<'r> {
+pub struct BoolVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BoolVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
             write!(f, "0x")?;
         }
-        write!(f, "{}", hex_string(self.as_slice()))
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BoolVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BoolVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BoolVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-}
-impl<'r> ::core::fmt::Debug for ForceCloseReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-}
-impl<'r> ::core::fmt::Display for ForceCloseReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-}
-impl<'r> ForceCloseReader<'r> {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    pub fn get(&self, idx: usize) -> Option<BoolReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+    pub fn get_unchecked(&self, idx: usize) -> BoolReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BoolReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BoolReader::new_unchecked(&self.as_slice()[start..end])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ForceCloseReader<'r> {
-    type Entity = ForceClose;
-    const NAME: &'static str = "ForceCloseReader";
+impl<'r> molecule::prelude::Reader<'r> for BoolVecReader<'r> {
+    type Entity = BoolVec;
+    const NAME: &'static str = "BoolVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ForceCloseReader(slice)
+        BoolVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            BoolReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
-pub struct ForceCloseBuilder(pub(crate) [Byte; 1]);
-impl ::core::fmt::Debug for ForceCloseBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
-    }
-}
-impl ::core::default::Default for ForceCloseBuilder {
-    fn default() -> Self {
-        ForceCloseBuilder([Byte::default()])
-    }
-}
-impl ForceCloseBuilder {
-    pub const TOTAL_SIZE: usize = 1;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 1;
-    pub fn set(mut self, v: [Byte; 1]) -> Self {
+#[derive(Debug, Default)]
+pub struct BoolVecBuilder(pub(crate) Vec<Bool>);
+impl BoolVecBuilder {
+    pub fn set(mut self, v: Vec<Bool>) -> Self {
         self.0 = v;
         self
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
+    pub fn push(mut self, v: Bool) -> Self {
+        self.0.push(v);
         self
     }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Bool>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Bool) -> Option<Bool> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for ForceCloseBuilder {
-    type Entity = ForceClose;
-    const NAME: &'static str = "ForceCloseBuilder";
+impl molecule::prelude::Builder for BoolVecBuilder {
+    type Entity = BoolVec;
+    const NAME: &'static str = "BoolVecBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ForceClose::new_unchecked(inner.into())
+        BoolVec::new_unchecked(inner.into())
+    }
+}
+pub struct BoolVecIterator(BoolVec, usize, usize);
+impl ::core::iter::Iterator for BoolVecIterator {
+    type Item = Bool;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BoolVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BoolVec {
+    type Item = Bool;
+    type IntoIter = BoolVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BoolVecIterator(self, 0, len)
+    }
+}
+impl<'r> BoolVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> BoolVecReaderIterator<'t, 'r> {
+        BoolVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BoolVecReaderIterator<'t, 'r>(&'t BoolVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BoolVecReaderIterator<'t, 'r> {
+    type Item = BoolReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BoolVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct ChannelWitness(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelWitness {
+pub struct ChannelStates(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelStates {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5423,49 +12876,75 @@ impl ::core::fmt::LowerHex for ChannelWitness {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelWitness {
+impl ::core::fmt::Debug for ChannelStates {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelWitness {
+impl ::core::fmt::Display for ChannelStates {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for ChannelWitness {
+impl ::core::default::Default for ChannelStates {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0, 0];
-        ChannelWitness::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        ChannelStates::new_unchecked(v.into())
     }
 }
-impl ChannelWitness {
-    pub const ITEMS_COUNT: usize = 5;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl ChannelStates {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> ChannelWitnessUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => Fund::new_unchecked(inner).into(),
-            1 => Abort::new_unchecked(inner).into(),
-            2 => Dispute::new_unchecked(inner).into(),
-            3 => Close::new_unchecked(inner).into(),
-            4 => ForceClose::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelWitnessReader<'r> {
-        ChannelWitnessReader::new_unchecked(self.as_slice())
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ChannelState> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ChannelState {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            ChannelState::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            ChannelState::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ChannelStatesReader<'r> {
+        ChannelStatesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelWitness {
-    type Builder = ChannelWitnessBuilder;
-    const NAME: &'static str = "ChannelWitness";
+impl molecule::prelude::Entity for ChannelStates {
+    type Builder = ChannelStatesBuilder;
+    const NAME: &'static str = "ChannelStates";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelWitness(data)
+        ChannelStates(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5474,21 +12953,21 @@ impl molecule::prelude::Entity for ChannelWitness {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelWitnessReader::from_slice(slice).map(|reader| reader.to_entity())
+        ChannelStatesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelWitnessReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ChannelStatesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder().extend(self.into_iter())
     }
 }
-#[derive(Clone, Copy)]
-pub struct ChannelWitnessReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelWitnessReader<'r> {
+#[derive(Clone, Copy)]
+pub struct ChannelStatesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelStatesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5497,43 +12976,69 @@ impl<'r> ::core::fmt::LowerHex for ChannelWitnessReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelWitnessReader<'r> {
+impl<'r> ::core::fmt::Debug for ChannelStatesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelWitnessReader<'r> {
+impl<'r> ::core::fmt::Display for ChannelStatesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
     }
 }
-impl<'r> ChannelWitnessReader<'r> {
-    pub const ITEMS_COUNT: usize = 5;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl<'r> ChannelStatesReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> ChannelWitnessUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => FundReader::new_unchecked(inner).into(),
-            1 => AbortReader::new_unchecked(inner).into(),
-            2 => DisputeReader::new_unchecked(inner).into(),
-            3 => CloseReader::new_unchecked(inner).into(),
-            4 => ForceCloseReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ChannelStateReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ChannelStateReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            ChannelStateReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            ChannelStateReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelWitnessReader<'r> {
-    type Entity = ChannelWitness;
-    const NAME: &'static str = "ChannelWitnessReader";
+impl<'r> molecule::prelude::Reader<'r> for ChannelStatesReader<'r> {
+    type Entity = ChannelStates;
+    const NAME: &'static str = "ChannelStatesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelWitnessReader(slice)
+        ChannelStatesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -5544,264 +13049,327 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelWitnessReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => FundReader::verify(inner_slice, compatible),
-            1 => AbortReader::verify(inner_slice, compatible),
-            2 => DisputeReader::verify(inner_slice, compatible),
-            3 => CloseReader::verify(inner_slice, compatible),
-            4 => ForceCloseReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-        }?;
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            ChannelStateReader::verify(&slice[start..end], compatible)?;
+        }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ChannelWitnessBuilder(pub(crate) ChannelWitnessUnion);
-impl ChannelWitnessBuilder {
-    pub const ITEMS_COUNT: usize = 5;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<ChannelWitnessUnion>,
-    {
-        self.0 = v.into();
+pub struct ChannelStatesBuilder(pub(crate) Vec<ChannelState>);
+impl ChannelStatesBuilder {
+    pub fn set(mut self, v: Vec<ChannelState>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: ChannelState) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = ChannelState>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: ChannelState) -> Option<ChannelState> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for ChannelWitnessBuilder {
-    type Entity = ChannelWitness;
-    const NAME: &'static str = "ChannelWitnessBuilder";
+impl molecule::prelude::Builder for ChannelStatesBuilder {
+    type Entity = ChannelStates;
+    const NAME: &'static str = "ChannelStatesBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelWitness::new_unchecked(inner.into())
+        ChannelStates::new_unchecked(inner.into())
     }
 }
-#[derive(Debug, Clone)]
-pub enum ChannelWitnessUnion {
-    Fund(Fund),
-    Abort(Abort),
-    Dispute(Dispute),
-    Close(Close),
-    ForceClose(ForceClose),
+pub struct ChannelStatesIterator(ChannelStates, usize, usize);
+impl ::core::iter::Iterator for ChannelStatesIterator {
+    type Item = ChannelState;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
 }
-#[derive(Debug, Clone, Copy)]
-pub enum ChannelWitnessUnionReader<'r> {
-    Fund(FundReader<'r>),
-    Abort(AbortReader<'r>),
-    Dispute(DisputeReader<'r>),
-    Close(CloseReader<'r>),
-    ForceClose(ForceCloseReader<'r>),
+impl ::core::iter::ExactSizeIterator for ChannelStatesIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
 }
-impl ::core::default::Default for ChannelWitnessUnion {
-    fn default() -> Self {
-        ChannelWitnessUnion::Fund(::core::default::Default::default())
+impl ::core::iter::IntoIterator for ChannelStates {
+    type Item = ChannelState;
+    type IntoIter = ChannelStatesIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        ChannelStatesIterator(self, 0, len)
     }
 }
-impl ::core::fmt::Display for ChannelWitnessUnion {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ChannelWitnessUnion::Fund(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Fund::NAME, item)
-            }
-            ChannelWitnessUnion::Abort(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Abort::NAME, item)
-            }
-            ChannelWitnessUnion::Dispute(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Dispute::NAME, item)
-            }
-            ChannelWitnessUnion::Close(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Close::NAME, item)
-            }
-            ChannelWitnessUnion::ForceClose(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ForceClose::NAME, item)
-            }
-        }
+impl<'r> ChannelStatesReader<'r> {
+    pub fn iter<'t>(&'t self) -> ChannelStatesReaderIterator<'t, 'r> {
+        ChannelStatesReaderIterator(&self, 0, self.len())
     }
 }
-impl<'r> ::core::fmt::Display for ChannelWitnessUnionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ChannelWitnessUnionReader::Fund(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Fund::NAME, item)
-            }
-            ChannelWitnessUnionReader::Abort(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Abort::NAME, item)
-            }
-            ChannelWitnessUnionReader::Dispute(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Dispute::NAME, item)
-            }
-            ChannelWitnessUnionReader::Close(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Close::NAME, item)
-            }
-            ChannelWitnessUnionReader::ForceClose(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ForceClose::NAME, item)
-            }
+pub struct ChannelStatesReaderIterator<'t, 'r>(&'t ChannelStatesReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for ChannelStatesReaderIterator<'t, 'r> {
+    type Item = ChannelStateReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
         }
     }
 }
-impl ChannelWitnessUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ChannelWitnessUnion::Fund(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnion::Abort(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnion::Dispute(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnion::Close(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnion::ForceClose(ref item) => write!(f, "{}", item),
-        }
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for ChannelStatesReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
-impl<'r> ChannelWitnessUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ChannelWitnessUnionReader::Fund(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnionReader::Abort(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnionReader::Dispute(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnionReader::Close(ref item) => write!(f, "{}", item),
-            ChannelWitnessUnionReader::ForceClose(ref item) => write!(f, "{}", item),
+#[derive(Clone)]
+pub struct DisputeDeadline(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DisputeDeadline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
         }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::convert::From<Fund> for ChannelWitnessUnion {
-    fn from(item: Fund) -> Self {
-        ChannelWitnessUnion::Fund(item)
+impl ::core::fmt::Debug for DisputeDeadline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::convert::From<Abort> for ChannelWitnessUnion {
-    fn from(item: Abort) -> Self {
-        ChannelWitnessUnion::Abort(item)
+impl ::core::fmt::Display for DisputeDeadline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
 }
-impl ::core::convert::From<Dispute> for ChannelWitnessUnion {
-    fn from(item: Dispute) -> Self {
-        ChannelWitnessUnion::Dispute(item)
+impl ::core::default::Default for DisputeDeadline {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        DisputeDeadline::new_unchecked(v.into())
     }
 }
-impl ::core::convert::From<Close> for ChannelWitnessUnion {
-    fn from(item: Close) -> Self {
-        ChannelWitnessUnion::Close(item)
+impl DisputeDeadline {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-}
-impl ::core::convert::From<ForceClose> for ChannelWitnessUnion {
-    fn from(item: ForceClose) -> Self {
-        ChannelWitnessUnion::ForceClose(item)
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-}
-impl<'r> ::core::convert::From<FundReader<'r>> for ChannelWitnessUnionReader<'r> {
-    fn from(item: FundReader<'r>) -> Self {
-        ChannelWitnessUnionReader::Fund(item)
+    pub fn to_opt(&self) -> Option<Uint64> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint64::new_unchecked(self.0.clone()))
+        }
     }
-}
-impl<'r> ::core::convert::From<AbortReader<'r>> for ChannelWitnessUnionReader<'r> {
-    fn from(item: AbortReader<'r>) -> Self {
-        ChannelWitnessUnionReader::Abort(item)
+    pub fn as_reader<'r>(&'r self) -> DisputeDeadlineReader<'r> {
+        DisputeDeadlineReader::new_unchecked(self.as_slice())
     }
 }
-impl<'r> ::core::convert::From<DisputeReader<'r>> for ChannelWitnessUnionReader<'r> {
-    fn from(item: DisputeReader<'r>) -> Self {
-        ChannelWitnessUnionReader::Dispute(item)
+impl molecule::prelude::Entity for DisputeDeadline {
+    type Builder = DisputeDeadlineBuilder;
+    const NAME: &'static str = "DisputeDeadline";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        DisputeDeadline(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DisputeDeadlineReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DisputeDeadlineReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
     }
 }
-impl<'r> ::core::convert::From<CloseReader<'r>> for ChannelWitnessUnionReader<'r> {
-    fn from(item: CloseReader<'r>) -> Self {
-        ChannelWitnessUnionReader::Close(item)
+#[derive(Clone, Copy)]
+pub struct DisputeDeadlineReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DisputeDeadlineReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::convert::From<ForceCloseReader<'r>> for ChannelWitnessUnionReader<'r> {
-    fn from(item: ForceCloseReader<'r>) -> Self {
-        ChannelWitnessUnionReader::ForceClose(item)
+impl<'r> ::core::fmt::Debug for DisputeDeadlineReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ChannelWitnessUnion {
-    pub const NAME: &'static str = "ChannelWitnessUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            ChannelWitnessUnion::Fund(item) => item.as_bytes(),
-            ChannelWitnessUnion::Abort(item) => item.as_bytes(),
-            ChannelWitnessUnion::Dispute(item) => item.as_bytes(),
-            ChannelWitnessUnion::Close(item) => item.as_bytes(),
-            ChannelWitnessUnion::ForceClose(item) => item.as_bytes(),
+impl<'r> ::core::fmt::Display for DisputeDeadlineReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
         }
     }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            ChannelWitnessUnion::Fund(item) => item.as_slice(),
-            ChannelWitnessUnion::Abort(item) => item.as_slice(),
-            ChannelWitnessUnion::Dispute(item) => item.as_slice(),
-            ChannelWitnessUnion::Close(item) => item.as_slice(),
-            ChannelWitnessUnion::ForceClose(item) => item.as_slice(),
-        }
+}
+impl<'r> DisputeDeadlineReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            ChannelWitnessUnion::Fund(_) => 0,
-            ChannelWitnessUnion::Abort(_) => 1,
-            ChannelWitnessUnion::Dispute(_) => 2,
-            ChannelWitnessUnion::Close(_) => 3,
-            ChannelWitnessUnion::ForceClose(_) => 4,
-        }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            ChannelWitnessUnion::Fund(_) => "Fund",
-            ChannelWitnessUnion::Abort(_) => "Abort",
-            ChannelWitnessUnion::Dispute(_) => "Dispute",
-            ChannelWitnessUnion::Close(_) => "Close",
-            ChannelWitnessUnion::ForceClose(_) => "ForceClose",
+    pub fn to_opt(&self) -> Option<Uint64Reader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Uint64Reader::new_unchecked(self.as_slice()))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelWitnessUnionReader<'r> {
-        match self {
-            ChannelWitnessUnion::Fund(item) => item.as_reader().into(),
-            ChannelWitnessUnion::Abort(item) => item.as_reader().into(),
-            ChannelWitnessUnion::Dispute(item) => item.as_reader().into(),
-            ChannelWitnessUnion::Close(item) => item.as_reader().into(),
-            ChannelWitnessUnion::ForceClose(item) => item.as_reader().into(),
+}
+impl<'r> molecule::prelude::Reader<'r> for DisputeDeadlineReader<'r> {
+    type Entity = DisputeDeadline;
+    const NAME: &'static str = "DisputeDeadlineReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DisputeDeadlineReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            Uint64Reader::verify(&slice[..], compatible)?;
         }
+        Ok(())
     }
 }
-impl<'r> ChannelWitnessUnionReader<'r> {
-    pub const NAME: &'r str = "ChannelWitnessUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            ChannelWitnessUnionReader::Fund(item) => item.as_slice(),
-            ChannelWitnessUnionReader::Abort(item) => item.as_slice(),
-            ChannelWitnessUnionReader::Dispute(item) => item.as_slice(),
-            ChannelWitnessUnionReader::Close(item) => item.as_slice(),
-            ChannelWitnessUnionReader::ForceClose(item) => item.as_slice(),
-        }
+#[derive(Debug, Default)]
+pub struct DisputeDeadlineBuilder(pub(crate) Option<Uint64>);
+impl DisputeDeadlineBuilder {
+    pub fn set(mut self, v: Option<Uint64>) -> Self {
+        self.0 = v;
+        self
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            ChannelWitnessUnionReader::Fund(_) => 0,
-            ChannelWitnessUnionReader::Abort(_) => 1,
-            ChannelWitnessUnionReader::Dispute(_) => 2,
-            ChannelWitnessUnionReader::Close(_) => 3,
-            ChannelWitnessUnionReader::ForceClose(_) => 4,
-        }
+}
+impl molecule::prelude::Builder for DisputeDeadlineBuilder {
+    type Entity = DisputeDeadline;
+    const NAME: &'static str = "DisputeDeadlineBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            ChannelWitnessUnionReader::Fund(_) => "Fund",
-            ChannelWitnessUnionReader::Abort(_) => "Abort",
-            ChannelWitnessUnionReader::Dispute(_) => "Dispute",
-            ChannelWitnessUnionReader::Close(_) => "Close",
-            ChannelWitnessUnionReader::ForceClose(_) => "ForceClose",
-        }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        DisputeDeadline::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ChannelState(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelState {
+pub struct BondHolder(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BondHolder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5810,91 +13378,49 @@ impl ::core::fmt::LowerHex for ChannelState {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelState {
+impl ::core::fmt::Debug for BondHolder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelState {
+impl ::core::fmt::Display for BondHolder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "balances", self.balances())?;
-        write!(f, ", {}: {}", "version", self.version())?;
-        write!(f, ", {}: {}", "is_final", self.is_final())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
         }
-        write!(f, " }}")
     }
 }
-impl ::core::default::Default for ChannelState {
+impl ::core::default::Default for BondHolder {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            97, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0,
-            12, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        ChannelState::new_unchecked(v.into())
+        let v: Vec<u8> = vec![];
+        BondHolder::new_unchecked(v.into())
     }
 }
-impl ChannelState {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn channel_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn balances(&self) -> Balances {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Balances::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn version(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn is_final(&self) -> Bool {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Bool::new_unchecked(self.0.slice(start..end))
+impl BondHolder {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Byte> {
+        if self.is_none() {
+            None
         } else {
-            Bool::new_unchecked(self.0.slice(start..))
+            Some(Byte::new_unchecked(self.0.clone()))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelStateReader<'r> {
-        ChannelStateReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BondHolderReader<'r> {
+        BondHolderReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelState {
-    type Builder = ChannelStateBuilder;
-    const NAME: &'static str = "ChannelState";
+impl molecule::prelude::Entity for BondHolder {
+    type Builder = BondHolderBuilder;
+    const NAME: &'static str = "BondHolder";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelState(data)
+        BondHolder(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5903,25 +13429,21 @@ impl molecule::prelude::Entity for ChannelState {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelStateReader::from_slice(slice).map(|reader| reader.to_entity())
+        BondHolderReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BondHolderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .channel_id(self.channel_id())
-            .balances(self.balances())
-            .version(self.version())
-            .is_final(self.is_final())
+        Self::new_builder().set(self.to_opt())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelStateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelStateReader<'r> {
+pub struct BondHolderReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BondHolderReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5930,190 +13452,82 @@ impl<'r> ::core::fmt::LowerHex for ChannelStateReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelStateReader<'r> {
+impl<'r> ::core::fmt::Debug for BondHolderReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelStateReader<'r> {
+impl<'r> ::core::fmt::Display for BondHolderReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "balances", self.balances())?;
-        write!(f, ", {}: {}", "version", self.version())?;
-        write!(f, ", {}: {}", "is_final", self.is_final())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl<'r> ChannelStateReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
         } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+            write!(f, "{}(None)", Self::NAME)
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn channel_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn balances(&self) -> BalancesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        BalancesReader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> BondHolderReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn version(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn is_final(&self) -> BoolReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            BoolReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn to_opt(&self) -> Option<ByteReader<'r>> {
+        if self.is_none() {
+            None
         } else {
-            BoolReader::new_unchecked(&self.as_slice()[start..])
+            Some(ByteReader::new_unchecked(self.as_slice()))
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelStateReader<'r> {
-    type Entity = ChannelState;
-    const NAME: &'static str = "ChannelStateReader";
+impl<'r> molecule::prelude::Reader<'r> for BondHolderReader<'r> {
+    type Entity = BondHolder;
+    const NAME: &'static str = "BondHolderReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelStateReader(slice)
+        BondHolderReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
     fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if !slice.is_empty() {
+            ByteReader::verify(&slice[..], compatible)?;
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BalancesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        BoolReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ChannelStateBuilder {
-    pub(crate) channel_id: Byte32,
-    pub(crate) balances: Balances,
-    pub(crate) version: Uint64,
-    pub(crate) is_final: Bool,
-}
-impl ChannelStateBuilder {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn channel_id(mut self, v: Byte32) -> Self {
-        self.channel_id = v;
-        self
-    }
-    pub fn balances(mut self, v: Balances) -> Self {
-        self.balances = v;
-        self
-    }
-    pub fn version(mut self, v: Uint64) -> Self {
-        self.version = v;
-        self
-    }
-    pub fn is_final(mut self, v: Bool) -> Self {
-        self.is_final = v;
+pub struct BondHolderBuilder(pub(crate) Option<Byte>);
+impl BondHolderBuilder {
+    pub fn set(mut self, v: Option<Byte>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelStateBuilder {
-    type Entity = ChannelState;
-    const NAME: &'static str = "ChannelStateBuilder";
+impl molecule::prelude::Builder for BondHolderBuilder {
+    type Entity = BondHolder;
+    const NAME: &'static str = "BondHolderBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.channel_id.as_slice().len()
-            + self.balances.as_slice().len()
-            + self.version.as_slice().len()
-            + self.is_final.as_slice().len()
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.channel_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.balances.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.version.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.is_final.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.channel_id.as_slice())?;
-        writer.write_all(self.balances.as_slice())?;
-        writer.write_all(self.version.as_slice())?;
-        writer.write_all(self.is_final.as_slice())?;
-        Ok(())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelState::new_unchecked(inner.into())
+        BondHolder::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -6138,6 +13552,11 @@ impl ::core::fmt::Display for ChannelStatus {
         write!(f, "{}: {}", "state", self.state())?;
         write!(f, ", {}: {}", "funded", self.funded())?;
         write!(f, ", {}: {}", "disputed", self.disputed())?;
+        write!(f, ", {}: {}", "funded_parties", self.funded_parties())?;
+        write!(f, ", {}: {}", "sub_states", self.sub_states())?;
+        write!(f, ", {}: {}", "dispute_deadline", self.dispute_deadline())?;
+        write!(f, ", {}: {}", "bond_holder", self.bond_holder())?;
+        write!(f, ", {}: {}", "spent_fee", self.spent_fee())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6148,17 +13567,19 @@ impl ::core::fmt::Display for ChannelStatus {
 impl ::core::default::Default for ChannelStatus {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            123, 0, 0, 0, 16, 0, 0, 0, 113, 0, 0, 0, 118, 0, 0, 0, 97, 0, 0, 0, 20, 0, 0, 0, 52, 0,
-            0, 0, 84, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 12, 0, 0, 0, 28, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            175, 0, 0, 0, 36, 0, 0, 0, 149, 0, 0, 0, 154, 0, 0, 0, 159, 0, 0, 0, 163, 0, 0, 0, 167,
+            0, 0, 0, 167, 0, 0, 0, 167, 0, 0, 0, 113, 0, 0, 0, 28, 0, 0, 0, 60, 0, 0, 0, 96, 0, 0,
+            0, 104, 0, 0, 0, 109, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 20, 0, 0, 0, 24, 0,
+            0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+            4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         ChannelStatus::new_unchecked(v.into())
     }
 }
 impl ChannelStatus {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 8;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6190,11 +13611,41 @@ impl ChannelStatus {
     pub fn disputed(&self) -> Bool {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bool::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn funded_parties(&self) -> BoolVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BoolVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sub_states(&self) -> ChannelStates {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ChannelStates::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn dispute_deadline(&self) -> DisputeDeadline {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        DisputeDeadline::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bond_holder(&self) -> BondHolder {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        BondHolder::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn spent_fee(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Bool::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[36..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Bool::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> ChannelStatusReader<'r> {
@@ -6227,6 +13678,11 @@ impl molecule::prelude::Entity for ChannelStatus {
             .state(self.state())
             .funded(self.funded())
             .disputed(self.disputed())
+            .funded_parties(self.funded_parties())
+            .sub_states(self.sub_states())
+            .dispute_deadline(self.dispute_deadline())
+            .bond_holder(self.bond_holder())
+            .spent_fee(self.spent_fee())
     }
 }
 #[derive(Clone, Copy)]
@@ -6251,6 +13707,11 @@ impl<'r> ::core::fmt::Display for ChannelStatusReader<'r> {
         write!(f, "{}: {}", "state", self.state())?;
         write!(f, ", {}: {}", "funded", self.funded())?;
         write!(f, ", {}: {}", "disputed", self.disputed())?;
+        write!(f, ", {}: {}", "funded_parties", self.funded_parties())?;
+        write!(f, ", {}: {}", "sub_states", self.sub_states())?;
+        write!(f, ", {}: {}", "dispute_deadline", self.dispute_deadline())?;
+        write!(f, ", {}: {}", "bond_holder", self.bond_holder())?;
+        write!(f, ", {}: {}", "spent_fee", self.spent_fee())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6259,7 +13720,7 @@ impl<'r> ::core::fmt::Display for ChannelStatusReader<'r> {
     }
 }
 impl<'r> ChannelStatusReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 8;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6291,11 +13752,41 @@ impl<'r> ChannelStatusReader<'r> {
     pub fn disputed(&self) -> BoolReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BoolReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn funded_parties(&self) -> BoolVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BoolVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sub_states(&self) -> ChannelStatesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ChannelStatesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn dispute_deadline(&self) -> DisputeDeadlineReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        DisputeDeadlineReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bond_holder(&self) -> BondHolderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        BondHolderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn spent_fee(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            BoolReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[36..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BoolReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -6351,6 +13842,11 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelStatusReader<'r> {
         ChannelStateReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         BoolReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         BoolReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BoolVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ChannelStatesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        DisputeDeadlineReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        BondHolderReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
         Ok(())
     }
 }
@@ -6359,9 +13855,14 @@ pub struct ChannelStatusBuilder {
     pub(crate) state: ChannelState,
     pub(crate) funded: Bool,
     pub(crate) disputed: Bool,
+    pub(crate) funded_parties: BoolVec,
+    pub(crate) sub_states: ChannelStates,
+    pub(crate) dispute_deadline: DisputeDeadline,
+    pub(crate) bond_holder: BondHolder,
+    pub(crate) spent_fee: Uint64,
 }
 impl ChannelStatusBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 8;
     pub fn state(mut self, v: ChannelState) -> Self {
         self.state = v;
         self
@@ -6374,6 +13875,26 @@ impl ChannelStatusBuilder {
         self.disputed = v;
         self
     }
+    pub fn funded_parties(mut self, v: BoolVec) -> Self {
+        self.funded_parties = v;
+        self
+    }
+    pub fn sub_states(mut self, v: ChannelStates) -> Self {
+        self.sub_states = v;
+        self
+    }
+    pub fn dispute_deadline(mut self, v: DisputeDeadline) -> Self {
+        self.dispute_deadline = v;
+        self
+    }
+    pub fn bond_holder(mut self, v: BondHolder) -> Self {
+        self.bond_holder = v;
+        self
+    }
+    pub fn spent_fee(mut self, v: Uint64) -> Self {
+        self.spent_fee = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for ChannelStatusBuilder {
     type Entity = ChannelStatus;
@@ -6383,6 +13904,11 @@ impl molecule::prelude::Builder for ChannelStatusBuilder {
             + self.state.as_slice().len()
             + self.funded.as_slice().len()
             + self.disputed.as_slice().len()
+            + self.funded_parties.as_slice().len()
+            + self.sub_states.as_slice().len()
+            + self.dispute_deadline.as_slice().len()
+            + self.bond_holder.as_slice().len()
+            + self.spent_fee.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -6393,6 +13919,16 @@ impl molecule::prelude::Builder for ChannelStatusBuilder {
         total_size += self.funded.as_slice().len();
         offsets.push(total_size);
         total_size += self.disputed.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.funded_parties.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sub_states.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.dispute_deadline.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bond_holder.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.spent_fee.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -6400,6 +13936,11 @@ impl molecule::prelude::Builder for ChannelStatusBuilder {
         writer.write_all(self.state.as_slice())?;
         writer.write_all(self.funded.as_slice())?;
         writer.write_all(self.disputed.as_slice())?;
+        writer.write_all(self.funded_parties.as_slice())?;
+        writer.write_all(self.sub_states.as_slice())?;
+        writer.write_all(self.dispute_deadline.as_slice())?;
+        writer.write_all(self.bond_holder.as_slice())?;
+        writer.write_all(self.spent_fee.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1,7 +1,22 @@
-use k256::{ecdsa::{VerifyingKey, Signature, signature::{hazmat::PrehashVerifier}}, elliptic_curve::sec1::EncodedPoint, Secp256k1};
+use k256::{ecdsa::{VerifyingKey, Signature, recoverable, signature::{hazmat::PrehashVerifier}}, elliptic_curve::sec1::EncodedPoint, schnorr, FieldBytes, Secp256k1};
 
 use crate::error::Error;
 
+/// SIG_SCHEME_ECDSA_DER denotes DER encoded secp256k1 ECDSA signatures on the raw message hash.
+pub const SIG_SCHEME_ECDSA_DER: u8 = 0;
+/// SIG_SCHEME_SCHNORR denotes 64 byte BIP340 Schnorr signatures on the raw message hash.
+pub const SIG_SCHEME_SCHNORR: u8 = 1;
+/// SIG_SCHEME_ECDSA_RECOVERABLE denotes 65 byte recoverable secp256k1 ECDSA signatures (r || s || v)
+/// on the raw message hash.
+pub const SIG_SCHEME_ECDSA_RECOVERABLE: u8 = 2;
+/// SIG_SCHEME_ETH_PERSONAL_SIGN denotes 65 byte recoverable secp256k1 ECDSA signatures (r || s || v)
+/// as produced by Ethereum's personal_sign on the message hash, i.e. on the keccak256 digest of
+/// "\x19Ethereum Signed Message:\n32" || message hash.
+pub const SIG_SCHEME_ETH_PERSONAL_SIGN: u8 = 3;
+
+/// ETH_PERSONAL_SIGN_PREFIX is prepended to the 32 byte message hash by Ethereum's personal_sign.
+pub const ETH_PERSONAL_SIGN_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
 pub fn verify_signature(msg_hash: &[u8; 32], sig: &[u8], key: &[u8]) -> Result<(), Error> {
     let signature = Signature::from_der(sig)?;
     let e = EncodedPoint::<Secp256k1>::from_bytes(key).expect("unable to decode public key");
@@ -9,3 +24,63 @@ pub fn verify_signature(msg_hash: &[u8; 32], sig: &[u8], key: &[u8]) -> Result<(
     verifying_key.verify_prehash(msg_hash, &signature)?;
     Ok(())
 }
+
+/// verify_signature_with_scheme verifies the signature `sig` on `msg_hash` by the SEC1 encoded public
+/// key `key` according to the signature scheme `scheme`.
+pub fn verify_signature_with_scheme(scheme: u8, msg_hash: &[u8; 32], sig: &[u8], key: &[u8]) -> Result<(), Error> {
+    match scheme {
+        SIG_SCHEME_ECDSA_DER => verify_signature(msg_hash, sig, key),
+        SIG_SCHEME_SCHNORR => verify_schnorr_signature(msg_hash, sig, key),
+        SIG_SCHEME_ECDSA_RECOVERABLE => {
+            let signature = to_recoverable_signature(sig)?;
+            let recovered = signature.recover_verifying_key_from_digest_bytes(FieldBytes::from_slice(msg_hash))?;
+            verify_recovered_key(&recovered, key)
+        }
+        SIG_SCHEME_ETH_PERSONAL_SIGN => {
+            let signature = to_recoverable_signature(sig)?;
+            let mut msg = [0u8; ETH_PERSONAL_SIGN_PREFIX.len() + 32];
+            msg[..ETH_PERSONAL_SIGN_PREFIX.len()].copy_from_slice(ETH_PERSONAL_SIGN_PREFIX);
+            msg[ETH_PERSONAL_SIGN_PREFIX.len()..].copy_from_slice(msg_hash);
+            // recover_verifying_key hashes the message with keccak256.
+            let recovered = signature.recover_verifying_key(&msg)?;
+            verify_recovered_key(&recovered, key)
+        }
+        _ => Err(Error::UnknownSignatureScheme),
+    }
+}
+
+/// verify_schnorr_signature verifies a BIP340 Schnorr signature. BIP340 uses x-only public keys, so
+/// only the x coordinate of the SEC1 encoded public key is taken into account.
+fn verify_schnorr_signature(msg_hash: &[u8; 32], sig: &[u8], key: &[u8]) -> Result<(), Error> {
+    let e = EncodedPoint::<Secp256k1>::from_bytes(key).expect("unable to decode public key");
+    let x = e.x().ok_or(Error::SignatureVerificationError)?;
+    let verifying_key = schnorr::VerifyingKey::from_bytes(x)?;
+    let signature = schnorr::Signature::try_from(sig)?;
+    verifying_key.verify_prehashed(msg_hash, &signature)?;
+    Ok(())
+}
+
+/// to_recoverable_signature parses a 65 byte recoverable signature. Recovery ids of 27 and 28, as
+/// used by Ethereum, are normalized to 0 and 1.
+fn to_recoverable_signature(sig: &[u8]) -> Result<recoverable::Signature, Error> {
+    if sig.len() != recoverable::SIZE {
+        return Err(Error::SignatureVerificationError);
+    }
+    let mut bytes = [0u8; recoverable::SIZE];
+    bytes.copy_from_slice(sig);
+    if bytes[64] >= 27 {
+        bytes[64] -= 27;
+    }
+    Ok(recoverable::Signature::try_from(&bytes[..])?)
+}
+
+/// verify_recovered_key verifies that the public key recovered from a signature is the SEC1 encoded
+/// public key `key`. The recovered key always verifies the signature it was recovered from.
+fn verify_recovered_key(recovered: &VerifyingKey, key: &[u8]) -> Result<(), Error> {
+    let e = EncodedPoint::<Secp256k1>::from_bytes(key).expect("unable to decode public key");
+    let verifying_key = VerifyingKey::from_encoded_point(&e)?;
+    if verifying_key != *recovered {
+        return Err(Error::SignatureVerificationError);
+    }
+    Ok(())
+}
@@ -0,0 +1,189 @@
+// Import from `core` instead of from `std` since we are in no-std mode
+use core::result::Result;
+// Import heap related library from `alloc`
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::{vec, vec::Vec};
+
+// Import CKB syscalls and structures
+// https://docs.rs/ckb-std/
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, prelude::*},
+    cstr_core::CStr,
+    debug,
+    high_level::load_cell_data,
+};
+use perun_common::{
+    error::Error,
+    perun_types::{
+        Balances, CKByteDistribution, ChannelState, ChannelStatus, SUDTAllocation, SUDTDistribution,
+    },
+};
+
+// The sample-tic-tac-toe script is an app validator for two-party Perun app channels. It is not used
+// as lock or type script of any cell, but executed by the pcts (see exec_app_validator) whenever a disputed
+// channel with this app is progressed unilaterally. The pcts passes the index of the acting party as the
+// only argument. The old and new channel status are loaded from the GroupInput and GroupOutput of the pcts.
+//
+// The app state (ChannelState.data) consists of 10 bytes:
+// - the index of the party whose turn it is,
+// - the 9 fields of the grid in row-major order. A field is 0 if it is empty and 1 + the index of the party
+//   that marked it otherwise.
+//
+// Each move marks exactly one empty field and passes the turn to the other party. If a move wins the game,
+// the new state has to be final and the winner receives all balances. If a move fills the grid without a
+// winner, the new state has to be final with unchanged balances. Otherwise, the balances must not change.
+
+const NUM_PARTIES: usize = 2;
+const GRID_SIZE: usize = 9;
+const DATA_LEN: usize = 1 + GRID_SIZE;
+const EMPTY: u8 = 0;
+const LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+#[derive(PartialEq)]
+enum Outcome {
+    Ongoing,
+    Draw,
+    Winner(usize),
+}
+
+struct Game {
+    next_actor: usize,
+    grid: [u8; GRID_SIZE],
+}
+
+impl Game {
+    fn parse(state: &ChannelState) -> Result<Game, Error> {
+        let data: Bytes = state.data().unpack();
+        if data.len() != DATA_LEN {
+            return Err(Error::InvalidAppData);
+        }
+        let next_actor = data[0] as usize;
+        if next_actor >= NUM_PARTIES {
+            return Err(Error::InvalidAppData);
+        }
+        let mut grid = [EMPTY; GRID_SIZE];
+        grid.copy_from_slice(&data[1..]);
+        if grid.iter().any(|field| *field as usize > NUM_PARTIES) {
+            return Err(Error::InvalidAppData);
+        }
+        Ok(Game { next_actor, grid })
+    }
+
+    fn outcome(&self) -> Outcome {
+        for line in LINES.iter() {
+            let field = self.grid[line[0]];
+            if field != EMPTY && line.iter().all(|i| self.grid[*i] == field) {
+                return Outcome::Winner(field as usize - 1);
+            }
+        }
+        if self.grid.iter().all(|field| *field != EMPTY) {
+            return Outcome::Draw;
+        }
+        Outcome::Ongoing
+    }
+}
+
+pub fn main(argv: &[&CStr]) -> Result<(), Error> {
+    let actor = parse_actor(argv)?;
+    debug!("tic-tac-toe: move by party {}", actor);
+
+    let old_status = ChannelStatus::from_slice(&load_cell_data(0, Source::GroupInput)?)?;
+    let new_status = ChannelStatus::from_slice(&load_cell_data(0, Source::GroupOutput)?)?;
+    let old_state = old_status.state();
+    let new_state = new_status.state();
+    if old_state.balances().ckbytes().len() != NUM_PARTIES {
+        return Err(Error::PartyCountMismatch);
+    }
+
+    let old_game = Game::parse(&old_state)?;
+    let new_game = Game::parse(&new_state)?;
+
+    // No moves are possible once the game is over.
+    if old_game.outcome() != Outcome::Ongoing {
+        return Err(Error::InvalidAppTransition);
+    }
+    verify_valid_move(&old_game, &new_game, actor)?;
+    debug!("verify_valid_move passed");
+
+    let (expected_balances, expect_final) = match new_game.outcome() {
+        Outcome::Winner(winner) => (winner_takes_all(&old_state.balances(), winner)?, true),
+        Outcome::Draw => (old_state.balances(), true),
+        Outcome::Ongoing => (old_state.balances(), false),
+    };
+    if new_state.is_final().to_bool() != expect_final {
+        return Err(Error::InvalidAppTransition);
+    }
+    if expected_balances.as_slice()[..] != new_state.balances().as_slice()[..] {
+        return Err(Error::InvalidAppTransition);
+    }
+    Ok(())
+}
+
+fn parse_actor(argv: &[&CStr]) -> Result<usize, Error> {
+    let arg = match argv {
+        [arg] => arg.to_str().map_err(|_| Error::InvalidAppData)?,
+        _ => return Err(Error::InvalidAppData),
+    };
+    let actor: usize = arg.parse().map_err(|_| Error::InvalidAppData)?;
+    if actor >= NUM_PARTIES {
+        return Err(Error::InvalidAppData);
+    }
+    Ok(actor)
+}
+
+/// verify_valid_move verifies that it is the actor's turn, that the actor marks exactly one empty field
+/// and that the turn passes to the other party.
+fn verify_valid_move(old_game: &Game, new_game: &Game, actor: usize) -> Result<(), Error> {
+    if old_game.next_actor != actor || new_game.next_actor != (actor + 1) % NUM_PARTIES {
+        return Err(Error::InvalidAppTransition);
+    }
+    let mut marked = 0;
+    for (old_field, new_field) in old_game.grid.iter().zip(new_game.grid.iter()) {
+        if old_field == new_field {
+            continue;
+        }
+        if *old_field != EMPTY || *new_field as usize != actor + 1 {
+            return Err(Error::InvalidAppTransition);
+        }
+        marked += 1;
+    }
+    if marked != 1 {
+        return Err(Error::InvalidAppTransition);
+    }
+    Ok(())
+}
+
+/// winner_takes_all returns the balances in which the winner holds the sum of all balances.
+fn winner_takes_all(balances: &Balances, winner: usize) -> Result<Balances, Error> {
+    let mut ckbytes = vec![0u64; NUM_PARTIES];
+    ckbytes[winner] = balances.ckbytes().sum();
+    let mut sudts: Vec<_> = Vec::new();
+    for sudt in balances.sudts().into_iter() {
+        if sudt.distribution().len() != NUM_PARTIES {
+            return Err(Error::PartyCountMismatch);
+        }
+        let mut distribution = vec![0u128; NUM_PARTIES];
+        distribution[winner] = sudt.distribution().sum();
+        sudts.push(
+            sudt.as_builder()
+                .distribution(SUDTDistribution::from_vec(distribution))
+                .build(),
+        );
+    }
+    Ok(balances
+        .clone()
+        .as_builder()
+        .ckbytes(CKByteDistribution::from_vec(ckbytes))
+        .sudts(SUDTAllocation::new_builder().set(sudts).build())
+        .build())
+}
@@ -0,0 +1,39 @@
+//! Generated by capsule
+//!
+//! `main.rs` is used to define rust lang items and modules.
+//! See `entry.rs` for the `main` function.
+//! See `error.rs` for the `Error` type.
+
+#![no_std]
+#![no_main]
+#![feature(asm_sym)]
+#![feature(lang_items)]
+#![feature(alloc_error_handler)]
+#![feature(panic_info_message)]
+
+// define modules
+mod entry;
+
+use alloc::vec::Vec;
+use ckb_std::{
+    cstr_core::{c_char, CStr},
+    default_alloc,
+};
+use core::arch::asm;
+
+ckb_std::entry!(program_entry);
+default_alloc!();
+
+/// program entry
+///
+/// The pcts passes the arguments for the app validator in `argv`.
+fn program_entry(argc: u64, argv: *const *const u8) -> i8 {
+    let argv: Vec<&CStr> = (0..argc as usize)
+        .map(|i| unsafe { CStr::from_ptr(*argv.add(i) as *const c_char) })
+        .collect();
+    // Call main function and return error code
+    match entry::main(&argv) {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
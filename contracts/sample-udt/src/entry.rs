@@ -68,10 +68,10 @@ pub fn collect_inputs_amount() -> Result<u128, Error> {
             Err(err) => return Err(err.into()),
         };
 
-        if data.len() != UDT_LEN {
+        if data.len() < UDT_LEN {
             return Err(Error::Encoding);
         }
-        buf.copy_from_slice(&data);
+        buf.copy_from_slice(&data[..UDT_LEN]);
         inputs_amount += u128::from_le_bytes(buf);
     }
     Ok(inputs_amount)
@@ -91,10 +91,10 @@ fn collect_outputs_amount() -> Result<u128, Error> {
             Err(err) => return Err(err.into()),
         };
 
-        if data.len() != UDT_LEN {
+        if data.len() < UDT_LEN {
             return Err(Error::Encoding);
         }
-        buf.copy_from_slice(&data);
+        buf.copy_from_slice(&data[..UDT_LEN]);
         outputs_amount += u128::from_le_bytes(buf);
     }
     Ok(outputs_amount)
@@ -1,30 +1,44 @@
 use k256::{ecdsa::SigningKey, PublicKey};
+use perun_common::sig::SIG_SCHEME_ECDSA_DER;
 use rand_core::OsRng;
 use std::fmt::Debug;
 
 pub trait Account: Debug + Clone {
     fn public_key(&self) -> PublicKey;
     fn name(&self) -> String;
+    fn sig_scheme(&self) -> u8;
 }
 
 #[derive(Clone, Debug)]
 pub struct TestAccount {
     pub sk: SigningKey,
     pub name: String,
+    /// The scheme used to sign channel states, see `perun_common::sig`.
+    pub sig_scheme: u8,
 }
 
 impl TestAccount {
     pub fn new(sk: SigningKey, name: String) -> Self {
-        Self { sk, name }
+        Self {
+            sk,
+            name,
+            sig_scheme: SIG_SCHEME_ECDSA_DER,
+        }
     }
 
     pub fn new_with_random_key(name: String) -> Self {
         Self {
             sk: SigningKey::random(&mut OsRng),
             name,
+            sig_scheme: SIG_SCHEME_ECDSA_DER,
         }
     }
 
+    /// with_sig_scheme returns the account signing channel states with the given scheme.
+    pub fn with_sig_scheme(self, sig_scheme: u8) -> Self {
+        Self { sig_scheme, ..self }
+    }
+
     pub fn id(&self) -> &str {
         &self.name
     }
@@ -38,4 +52,8 @@ impl Account for TestAccount {
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn sig_scheme(&self) -> u8 {
+        self.sig_scheme
+    }
 }
@@ -1,5 +1,7 @@
 use ckb_testtool::{
     ckb_types::{
+        bytes::Bytes,
+        core::EpochNumberWithFraction,
         packed::{Header, OutPoint, RawHeader, Script},
         prelude::{Builder, Entity, Pack, Unpack},
     },
@@ -7,12 +9,20 @@ use ckb_testtool::{
 };
 use k256::ecdsa::VerifyingKey;
 use perun_common::{
-    ctrue,
-    perun_types::{ChannelConstants, ChannelStatus, ChannelState},
+    cfalse, ctrue,
+    helpers::{
+        blake2b256, CHALLENGE_DURATION_METRIC_BLOCK_NUMBER, CHALLENGE_DURATION_METRIC_EPOCH,
+        CHALLENGE_DURATION_METRIC_TIMESTAMP, DUST_POLICY_FEE,
+    },
+    perun_types::{
+        App, BondHolder, BoolVec, CKByteDistribution, CapacityRefund, ChannelConstants, ChannelState, ChannelStates,
+        ChannelStatus, ChannelToken, DisputeDeadline, Participants, Predecessor, SEC1EncodedPubKey,
+        SignedState, Successor,
+    },
 };
 
 use crate::perun::{
-    self,
+    self, random,
     test::{keys, Client},
 };
 use crate::perun::{harness, test};
@@ -45,6 +55,10 @@ where
     channel_state: ChannelStatus,
     /// The cells locking funds for this channel.
     funding_cells: Vec<FundingCell>,
+    /// The signed sub-channel states which are registered with the next dispute.
+    sub_channels: Vec<SignedState>,
+    /// The preimages which are revealed to settle HTLCs with the next close or force close.
+    preimages: Vec<Vec<u8>>,
     /// The used Perun Channel Type Script.
     pcts: Script,
     /// All available parties.
@@ -54,8 +68,30 @@ where
     /// The intial test harness environment supplying all Perun specific
     /// contracts and functionality for deployment etc.
     env: &'a harness::Env,
-    /// The current channel time.
+    /// The current channel time, measured in the challenge duration metric of the channel.
     current_time: u64,
+    /// The channel time at which the current channel cell was created.
+    channel_cell_time: u64,
+    /// The metric in which the challenge duration of the channel is measured.
+    challenge_duration_metric: u8,
+    /// The funding deadline of the channel, if any.
+    funding_deadline: Option<u64>,
+    /// The fee budget of the channel, which may be payed from the channel funds.
+    max_fee: u64,
+    /// The fee which the following disputes and force closes pay from the channel funds.
+    fee: u64,
+    /// The dispute bond of the channel, if any.
+    dispute_bond: Option<u64>,
+    /// The dust policy of the channel.
+    dust_policy: u8,
+    /// The maximum number of sUDT assets of the channel.
+    max_sudt_assets: u8,
+    /// The party or sponsor that is refunded the capacity of the channel cell.
+    capacity_refund: CapacityRefund,
+    /// The CKBytes which the next close takes from each sUDT payout of the other parties.
+    skim: u64,
+    /// The party which the next dispute records as holder of the dispute bond instead of the active party.
+    bond_holder: Option<u8>,
     /// The validity of the next action.
     validity: ActionValidity,
     /// The history of actions performed on this channel.
@@ -101,7 +137,8 @@ where
             .map(|(i, p)| {
                 (
                     p.name().clone(),
-                    perun::test::Client::new(i as u8, p.name(), p.sk.clone()),
+                    perun::test::Client::new(i as u8, p.name(), p.sk.clone())
+                        .with_sig_scheme(p.sig_scheme),
                 )
             })
             .collect();
@@ -110,12 +147,25 @@ where
         Channel {
             id: test::ChannelId::new(),
             current_time: 0,
+            channel_cell_time: 0,
+            challenge_duration_metric: CHALLENGE_DURATION_METRIC_TIMESTAMP,
+            funding_deadline: None,
+            max_fee: 0,
+            fee: 0,
+            dispute_bond: None,
+            dust_policy: DUST_POLICY_FEE,
+            max_sudt_assets: harness::MAX_SUDT_ASSETS,
+            capacity_refund: CapacityRefund::default(),
+            skim: 0,
+            bond_holder: None,
             ctx: context,
             env,
             pcts: Script::default(),
             channel_cell: None,
             channel_state: ChannelStatus::default(),
             funding_cells: Vec::new(),
+            sub_channels: Vec::new(),
+            preimages: Vec::new(),
             active_part: active.clone(),
             parts: m_parts.clone(),
             validity: ActionValidity::Valid,
@@ -130,17 +180,183 @@ where
         self
     }
 
+    /// with_delegate_of sets the currently active participant to the delegate of the given `part`.
+    pub fn with_delegate_of(&mut self, part: &str) -> &mut Self {
+        self.active_part = self.parts.get(part).expect("part not found").delegate();
+        self
+    }
+
+    /// with_signature_of sets the currently active participant to the given `part`, which
+    /// authorizes the following actions by a signature on the transaction instead of an input cell.
+    pub fn with_signature_of(&mut self, part: &str) -> &mut Self {
+        self.active_part = self.parts.get(part).expect("part not found").signing_txs();
+        self
+    }
+
     /// delay the environment by the given `duration`, this makes the next
     /// transaction receive a block_header with a timestamp that is `duration`
-    /// in the future.
+    /// in the future. The duration is measured in the challenge duration metric
+    /// of the channel.
     pub fn delay(&mut self, duration: u64) {
         self.current_time += duration;
     }
 
+    /// with_challenge_duration_metric sets the metric in which the challenge
+    /// duration of the channel is measured. It has to be set before opening
+    /// the channel.
+    pub fn with_challenge_duration_metric(&mut self, metric: u8) -> &mut Self {
+        self.challenge_duration_metric = metric;
+        self
+    }
+
+    /// with_preimage reveals the given `preimage` with the next close or
+    /// force close, which settles the HTLCs it unlocks to their receivers.
+    pub fn with_preimage(&mut self, preimage: &[u8]) -> &mut Self {
+        self.preimages.push(preimage.to_vec());
+        self
+    }
+
+    /// htlc_settlement returns the preimages and the since with which a
+    /// closing transaction settles the HTLCs of the channel. The payment input
+    /// is consumed with an absolute since of the current time, which times
+    /// out all HTLCs whose timeout has passed.
+    fn htlc_settlement(&self) -> test::transaction::HTLCSettlement {
+        test::transaction::HTLCSettlement {
+            preimages: self.preimages.clone(),
+            since: absolute_since(self.challenge_duration_metric, self.current_time),
+            time: self.current_time,
+        }
+    }
+
+    /// with_funding_deadline sets the funding deadline of the channel, measured
+    /// in its challenge duration metric. It has to be set before opening the
+    /// channel.
+    pub fn with_funding_deadline(&mut self, deadline: u64) -> &mut Self {
+        self.funding_deadline = Some(deadline);
+        self
+    }
+
+    /// with_max_fee sets the fee budget of the channel in shannons, which
+    /// disputes and force closes may pay from the channel funds. It has to be
+    /// set before opening the channel.
+    pub fn with_max_fee(&mut self, max_fee: u64) -> &mut Self {
+        self.max_fee = max_fee;
+        self
+    }
+
+    /// with_fee sets the fee in shannons, which the following disputes and
+    /// force closes pay from the channel funds.
+    pub fn with_fee(&mut self, fee: u64) -> &mut Self {
+        self.fee = fee;
+        self
+    }
+
+    /// with_skimmed_sudt_payouts makes the next close take `amount` CKBytes
+    /// from each sUDT payout of the other parties, which are payed to the
+    /// active participant instead.
+    pub fn with_skimmed_sudt_payouts(&mut self, amount: u64) -> &mut Self {
+        self.skim = amount;
+        self
+    }
+
+    /// with_bond_holder makes the next dispute record the party at `index` as
+    /// holder of the dispute bond instead of the active party.
+    pub fn with_bond_holder(&mut self, index: u8) -> &mut Self {
+        self.bond_holder = Some(index);
+        self
+    }
+
+    /// with_dispute_bond sets the dispute bond of the channel in shannons,
+    /// which the first dispute locks in the channel cell. It has to be set
+    /// before opening the channel.
+    pub fn with_dispute_bond(&mut self, bond: u64) -> &mut Self {
+        self.dispute_bond = Some(bond);
+        self
+    }
+
+    /// with_dust_policy sets the dust policy of the channel, which decides who
+    /// receives payouts below the payment_min_capacity of their party. It has
+    /// to be set before opening the channel.
+    pub fn with_dust_policy(&mut self, dust_policy: u8) -> &mut Self {
+        self.dust_policy = dust_policy;
+        self
+    }
+
+    /// with_max_sudt_assets sets the maximum number of sUDT assets of the
+    /// channel. It has to be set before opening the channel.
+    pub fn with_max_sudt_assets(&mut self, max_sudt_assets: u8) -> &mut Self {
+        self.max_sudt_assets = max_sudt_assets;
+        self
+    }
+
+    /// with_capacity_refund sets the party or sponsor that is refunded the
+    /// capacity of the channel cell. It has to be set before opening the
+    /// channel.
+    pub fn with_capacity_refund(&mut self, refund: CapacityRefund) -> &mut Self {
+        self.capacity_refund = refund;
+        self
+    }
+
     /// open a channel using the currently active participant set by `with(..)`
     /// with the value given in `funding_agreement`.
     pub fn open(&mut self, funding_agreement: &test::FundingAgreement) -> Result<(), perun::Error> {
-        let (id, or) = call_action!(self, open, funding_agreement)?;
+        self.open_with_app(funding_agreement, Default::default(), Bytes::new())
+    }
+
+    /// open_with_app opens an app channel using the currently active participant set by `with(..)`
+    /// with the value given in `funding_agreement`. The channel starts with `app_data` as app state.
+    pub fn open_with_app(
+        &mut self,
+        funding_agreement: &test::FundingAgreement,
+        app: App,
+        app_data: Bytes,
+    ) -> Result<(), perun::Error> {
+        let (id, or) = call_action!(
+            self,
+            open,
+            funding_agreement,
+            app.clone(),
+            app_data.clone(),
+            self.challenge_duration_metric,
+            self.funding_deadline,
+            self.max_fee,
+            self.dispute_bond,
+            self.dust_policy,
+            self.max_sudt_assets,
+            self.capacity_refund.clone(),
+        )?;
+        self.opened(id, or);
+        Ok(())
+    }
+
+    /// open_jointly opens a channel using the currently active participant set
+    /// by `with(..)`, which all parties fund jointly in the opening transaction
+    /// with the values given in `funding_agreement`. The channel starts fully
+    /// funded.
+    pub fn open_jointly(
+        &mut self,
+        funding_agreement: &test::FundingAgreement,
+    ) -> Result<(), perun::Error> {
+        let (id, or) = call_action!(
+            self,
+            open_jointly,
+            funding_agreement,
+            Default::default(),
+            Bytes::new(),
+            self.challenge_duration_metric,
+            self.funding_deadline,
+            self.max_fee,
+            self.dispute_bond,
+            self.dust_policy,
+            self.max_sudt_assets,
+            self.capacity_refund.clone(),
+        )?;
+        self.opened(id, or);
+        Ok(())
+    }
+
+    /// opened tracks the channel opened by the given transaction.
+    fn opened(&mut self, id: test::ChannelId, or: test::transaction::OpenResult) {
         self.id = id;
         self.channel_cell = Some(or.channel_cell.clone());
         // Make sure the channel cell is linked to a header with a timestamp.
@@ -150,14 +366,17 @@ where
         self.funding_cells = fs.to_vec();
         self.pcts = or.pcts;
         self.channel_state = or.state;
-        Ok(())
     }
 
     fn push_header_with_cell(&mut self, cell: OutPoint) {
+        // The channel time is used for all challenge duration metrics.
+        let epoch = EpochNumberWithFraction::new(self.current_time, 0, 1);
         let header = Header::new_builder()
             .raw(
                 RawHeader::new_builder()
                     .timestamp(self.current_time.pack())
+                    .number(self.current_time.pack())
+                    .epoch(epoch.full_value().pack())
                     .build(),
             )
             .build()
@@ -165,6 +384,7 @@ where
         self.ctx.insert_header(header.clone());
         // We will always use 0 as the `tx_index`.
         self.ctx.link_cell_with_block(cell, header.hash(), 0);
+        self.channel_cell_time = self.current_time;
     }
 
     /// fund a channel using the currently active participant set by `with(..)`
@@ -205,8 +425,43 @@ where
     /// dispute a channel using the currently active participant set by
     /// `with(..)`.
     pub fn dispute(&mut self) -> Result<(), perun::Error> {
-        self.channel_state = self.channel_state.clone().as_builder().disputed(ctrue!()).build();
         let sigs = self.sigs_for_channel_state()?;
+        self.dispute_with_sigs(sigs)
+    }
+
+    /// progress progresses a disputed app channel unilaterally using the currently active
+    /// participant set by `with(..)`. Only the active participant signs the channel state.
+    pub fn progress(&mut self) -> Result<(), perun::Error> {
+        let active = self.active_part.pubkey();
+        let sigs = self.sigs_for_channel_state_by(|c| c.pubkey() == active)?;
+        self.dispute_with_sigs(sigs)
+    }
+
+    /// with_sub_channel registers the given signed sub-channel state with the next dispute.
+    pub fn with_sub_channel(&mut self, sub_channel: SignedState) -> &mut Self {
+        self.sub_channels.push(sub_channel);
+        self
+    }
+
+    fn dispute_with_sigs(&mut self, sigs: Vec<Vec<u8>>) -> Result<(), perun::Error> {
+        let sub_channels = std::mem::take(&mut self.sub_channels);
+        let sub_states = self.sub_states_with(&sub_channels);
+        let dispute_deadline = self.dispute_deadline();
+        let bond_holder = self.bond_holder();
+        // Only the first dispute locks the dispute bond.
+        let bond = match self.channel_state.disputed().to_bool() {
+            true => 0,
+            false => self.dispute_bond.unwrap_or_default(),
+        };
+        self.channel_state = self
+            .channel_state
+            .clone()
+            .as_builder()
+            .disputed(ctrue!())
+            .sub_states(sub_states)
+            .dispute_deadline(dispute_deadline)
+            .bond_holder(bond_holder)
+            .build();
         let res = match &self.channel_cell {
             Some(channel_cell) => {
                 call_action!(
@@ -217,18 +472,209 @@ where
                     self.channel_state.clone(),
                     self.pcts.clone(),
                     sigs,
+                    sub_channels.clone(),
+                    self.fee,
+                    bond,
+                )
+            }
+            None => panic!("no channel cell, invalid test setup"),
+        }?;
+        self.channel_state = res.state;
+        self.channel_cell = Some(res.channel_cell.clone());
+        self.push_header_with_cell(res.channel_cell);
+        Ok(())
+    }
+
+    /// bond_holder returns the holder of the dispute bond after the next
+    /// dispute, which is the party that registers the new state. This is the
+    /// active party, or the party it is a delegate of.
+    fn bond_holder(&mut self) -> BondHolder {
+        let overridden = self.bond_holder.take();
+        if self.dispute_bond.is_none() {
+            return BondHolder::default();
+        }
+        let holder =
+            overridden.unwrap_or(self.active_part.index() % harness::DELEGATE_INDEX_OFFSET);
+        BondHolder::new_builder().set(Some(holder.into())).build()
+    }
+
+    /// dispute_deadline returns the dispute deadline of the next dispute. It is recorded by the
+    /// first refutation as the end of the challenge duration starting with the first dispute.
+    fn dispute_deadline(&self) -> DisputeDeadline {
+        if !self.channel_state.disputed().to_bool() {
+            return DisputeDeadline::default();
+        }
+        if self.channel_state.dispute_deadline().is_some() {
+            return self.channel_state.dispute_deadline();
+        }
+        let end = self.channel_cell_time + self.env.challenge_duration;
+        let deadline = match self.challenge_duration_metric {
+            CHALLENGE_DURATION_METRIC_EPOCH => EpochNumberWithFraction::new(end, 0, 1).full_value(),
+            _ => end,
+        };
+        DisputeDeadline::new_builder()
+            .set(Some(deadline.pack()))
+            .build()
+    }
+
+    /// sub_states_with returns the sub-channel states for the sub-allocations in the channel state.
+    /// The state of a sub-channel is taken from `sub_channels`, if present, and from the already
+    /// registered sub-channel states otherwise.
+    fn sub_states_with(&self, sub_channels: &[SignedState]) -> ChannelStates {
+        let registered = self.channel_state.sub_states();
+        let sub_states: Vec<ChannelState> = self
+            .channel_state
+            .state()
+            .balances()
+            .locked()
+            .into_iter()
+            .filter_map(|sub_alloc| {
+                let has_id = |s: &ChannelState| s.channel_id().as_slice() == sub_alloc.id().as_slice();
+                sub_channels
+                    .iter()
+                    .map(|signed| signed.state())
+                    .find(has_id)
+                    .or_else(|| registered.clone().into_iter().find(has_id))
+            })
+            .collect();
+        ChannelStates::new_builder().set(sub_states).build()
+    }
+
+    /// checkpoint records the current channel state in the channel cell using the currently active
+    /// participant set by `with(..)`. The state is signed by all participants and the channel is
+    /// not disputed.
+    pub fn checkpoint(&mut self) -> Result<(), perun::Error> {
+        let sigs = self.sigs_for_channel_state()?;
+        let res = match &self.channel_cell {
+            Some(channel_cell) => {
+                call_action!(
+                    self,
+                    checkpoint,
+                    self.id,
+                    channel_cell.clone(),
+                    self.channel_state.clone(),
+                    self.pcts.clone(),
+                    sigs,
+                )
+            }
+            None => panic!("no channel cell, invalid test setup"),
+        }?;
+        self.channel_cell = Some(res.channel_cell.clone());
+        self.push_header_with_cell(res.channel_cell);
+        Ok(())
+    }
+
+    /// deposit locks `amount` additional CKBytes for the currently active participant set by
+    /// `with(..)` to the channel. The deposit is credited to the participant in a new state, which is
+    /// signed by all participants.
+    pub fn deposit(&mut self, amount: u64) -> Result<(), perun::Error> {
+        let idx = self.active_part.index() as usize;
+        let state = self.channel_state.state();
+        let mut ckbytes = state.balances().ckbytes().to_vec();
+        ckbytes[idx] += amount;
+        let balances = state
+            .balances()
+            .as_builder()
+            .ckbytes(CKByteDistribution::from_vec(ckbytes))
+            .build();
+        let old_version: u64 = state.version().unpack();
+        let state = state
+            .as_builder()
+            .balances(balances)
+            .version((old_version + 1).pack())
+            .build();
+        self.channel_state = self.channel_state.clone().as_builder().state(state).build();
+        let sigs = self.sigs_for_channel_state()?;
+        let res = match &self.channel_cell {
+            Some(channel_cell) => {
+                call_action!(
+                    self,
+                    deposit,
+                    self.id,
+                    channel_cell.clone(),
+                    self.channel_state.clone(),
+                    self.pcts.clone(),
+                    sigs,
+                    amount,
                 )
             }
             None => panic!("no channel cell, invalid test setup"),
         }?;
         self.channel_cell = Some(res.channel_cell.clone());
         self.push_header_with_cell(res.channel_cell);
+        self.funding_cells.extend(res.funds_cells.iter().cloned());
+        Ok(())
+    }
+
+    /// withdraw pays out `amount` CKBytes of the balance of the currently active participant set by
+    /// `with(..)` without closing the channel. The withdrawal is deducted from the participant in a
+    /// new state, which is signed by all participants.
+    pub fn withdraw(&mut self, amount: u64) -> Result<(), perun::Error> {
+        // The withdrawal is determined relative to the latest off-chain state.
+        let prev_sigs = self.sigs_for_channel_state()?;
+        let prev_state = self.channel_state.state();
+        let idx = self.active_part.index() as usize;
+        let state = self.channel_state.state();
+        let mut ckbytes = state.balances().ckbytes().to_vec();
+        ckbytes[idx] = ckbytes[idx]
+            .checked_sub(amount)
+            .ok_or(perun::Error::new("insufficient funds"))?;
+        let balances = state
+            .balances()
+            .as_builder()
+            .ckbytes(CKByteDistribution::from_vec(ckbytes))
+            .build();
+        let old_version: u64 = state.version().unpack();
+        let state = state
+            .as_builder()
+            .balances(balances)
+            .version((old_version + 1).pack())
+            .build();
+        self.channel_state = self.channel_state.clone().as_builder().state(state).build();
+        let sigs = self.sigs_for_channel_state()?;
+        // The withdrawal consumes all CKBytes funding cells and locks the remainder in a new one.
+        let mut ckbytes_cells = Vec::new();
+        let mut remaining_cells = Vec::new();
+        for f in self.funding_cells.iter().cloned() {
+            match f {
+                FundingCell::FundingCellCKBytes(f) => ckbytes_cells.push(f),
+                f => remaining_cells.push(f),
+            }
+        }
+        let res = match &self.channel_cell {
+            Some(channel_cell) => {
+                call_action!(
+                    self,
+                    withdraw,
+                    self.id,
+                    channel_cell.clone(),
+                    ckbytes_cells,
+                    prev_state,
+                    prev_sigs,
+                    self.channel_state.clone(),
+                    self.pcts.clone(),
+                    sigs,
+                    amount,
+                )
+            }
+            None => panic!("no channel cell, invalid test setup"),
+        }?;
+        self.channel_cell = Some(res.channel_cell.clone());
+        self.push_header_with_cell(res.channel_cell);
+        remaining_cells.push(res.funds_cell);
+        self.funding_cells = remaining_cells;
         Ok(())
     }
 
     /// abort a channel using the currently active participant set by
     /// `with(..)`.
     pub fn abort(&mut self) -> Result<(), perun::Error> {
+        // A channel with a funding deadline is aborted with an absolute since
+        // of the current time.
+        let since = match self.funding_deadline {
+            Some(_) => absolute_since(self.challenge_duration_metric, self.current_time),
+            None => 0,
+        };
         match &self.channel_cell {
             Some(channel_cell) => {
                 call_action!(
@@ -237,7 +683,8 @@ where
                     self.id,
                     self.channel_state.clone(),
                     channel_cell.clone(),
-                    self.funding_cells.clone()
+                    self.funding_cells.clone(),
+                    since
                 )
             }
             None => panic!("no channel cell, invalid test setup"),
@@ -277,18 +724,224 @@ where
                 channel_cell,
                 self.funding_cells.clone(),
                 self.channel_state.clone(),
-                sigs
+                sigs,
+                self.htlc_settlement(),
+                std::mem::take(&mut self.skim),
             ),
             None => panic!("no channel cell, invalid test setup"),
         }?;
         Ok(())
     }
 
-    fn sigs_for_channel_state(&self) -> Result<[Vec<u8>; 2], perun::Error> {
+    /// splice closes the channel using the currently active participant set by `with(..)` and
+    /// starts its successor in the same transaction. The successor has the same parameters apart
+    /// from a fresh nonce and is funded by the final balances of the channel. The active
+    /// participant adds `amount` CKBytes to its balance in the successor.
+    pub fn splice(
+        &mut self,
+        funding_agreement: &test::FundingAgreement,
+        amount: u64,
+    ) -> Result<(), perun::Error> {
+        let channel_cell = match self.channel_cell.clone() {
+            Some(channel_cell) => channel_cell,
+            None => panic!("no channel cell, invalid test setup"),
+        };
+        let constants = ChannelConstants::from_slice(&self.pcts.args().raw_data())?;
+        let params = constants
+            .params()
+            .as_builder()
+            .nonce(random::nonce().pack())
+            .build();
+        let successor_id = test::ChannelId::from(blake2b256(params.as_slice()));
+        // The spliced channel cell serves as thread token of the successor.
+        let successor_constants = constants
+            .as_builder()
+            .params(params)
+            .thread_token(ChannelToken::new_builder().out_point(channel_cell.clone()).build())
+            .build();
+        let successor_pcts = self.env.build_pcts(self.ctx, successor_constants.as_bytes());
+
+        self.finalize();
+        let final_state = self
+            .channel_state
+            .state()
+            .as_builder()
+            .successor(
+                Successor::new_builder()
+                    .set(Some(successor_pcts.calc_script_hash()))
+                    .build(),
+            )
+            .build();
+        self.channel_state = self
+            .channel_state
+            .clone()
+            .as_builder()
+            .state(final_state.clone())
+            .build();
+        let sigs = self.sigs_for_channel_state()?;
+
+        let idx = self.active_part.index() as usize;
+        let mut ckbytes = final_state.balances().ckbytes().to_vec();
+        ckbytes[idx] += amount;
+        let balances = final_state
+            .balances()
+            .as_builder()
+            .ckbytes(CKByteDistribution::from_vec(ckbytes.clone()))
+            .build();
+        let successor_state = ChannelState::new_builder()
+            .channel_id(successor_id.to_byte32())
+            .balances(balances)
+            .version(0u64.pack())
+            .is_final(cfalse!())
+            .data(final_state.data())
+            .build();
+        let successor_status = ChannelStatus::new_builder()
+            .state(successor_state)
+            .funded(ctrue!())
+            .disputed(cfalse!())
+            .funded_parties(BoolVec::from_vec(vec![true; ckbytes.len()]))
+            .build();
+
+        let res = call_action!(
+            self,
+            splice,
+            self.id,
+            channel_cell,
+            self.funding_cells.clone(),
+            self.channel_state.clone(),
+            sigs,
+            successor_status.clone(),
+            successor_pcts.clone(),
+            funding_agreement,
+            amount,
+        )?;
+        self.id = successor_id;
+        self.pcts = successor_pcts;
+        self.channel_state = successor_status;
+        self.channel_cell = Some(res.channel_cell.clone());
+        self.push_header_with_cell(res.channel_cell);
+        self.funding_cells = res.funds_cells;
+        Ok(())
+    }
+
+    /// rotate replaces the key of the given `part` by the key of `account` using the currently
+    /// active participant set by `with(..)`. All participants sign the rotation. The channel
+    /// continues as a successor channel with the rotated participant and the same status.
+    pub fn rotate(&mut self, part: &str, account: &perun::TestAccount) -> Result<(), perun::Error> {
+        let signers: Vec<_> = self.parts.keys().cloned().collect();
+        self.rotate_signed_by(part, account, &signers)
+    }
+
+    /// rotate_signed_by rotates the key of the given `part` like `rotate`, but only the given
+    /// `signers` sign the rotation.
+    pub fn rotate_signed_by(
+        &mut self,
+        part: &str,
+        account: &perun::TestAccount,
+        signers: &[String],
+    ) -> Result<(), perun::Error> {
+        let channel_cell = match self.channel_cell.clone() {
+            Some(channel_cell) => channel_cell,
+            None => panic!("no channel cell, invalid test setup"),
+        };
+        let rotated = self.parts.get(part).expect("part not found");
+        let client = Client::new(rotated.index(), part.to_string(), account.sk.clone())
+            .with_sig_scheme(account.sig_scheme);
+
+        let constants = ChannelConstants::from_slice(&self.pcts.args().raw_data())?;
+        let mut parties: Vec<_> = constants.params().parties().into_iter().collect();
+        let idx = client.index() as usize;
+        parties[idx] = parties[idx]
+            .clone()
+            .as_builder()
+            .pub_key(SEC1EncodedPubKey::from_slice(&client.pubkey())?)
+            .sig_scheme(account.sig_scheme.into())
+            .build();
+        let params = constants
+            .params()
+            .as_builder()
+            .parties(Participants::new_builder().set(parties).build())
+            .build();
+        let successor_id = test::ChannelId::from(blake2b256(params.as_slice()));
+        // The rotated channel cell serves as thread token of the successor, which is linked to
+        // the rotated channel by its id.
+        let successor_constants = constants
+            .as_builder()
+            .params(params)
+            .thread_token(ChannelToken::new_builder().out_point(channel_cell.clone()).build())
+            .predecessor(
+                Predecessor::new_builder()
+                    .set(Some(self.id.to_byte32()))
+                    .build(),
+            )
+            .build();
+        let successor_pcts = self.env.build_pcts(self.ctx, successor_constants.as_bytes());
+        let successor_state = self
+            .channel_state
+            .state()
+            .as_builder()
+            .channel_id(successor_id.to_byte32())
+            .build();
+        let successor_status = self
+            .channel_state
+            .clone()
+            .as_builder()
+            .state(successor_state.clone())
+            .build();
+        let sigs = self.sigs_by(&self.pcts, &successor_pcts, successor_state, |c| {
+            signers.contains(&c.name())
+        })?;
+
+        let res = call_action!(
+            self,
+            rotate,
+            self.id,
+            channel_cell,
+            self.funding_cells.clone(),
+            successor_status.clone(),
+            sigs,
+            successor_pcts.clone(),
+        )?;
+        if self.active_part.index() == client.index() {
+            self.active_part = client.clone();
+        }
+        self.parts.insert(part.to_string(), client);
+        self.id = successor_id;
+        self.pcts = successor_pcts;
+        self.channel_state = successor_status;
+        self.channel_cell = Some(res.channel_cell.clone());
+        self.push_header_with_cell(res.channel_cell);
+        self.funding_cells = res.funds_cells;
+        Ok(())
+    }
+
+    fn sigs_for_channel_state(&self) -> Result<Vec<Vec<u8>>, perun::Error> {
+        self.sigs_for_channel_state_by(|_| true)
+    }
+
+    /// sigs_for_channel_state_by returns a signature on the channel state for every participant
+    /// for which `signs` returns true and an empty signature for all others.
+    fn sigs_for_channel_state_by(
+        &self,
+        signs: impl Fn(&Client) -> bool,
+    ) -> Result<Vec<Vec<u8>>, perun::Error> {
+        self.sigs_by(&self.pcts, &self.pcts, self.channel_state.state(), signs)
+    }
+
+    /// sigs_by returns a signature on `state` bound to `domain` for every participant of the
+    /// channel with the given `pcts` for which `signs` returns true and an empty signature for all
+    /// others.
+    fn sigs_by(
+        &self,
+        pcts: &Script,
+        domain: &Script,
+        state: ChannelState,
+        signs: impl Fn(&Client) -> bool,
+    ) -> Result<Vec<Vec<u8>>, perun::Error> {
         // We have to unpack the ChannelConstants like this. Otherwise the molecule header is still
         // part of the slice. On-chain we have no problem due to unpacking the arguments, but this
         // does not seem possible in this scope.
-        let bytes = self.pcts.args().raw_data();
+        let bytes = pcts.args().raw_data();
         // We want to have the correct order of clients in an array to construct signatures. For
         // consistency we use the ChannelConstants which are also used to construct the channel and
         // look up the participants according to their public key identifier.
@@ -309,28 +962,35 @@ where
             .cloned()
             .map(resolve_client)
             .collect();
+        let pcts_hash = domain.calc_script_hash();
         let sigs: Result<Vec<_>, _> = clients?
             .iter()
-            .map(|p| p.sign(self.channel_state.state()))
+            .map(|p| match signs(p) {
+                true => p.sign(&pcts_hash, state.clone()),
+                false => Ok(Vec::new()),
+            })
             .collect();
-        let sig_arr: [Vec<u8>; 2] = sigs?.try_into()?;
-        Ok(sig_arr)
+        sigs
     }
 
     /// force_close a channel using the currently active participant set by
     /// `with(..)`.
     pub fn force_close(&mut self) -> Result<(), perun::Error> {
-        let h = Header::new_builder()
-            .raw(
-                RawHeader::new_builder()
-                    .timestamp(self.current_time.pack())
-                    .build(),
+        // A disputed final state can be settled at once. Once the dispute
+        // deadline is recorded, the channel cell is consumed with an absolute
+        // since of the current time. Otherwise, it is consumed with a relative
+        // since, which has matured by the time passed since the channel cell was
+        // created.
+        let since = if self.channel_state.state().is_final().to_bool() {
+            0
+        } else if self.channel_state.dispute_deadline().is_some() {
+            absolute_since(self.challenge_duration_metric, self.current_time)
+        } else {
+            relative_since(
+                self.challenge_duration_metric,
+                self.current_time - self.channel_cell_time,
             )
-            .build()
-            .into_view();
-        // Push a header with the current time which can be used in force_close
-        // as for time validation purposes.
-        self.ctx.insert_header(h.clone());
+        };
         match self.channel_cell.clone() {
             Some(channel_cell) => call_action!(
                 self,
@@ -339,6 +999,10 @@ where
                 channel_cell,
                 self.funding_cells.clone(),
                 self.channel_state.clone(),
+                since,
+                self.htlc_settlement(),
+                self.fee,
+                self.dispute_bond.unwrap_or_default(),
             ),
             None => panic!("no channel cell, invalid test setup"),
         }?;
@@ -372,3 +1036,26 @@ where
         assert_eq!(expected_state, self.current_state)
     }
 }
+
+/// relative_since returns the relative since of an input, which matures `elapsed` units of the
+/// given challenge duration metric after the input was committed.
+fn relative_since(metric: u8, elapsed: u64) -> u64 {
+    const RELATIVE: u64 = 1 << 63;
+    RELATIVE | absolute_since(metric, elapsed)
+}
+
+/// absolute_since returns the absolute since of an input, which matures at `time` in the given
+/// challenge duration metric.
+fn absolute_since(metric: u8, time: u64) -> u64 {
+    const METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+    const METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+    match metric {
+        // Timestamps in since values are measured in seconds.
+        CHALLENGE_DURATION_METRIC_TIMESTAMP => METRIC_TIMESTAMP | (time / 1000),
+        CHALLENGE_DURATION_METRIC_BLOCK_NUMBER => time,
+        CHALLENGE_DURATION_METRIC_EPOCH => {
+            METRIC_EPOCH | EpochNumberWithFraction::new(time, 0, 1).full_value()
+        }
+        _ => panic!("unknown challenge duration metric"),
+    }
+}
@@ -58,6 +58,12 @@ impl From<VerificationError> for Error {
     }
 }
 
+impl From<perun_common::error::Error> for Error {
+    fn from(err: perun_common::error::Error) -> Error {
+        Error::new(&format!("{:?}", err))
+    }
+}
+
 impl From<Vec<Vec<u8>>> for Error {
     fn from(vs: Vec<Vec<u8>>) -> Error {
         Error::new(&format!("converting from nested vectors: {:?}", vs))
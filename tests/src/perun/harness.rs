@@ -3,10 +3,12 @@ use crate::Loader;
 use ckb_occupied_capacity::{Capacity, IntoCapacity};
 use ckb_testtool::{
     builtin::ALWAYS_SUCCESS,
-    ckb_types::{bytes::Bytes, packed::*, prelude::*},
+    ckb_traits::CellDataProvider,
+    ckb_types::{bytes::Bytes, core::ScriptHashType, packed::*, prelude::*},
     context::Context,
 };
 use perun_common::cfalse;
+use perun_common::perun_types::{Bool, BoolVec};
 use perun_common::perun_types::ChannelStateBuilder;
 use perun_common::perun_types::ChannelStatusBuilder;
 use perun_common::perun_types::{self, ChannelStatus, ChannelToken};
@@ -25,6 +27,7 @@ pub struct Env {
     // Auxiliary contracts.
     pub always_success_out_point: OutPoint,
     pub sample_udt_out_point: OutPoint,
+    pub sample_tic_tac_toe_out_point: OutPoint,
 
     // Perun scripts.
     pcls_script: Script,
@@ -38,6 +41,7 @@ pub struct Env {
     pub always_success_script_dep: CellDep,
     pub sample_udt_script: Script,
     pub sample_udt_script_dep: CellDep,
+    pub sample_tic_tac_toe_script_dep: CellDep,
     // Maximum amount of cycles used when verifying TXs.
     pub max_cycles: u64,
     pub min_capacity_no_script: Capacity,
@@ -59,11 +63,13 @@ impl Env {
         let pcts: Bytes = Loader::default().load_binary("perun-channel-typescript");
         let pfls: Bytes = Loader::default().load_binary("perun-funds-lockscript");
         let sample_udt: Bytes = Loader::default().load_binary("sample-udt");
+        let sample_tic_tac_toe: Bytes = Loader::default().load_binary("sample-tic-tac-toe");
         // Deploying the contracts returns the cell they are deployed in.
         let pcls_out_point = context.deploy_cell(pcls);
         let pcts_out_point = context.deploy_cell(pcts);
         let pfls_out_point = context.deploy_cell(pfls);
         let sample_udt_out_point = context.deploy_cell(sample_udt);
+        let sample_tic_tac_toe_out_point = context.deploy_cell(sample_tic_tac_toe);
         // Auxiliary contracts.
         let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
 
@@ -96,6 +102,9 @@ impl Env {
         let sample_udt_script_dep = CellDep::new_builder()
             .out_point(sample_udt_out_point.clone())
             .build();
+        let sample_tic_tac_toe_script_dep = CellDep::new_builder()
+            .out_point(sample_tic_tac_toe_out_point.clone())
+            .build();
         let sample_udt_max_cap = sample_udt_script.occupied_capacity()?.safe_mul(Capacity::shannons(10))?;
         // Auxiliary scripts.
         let always_success_script = context
@@ -130,6 +139,7 @@ impl Env {
             pfls_out_point,
             always_success_out_point,
             sample_udt_out_point,
+            sample_tic_tac_toe_out_point,
             pcls_script,
             pcts_script,
             pfls_script,
@@ -140,6 +150,7 @@ impl Env {
             always_success_script_dep,
             sample_udt_script,
             sample_udt_script_dep,
+            sample_tic_tac_toe_script_dep,
             max_cycles,
             min_capacity_no_script,
             min_capacity_pfls,
@@ -176,17 +187,71 @@ impl Env {
             .expect("always_success")
     }
 
-    pub fn min_capacity_for_channel(&self, cs: ChannelStatus) -> Result<Capacity, perun::Error> {
+    /// sponsor_refund returns the CapacityRefund, which refunds the capacity of the channel cell to
+    /// the sponsor identified by `SPONSOR_INDEX`.
+    pub fn sponsor_refund(&self, context: &mut Context) -> perun_types::CapacityRefund {
+        let sponsor = self.build_lock_script(context, Bytes::from(vec![SPONSOR_INDEX]));
+        perun_types::CapacityRefund::new_builder()
+            .set(
+                perun_types::RefundSponsor::from_slice(sponsor.calc_script_hash().as_slice())
+                    .expect("sponsor lock hash"),
+            )
+            .build()
+    }
+
+    /// sample_tic_tac_toe_app returns the App referencing the sample-tic-tac-toe app validator.
+    pub fn sample_tic_tac_toe_app(&self, context: &Context) -> perun_types::App {
+        let code_hash = context
+            .get_cell_data_hash(&self.sample_tic_tac_toe_out_point)
+            .expect("sample-tic-tac-toe hash");
+        let app_script = perun_types::AppScript::new_builder()
+            .code_hash(code_hash)
+            .hash_type(ScriptHashType::Data1.into())
+            .build();
+        perun_types::App::new_builder().set(Some(app_script)).build()
+    }
+
+    /// min_capacity_for_channel returns the occupied capacity of a channel cell with the type script
+    /// `pcts`, which holds the channel constants in its args, and the channel status `cs`.
+    pub fn min_capacity_for_channel(
+        &self,
+        pcts: &Script,
+        cs: ChannelStatus,
+    ) -> Result<Capacity, perun::Error> {
         let tmp_output = CellOutput::new_builder()
             .capacity(0u64.pack())
             .lock(self.pcls_script.clone())
-            .type_(Some(self.pcts_script.clone()).pack())
+            .type_(Some(pcts.clone()).pack())
             .build();
         let cs_capacity = Capacity::bytes(cs.as_bytes().len())?;
         let min_capacity = tmp_output.occupied_capacity(cs_capacity)?;
         Ok(min_capacity)
     }
 
+    /// start_capacity_for_channel returns the capacity of a started channel cell with the type script
+    /// `pcts` and the channel status `cs`, which reserves the fee budget of the channel constants.
+    pub fn start_capacity_for_channel(
+        &self,
+        pcts: &Script,
+        cs: ChannelStatus,
+    ) -> Result<Capacity, perun::Error> {
+        let constants = perun_types::ChannelConstants::from_slice(&pcts.args().raw_data())?;
+        let max_fee: u64 = constants.max_fee().unpack();
+        Ok(self
+            .min_capacity_for_channel(pcts, cs)?
+            .safe_add(Capacity::shannons(max_fee))?)
+    }
+
+    /// min_sudt_max_capacity returns the least max_capacity of the sample UDT which is accepted upon
+    /// start. It covers an sUDT cell payed to a party with the payment_min_capacity of the parties.
+    pub fn min_sudt_max_capacity(&self) -> Result<Capacity, perun::Error> {
+        Ok(self
+            .sample_udt_script
+            .occupied_capacity()?
+            .safe_add(Capacity::bytes(16)?)?
+            .safe_add(self.min_capacity_no_script)?)
+    }
+
     pub fn create_channel_token(&self, context: &mut Context) -> (ChannelToken, OutPoint) {
         let channel_token_outpoint = context.create_cell(
             CellOutput::new_builder()
@@ -260,8 +325,9 @@ impl Env {
     pub fn build_initial_channel_state(
         &self,
         channel_id: ChannelId,
-        client_index: u8,
+        funders: &[u8],
         funding_agreement: &FundingAgreement,
+        app_data: Bytes,
     ) -> Result<ChannelStatus, perun::Error> {
         let all_indices = funding_agreement
             .content()
@@ -274,12 +340,59 @@ impl Env {
             .balances(channel_balances)
             .version(Default::default())
             .is_final(cfalse!())
+            .data(app_data.pack())
             .build();
+        // The funders fund the channel with the opening transaction. All other parties are only
+        // considered funded, if they have nothing to fund.
+        let funded_parties = BoolVec::from_vec(
+            funding_agreement
+                .content()
+                .iter()
+                .map(|entry| {
+                    funders.contains(&entry.index)
+                        || (entry.ckbytes == 0 && !funding_agreement.has_udts())
+                })
+                .collect(),
+        );
         let channel_status = ChannelStatusBuilder::default()
             .state(channel_state)
-            .funded(cfalse!())
+            .funded(Bool::from_bool(funded_parties.all()))
             .disputed(cfalse!())
+            .funded_parties(funded_parties)
             .build();
         Ok(channel_status)
     }
 }
+
+/// DELEGATE_INDEX_OFFSET is added to the index of a party to get the index identifying its delegate.
+pub const DELEGATE_INDEX_OFFSET: u8 = 0x80;
+
+/// delegate_index returns the index identifying the delegate of the party at `party_index`. Cells
+/// created for this index are locked by the unlock script of the delegate.
+pub fn delegate_index(party_index: u8) -> u8 {
+    DELEGATE_INDEX_OFFSET + party_index
+}
+
+/// FEE_PAYER_INDEX identifies an outside party, which pays the fees of transactions authorized by
+/// a signature of a participant.
+pub const FEE_PAYER_INDEX: u8 = 0xff;
+
+/// SPONSOR_INDEX identifies an outside sponsor, which provides the capacity of a channel cell and is
+/// refunded it upon close.
+pub const SPONSOR_INDEX: u8 = 0xfe;
+
+/// party_refund returns the CapacityRefund, which refunds the capacity of the channel cell to the
+/// party at `party_index`.
+pub fn party_refund(party_index: u8) -> perun_types::CapacityRefund {
+    perun_types::CapacityRefund::new_builder()
+        .set(
+            perun_types::RefundParty::new_builder()
+                .nth0(party_index.into())
+                .build(),
+        )
+        .build()
+}
+
+/// MAX_SUDT_ASSETS is the maximum number of sUDT assets of the channels opened in the tests, unless
+/// set otherwise.
+pub const MAX_SUDT_ASSETS: u8 = 4;
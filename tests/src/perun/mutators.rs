@@ -1,11 +1,16 @@
 use crate::perun;
-use ckb_testtool::ckb_types::prelude::{Unpack, Pack};
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::Byte32, prelude::{Unpack, Pack}};
 use molecule::prelude::{Entity, Builder};
-use perun_common::perun_types::{ChannelState, CKByteDistribution, SUDTDistribution};
+use perun_common::ctrue;
+use perun_common::perun_types::{
+    ChannelState, CKByteDistribution, HTLCs, IndexMap, SUDTAmounts, SUDTDistribution, SubAlloc, HTLC,
+};
 
 pub enum Direction {
     AtoB,
     BtoA,
+    /// FromTo transfers from the party at the first index to the party at the second index.
+    FromTo(usize, usize),
 }
 
 /// id returns a mutator that does not change the channel state.
@@ -24,13 +29,16 @@ pub fn pay_ckbytes(direction: Direction, amount: u64) -> impl Fn(&ChannelState)
     let (sender_index, receiver_index) = get_indices(direction);
     move |s| {
         let s_bumped = bump_version()(s)?;
-        let mut distribution = s_bumped.balances().ckbytes().to_array();
+        let mut distribution = s_bumped.balances().ckbytes().to_vec();
+        if sender_index.max(receiver_index) >= distribution.len() {
+            return Err(perun::Error::new("party index out of bounds"));
+        }
         if distribution[sender_index] < amount {
             return Err(perun::Error::new("insufficient funds"));
         }
         distribution[sender_index] -= amount;
         distribution[receiver_index] += amount;
-        let balances = s_bumped.balances().clone().as_builder().ckbytes(CKByteDistribution::from_array(distribution)).build();
+        let balances = s_bumped.balances().clone().as_builder().ckbytes(CKByteDistribution::from_vec(distribution)).build();
         Ok(s_bumped.clone().as_builder().balances(balances).build())
     }
 }
@@ -46,13 +54,16 @@ pub fn pay_sudt(direction:Direction, amount: u128, asset_index: usize)-> impl Fn
             return Err(perun::Error::new("asset index out of bounds"));
         }
         let sudt = sudts.get(asset_index).unwrap();
-        let mut distribution = sudt.distribution().to_array();
+        let mut distribution = sudt.distribution().to_vec();
+        if sender_index.max(receiver_index) >= distribution.len() {
+            return Err(perun::Error::new("party index out of bounds"));
+        }
         if distribution[sender_index] < amount {
             return Err(perun::Error::new("insufficient funds"));
         }
         distribution[sender_index] -= amount;
         distribution[receiver_index] += amount;
-        let packed_sudt = sudt.clone().as_builder().distribution(SUDTDistribution::from_array(distribution)).build();
+        let packed_sudt = sudt.clone().as_builder().distribution(SUDTDistribution::from_vec(distribution)).build();
         let mut new_sudts = sudts.clone().as_builder();
         new_sudts.replace(asset_index, packed_sudt).unwrap();
         let balances = s_bumped.balances().clone().as_builder().sudts(new_sudts.build()).build();
@@ -60,10 +71,152 @@ pub fn pay_sudt(direction:Direction, amount: u128, asset_index: usize)-> impl Fn
     }
 }
 
+/// lock_sub_alloc returns a mutator that locks CKBytes for the sub-channel with the given id. Each party contributes
+/// the amount at its index in `contributions`. The `index_map` maps the sub-channel participants to the parties of
+/// the channel. It also bumps the version number of the channel state.
+pub fn lock_sub_alloc(id: Byte32, contributions: Vec<u64>, index_map: Vec<u8>) -> impl Fn(&ChannelState) -> Result<ChannelState, perun::Error> {
+    move |s| {
+        let s_bumped = bump_version()(s)?;
+        let mut distribution = s_bumped.balances().ckbytes().to_vec();
+        if contributions.len() != distribution.len() {
+            return Err(perun::Error::new("invalid number of contributions"));
+        }
+        for (balance, contribution) in distribution.iter_mut().zip(contributions.iter()) {
+            if *balance < *contribution {
+                return Err(perun::Error::new("insufficient funds"));
+            }
+            *balance -= contribution;
+        }
+        let num_sudts = s_bumped.balances().sudts().len();
+        let sub_alloc = SubAlloc::new_builder()
+            .id(id.clone())
+            .ckbytes(contributions.iter().sum::<u64>().pack())
+            .sudts(SUDTAmounts::new_builder().set(vec![0u128.pack(); num_sudts]).build())
+            .index_map(IndexMap::new_builder().set(index_map.iter().map(|i| (*i).into()).collect()).build())
+            .build();
+        let locked = s_bumped.balances().locked().as_builder().push(sub_alloc).build();
+        let balances = s_bumped.balances().clone().as_builder()
+            .ckbytes(CKByteDistribution::from_vec(distribution))
+            .locked(locked)
+            .build();
+        Ok(s_bumped.clone().as_builder().balances(balances).build())
+    }
+}
+
+/// add_htlc returns a mutator that locks the given amount of CKBytes of the sender in an HTLC to the receiver
+/// according to the specified direction. The HTLC is unlocked by the preimage of `hash_lock` and times out at
+/// `timeout`, measured in the challenge duration metric of the channel. It also bumps the version number of the
+/// channel state.
+pub fn add_htlc(direction: Direction, amount: u64, hash_lock: [u8; 32], timeout: u64) -> impl Fn(&ChannelState) -> Result<ChannelState, perun::Error> {
+    let (sender_index, receiver_index) = get_indices(direction);
+    move |s| {
+        let s_bumped = bump_version()(s)?;
+        let mut distribution = s_bumped.balances().ckbytes().to_vec();
+        if sender_index.max(receiver_index) >= distribution.len() {
+            return Err(perun::Error::new("party index out of bounds"));
+        }
+        if distribution[sender_index] < amount {
+            return Err(perun::Error::new("insufficient funds"));
+        }
+        distribution[sender_index] -= amount;
+        let num_sudts = s_bumped.balances().sudts().len();
+        let htlc = HTLC::new_builder()
+            .sender((sender_index as u8).into())
+            .receiver((receiver_index as u8).into())
+            .hash_lock(hash_lock.pack())
+            .timeout(timeout.pack())
+            .ckbytes(amount.pack())
+            .sudts(SUDTAmounts::new_builder().set(vec![0u128.pack(); num_sudts]).build())
+            .build();
+        let htlcs = s_bumped.balances().htlcs().as_builder().push(htlc).build();
+        let balances = s_bumped.balances().clone().as_builder()
+            .ckbytes(CKByteDistribution::from_vec(distribution))
+            .htlcs(htlcs)
+            .build();
+        Ok(s_bumped.clone().as_builder().balances(balances).build())
+    }
+}
+
+/// settle_htlc returns a mutator that cooperatively settles the HTLC at the given index to its receiver, e.g. after
+/// the preimage was revealed off-chain. It also bumps the version number of the channel state.
+pub fn settle_htlc(index: usize) -> impl Fn(&ChannelState) -> Result<ChannelState, perun::Error> {
+    move |s| resolve_htlc(s, index, true)
+}
+
+/// fail_htlc returns a mutator that cooperatively pays the HTLC at the given index back to its sender. It also bumps
+/// the version number of the channel state.
+pub fn fail_htlc(index: usize) -> impl Fn(&ChannelState) -> Result<ChannelState, perun::Error> {
+    move |s| resolve_htlc(s, index, false)
+}
+
+/// resolve_htlc removes the HTLC at the given index and credits its funds to the receiver, if `to_receiver` is set,
+/// and to the sender otherwise. It also bumps the version number of the channel state.
+fn resolve_htlc(s: &ChannelState, index: usize, to_receiver: bool) -> Result<ChannelState, perun::Error> {
+    let s_bumped = bump_version()(s)?;
+    let mut htlcs: Vec<HTLC> = s_bumped.balances().htlcs().into_iter().collect();
+    if index >= htlcs.len() {
+        return Err(perun::Error::new("htlc index out of bounds"));
+    }
+    let htlc = htlcs.remove(index);
+    let party = if to_receiver { htlc.receiver() } else { htlc.sender() };
+    let party = u8::from(party) as usize;
+    let mut distribution = s_bumped.balances().ckbytes().to_vec();
+    if party >= distribution.len() {
+        return Err(perun::Error::new("party index out of bounds"));
+    }
+    distribution[party] += Unpack::<u64>::unpack(&htlc.ckbytes());
+    let mut sudts = s_bumped.balances().sudts().as_builder();
+    for (i, sudt) in s_bumped.balances().sudts().into_iter().enumerate() {
+        let mut sudt_distribution = sudt.distribution().to_vec();
+        sudt_distribution[party] += Unpack::<u128>::unpack(&htlc.sudts().get(i).unwrap());
+        let sudt = sudt.as_builder().distribution(SUDTDistribution::from_vec(sudt_distribution)).build();
+        sudts.replace(i, sudt).unwrap();
+    }
+    let balances = s_bumped.balances().clone().as_builder()
+        .ckbytes(CKByteDistribution::from_vec(distribution))
+        .sudts(sudts.build())
+        .htlcs(HTLCs::new_builder().set(htlcs).build())
+        .build();
+    Ok(s_bumped.clone().as_builder().balances(balances).build())
+}
+
 /// get_indices returns (sender_index, receiver_index)
 fn get_indices(direction: Direction) -> (usize, usize) {
     match direction {
         Direction::AtoB => (0, 1),
         Direction::BtoA => (1, 0),
+        Direction::FromTo(sender, receiver) => (sender, receiver),
     }
-}
\ No newline at end of file
+}
+
+/// tic_tac_toe_move returns a mutator that marks the given field of the sample-tic-tac-toe grid for the given actor
+/// and passes the turn to the other party. If the move wins the game, the state is finalized and the winner receives
+/// all balances. It also bumps the version number of the channel state.
+pub fn tic_tac_toe_move(actor: u8, field: usize) -> impl Fn(&ChannelState) -> Result<ChannelState, perun::Error> {
+    move |s| {
+        let s_bumped = bump_version()(s)?;
+        let mut data: Vec<u8> = s_bumped.data().raw_data().to_vec();
+        if data.len() != 10 || field >= 9 {
+            return Err(perun::Error::new("invalid tic-tac-toe state"));
+        }
+        data[0] = (actor + 1) % 2;
+        data[1 + field] = actor + 1;
+        let grid = &data[1..];
+        let lines = [[0, 1, 2], [3, 4, 5], [6, 7, 8], [0, 3, 6], [1, 4, 7], [2, 5, 8], [0, 4, 8], [2, 4, 6]];
+        let won = lines.iter().any(|l| l.iter().all(|i| grid[*i] == actor + 1));
+        let s_moved = s_bumped.clone().as_builder().data(data.pack()).build();
+        if !won {
+            return Ok(s_moved);
+        }
+        let balances = s_moved.balances();
+        let mut ckbytes = vec![0u64; balances.ckbytes().len()];
+        ckbytes[actor as usize] = balances.ckbytes().sum();
+        let balances = balances.clone().as_builder().ckbytes(CKByteDistribution::from_vec(ckbytes)).build();
+        Ok(s_moved.as_builder().balances(balances).is_final(ctrue!()).build())
+    }
+}
+
+/// tic_tac_toe_start returns the app state of a new sample-tic-tac-toe game, in which the first party moves first.
+pub fn tic_tac_toe_start() -> Bytes {
+    Bytes::from(vec![0u8; 10])
+}
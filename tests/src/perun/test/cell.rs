@@ -71,4 +71,20 @@ impl FundingCell {
             FundingCell::FundingCellSUDT(f) => f.out_point.clone(),
         }
     }
+
+    /// with_outpoint returns the funding cell with the same funds at the given outpoint.
+    pub fn with_outpoint(&self, out_point: OutPoint) -> FundingCell {
+        match self {
+            FundingCell::FundingCellCKBytes(f) => {
+                FundingCell::FundingCellCKBytes(FundingCellCKBytes {
+                    out_point,
+                    ..f.clone()
+                })
+            }
+            FundingCell::FundingCellSUDT(f) => FundingCell::FundingCellSUDT(FundingCellSUDT {
+                out_point,
+                ..f.clone()
+            }),
+        }
+    }
 }
\ No newline at end of file
@@ -1,15 +1,24 @@
 use ckb_testtool::ckb_traits::CellDataProvider;
 
+use ckb_testtool::ckb_types::bytes::Bytes;
 use ckb_testtool::ckb_types::core::ScriptHashType;
-use ckb_testtool::ckb_types::packed::{OutPoint, Script};
+use ckb_testtool::ckb_types::core::TransactionView;
+use ckb_testtool::ckb_types::packed::{Byte32, BytesOpt, CellInput, OutPoint, Script, WitnessArgs};
 use ckb_testtool::ckb_types::prelude::*;
 use ckb_testtool::context::Context;
 
+use k256::ecdsa::recoverable;
 use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::signature::Signer;
+use k256::schnorr;
+use perun_common::sig::{
+    ETH_PERSONAL_SIGN_PREFIX, SIG_SCHEME_ECDSA_DER, SIG_SCHEME_ECDSA_RECOVERABLE,
+    SIG_SCHEME_ETH_PERSONAL_SIGN, SIG_SCHEME_SCHNORR,
+};
 use perun_common::*;
 
-use perun_common::helpers::blake2b256;
-use perun_common::perun_types::{ChannelState, ChannelStatus};
+use perun_common::helpers::{blake2b256, state_signing_digest, tx_signing_digest};
+use perun_common::perun_types::{ChannelState, ChannelStatus, SignedState};
 
 use crate::perun;
 use crate::perun::harness;
@@ -20,7 +29,7 @@ use crate::perun::test::{keys, transaction};
 
 use k256::ecdsa::{Signature, SigningKey};
 
-use super::cell::FundingCell;
+use super::cell::{FundingCell, FundingCellCKBytes};
 use super::ChannelId;
 
 #[derive(Clone, Debug)]
@@ -28,6 +37,12 @@ pub struct Client {
     index: u8,
     signing_key: SigningKey,
     name: String,
+    // sign_tx is set, if the client authorizes transactions consuming the channel cell by a
+    // signature on the transaction signing digest instead of an input cell. The fees are then
+    // payed by an outside party.
+    sign_tx: bool,
+    // sig_scheme is the scheme used to sign channel states and transactions.
+    sig_scheme: u8,
 }
 
 impl Client {
@@ -36,9 +51,16 @@ impl Client {
             index: idx,
             name,
             signing_key: sk,
+            sign_tx: false,
+            sig_scheme: SIG_SCHEME_ECDSA_DER,
         }
     }
 
+    // with_sig_scheme returns the client signing with the given signature scheme.
+    pub fn with_sig_scheme(self, sig_scheme: u8) -> Client {
+        Client { sig_scheme, ..self }
+    }
+
     // pubkey returns the public key of the client as a SEC1 encoded byte
     // array.
     pub fn pubkey(&self) -> [u8; 33] {
@@ -49,15 +71,187 @@ impl Client {
         self.name.clone()
     }
 
+    // delegate returns a client acting as the delegate of this client. The delegate does not hold
+    // the signing key of the client, it only uses the delegate's unlock script.
+    pub fn delegate(&self) -> Client {
+        Client {
+            index: harness::delegate_index(self.index),
+            signing_key: SigningKey::random(&mut rand_core::OsRng),
+            name: format!("{}'s delegate", self.name),
+            sign_tx: false,
+            sig_scheme: SIG_SCHEME_ECDSA_DER,
+        }
+    }
+
+    // signing_txs returns a client which authorizes transactions consuming the channel cell by a
+    // signature on the transaction signing digest.
+    pub fn signing_txs(&self) -> Client {
+        Client {
+            sign_tx: true,
+            ..self.clone()
+        }
+    }
+
+    // payer_index returns the index of the party whose cells are used as inputs to interact with
+    // the channel.
+    fn payer_index(&self) -> u8 {
+        match self.sign_tx {
+            true => harness::FEE_PAYER_INDEX,
+            false => self.index,
+        }
+    }
+
+    // authorize signs the signing digest of the given transaction and places the signature in the
+    // lock field of the witness of the channel cell, which is the first input, if the client signs
+    // transactions.
+    fn authorize(
+        &self,
+        ctx: &Context,
+        tx: TransactionView,
+    ) -> Result<TransactionView, perun::Error> {
+        if !self.sign_tx {
+            return Ok(tx);
+        }
+        let tx_hash: [u8; 32] = tx.hash().raw_data()[..].try_into().expect("tx hash");
+        let mut witnesses: Vec<Bytes> = tx.witnesses().into_iter().map(|w| w.raw_data()).collect();
+        // The digest covers the witnesses of all channel cells, i.e. of all inputs locked by the pcls.
+        let lock_hash = |input: &CellInput| {
+            ctx.get_cell(&input.previous_output())
+                .map(|(cell, _)| cell.lock().calc_script_hash())
+        };
+        let pcls_hash = tx.inputs().get(0).and_then(|input| lock_hash(&input));
+        let channel_witnesses = tx
+            .inputs()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, input)| lock_hash(input) == pcls_hash)
+            .map(|(i, _)| {
+                Ok(WitnessArgs::from_slice(&witnesses[i])?
+                    .as_builder()
+                    .lock(BytesOpt::default())
+                    .build())
+            })
+            .collect::<Result<Vec<_>, perun::Error>>()?;
+        let digest = tx_signing_digest(&tx_hash, channel_witnesses.iter().map(|w| w.as_slice()));
+        let sig = Bytes::from(self.sign_hash(&digest)?);
+        let witness_args = WitnessArgs::from_slice(&witnesses[0])?
+            .as_builder()
+            .lock(Some(sig).pack())
+            .build();
+        witnesses[0] = witness_args.as_bytes();
+        Ok(tx
+            .as_advanced_builder()
+            .set_witnesses(witnesses.iter().map(|w| w.pack()).collect())
+            .build())
+    }
+
+    // index returns the index of the client in the channel parameters.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
     pub fn open(
         &self,
         ctx: &mut Context,
         env: &harness::Env,
         funding_agreement: &test::FundingAgreement,
+        app: perun_types::App,
+        app_data: Bytes,
+        challenge_duration_metric: u8,
+        funding_deadline: Option<u64>,
+        max_fee: u64,
+        dispute_bond: Option<u64>,
+        dust_policy: u8,
+        max_sudt_assets: u8,
+        capacity_refund: perun_types::CapacityRefund,
     ) -> Result<(ChannelId, OpenResult), perun::Error> {
-        // Prepare environment so that this party has the required funds.
+        self.open_with_funders(
+            ctx,
+            env,
+            funding_agreement,
+            app,
+            app_data,
+            challenge_duration_metric,
+            funding_deadline,
+            max_fee,
+            dispute_bond,
+            dust_policy,
+            max_sudt_assets,
+            capacity_refund,
+            &[],
+        )
+    }
+
+    // open_jointly opens a channel, which all parties of the funding agreement fund jointly in the
+    // opening transaction. The channel starts fully funded.
+    pub fn open_jointly(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        funding_agreement: &test::FundingAgreement,
+        app: perun_types::App,
+        app_data: Bytes,
+        challenge_duration_metric: u8,
+        funding_deadline: Option<u64>,
+        max_fee: u64,
+        dispute_bond: Option<u64>,
+        dust_policy: u8,
+        max_sudt_assets: u8,
+        capacity_refund: perun_types::CapacityRefund,
+    ) -> Result<(ChannelId, OpenResult), perun::Error> {
+        let joint_funders: Vec<u8> = funding_agreement
+            .content()
+            .iter()
+            .map(|entry| entry.index)
+            .filter(|idx| *idx != self.index)
+            .collect();
+        self.open_with_funders(
+            ctx,
+            env,
+            funding_agreement,
+            app,
+            app_data,
+            challenge_duration_metric,
+            funding_deadline,
+            max_fee,
+            dispute_bond,
+            dust_policy,
+            max_sudt_assets,
+            capacity_refund,
+            &joint_funders,
+        )
+    }
+
+    // open_with_funders opens a channel, which this client and the `joint_funders` fund in the
+    // opening transaction.
+    fn open_with_funders(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        funding_agreement: &test::FundingAgreement,
+        app: perun_types::App,
+        app_data: Bytes,
+        challenge_duration_metric: u8,
+        funding_deadline: Option<u64>,
+        max_fee: u64,
+        dispute_bond: Option<u64>,
+        dust_policy: u8,
+        max_sudt_assets: u8,
+        capacity_refund: perun_types::CapacityRefund,
+        joint_funders: &[u8],
+    ) -> Result<(ChannelId, OpenResult), perun::Error> {
+        // Prepare environment so that this party and the joint funders have the required funds.
         let inputs =
             env.create_funds_from_agreement(ctx, self.index, funding_agreement)?;
+        let joint_funders = joint_funders
+            .iter()
+            .map(|idx| {
+                Ok((
+                    *idx,
+                    env.create_funds_from_agreement(ctx, *idx, funding_agreement)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, perun::Error>>()?;
         // Create the channel token.
         let (channel_token, channel_token_outpoint) = env.create_channel_token(ctx);
 
@@ -73,13 +267,24 @@ impl Client {
         let parties = funding_agreement.mk_participants(ctx, env, env.min_capacity_no_script);
 
         let chan_params = perun_types::ChannelParametersBuilder::default()
-            .party_a(parties[0].clone())
-            .party_b(parties[1].clone())
+            .parties(perun_types::Participants::new_builder().set(parties).build())
             .nonce(random::nonce().pack())
             .challenge_duration(env.challenge_duration.pack())
-            .app(Default::default())
+            .challenge_duration_metric(challenge_duration_metric.into())
+            .app(app)
             .is_ledger_channel(ctrue!())
             .is_virtual_channel(cfalse!())
+            .funding_deadline(
+                perun_types::FundingDeadline::new_builder()
+                    .set(funding_deadline.map(|d| d.pack()))
+                    .build(),
+            )
+            .dispute_bond(
+                perun_types::DisputeBond::new_builder()
+                    .set(dispute_bond.map(|b| b.pack()))
+                    .build(),
+            )
+            .dust_policy(dust_policy.into())
             .build();
         let cid_raw = blake2b256(chan_params.as_slice());
         let cid = ChannelId::from(cid_raw);
@@ -91,6 +296,9 @@ impl Client {
             .pcls_code_hash(pcls_code_hash.clone())
             .pcls_hash_type(ScriptHashType::Data1.into())
             .thread_token(channel_token.clone())
+            .max_fee(max_fee.pack())
+            .max_sudt_assets(max_sudt_assets.into())
+            .capacity_refund(capacity_refund)
             .build();
 
         let pcts = env.build_pcts(ctx, chan_const.as_bytes());
@@ -102,9 +310,12 @@ impl Client {
             channel_token_outpoint: channel_token_outpoint.clone(),
             inputs: inputs,
             party_index: self.index,
+            joint_funders,
             pcls_script: pcls,
             pcts_script: pcts,
             pfls_script: pfls,
+            app_data,
+            max_fee,
         };
         let or = transaction::mk_open(ctx, env, args)?;
 
@@ -147,11 +358,40 @@ impl Client {
         Ok(())
     }
 
-    pub fn sign(&self, state: ChannelState) -> Result<Vec<u8>, perun::Error> {
-        let s: Signature = self
-            .signing_key
-            .sign_prehash(&blake2b256(state.as_slice()))?;
-        Ok(Vec::from(s.to_der().as_bytes()))
+    // sign signs the given state of the channel whose states are bound to `pcts_hash`, see
+    // `state_signing_digest`.
+    pub fn sign(&self, pcts_hash: &Byte32, state: ChannelState) -> Result<Vec<u8>, perun::Error> {
+        let pcts_hash: [u8; 32] = pcts_hash.as_slice().try_into().expect("pcts hash");
+        self.sign_hash(&state_signing_digest(&pcts_hash, &state))
+    }
+
+    // sign_hash signs the given message hash using the signature scheme of the client.
+    fn sign_hash(&self, hash: &[u8; 32]) -> Result<Vec<u8>, perun::Error> {
+        match self.sig_scheme {
+            SIG_SCHEME_ECDSA_DER => {
+                let s: Signature = self.signing_key.sign_prehash(hash)?;
+                Ok(Vec::from(s.to_der().as_bytes()))
+            }
+            SIG_SCHEME_SCHNORR => {
+                let sk = schnorr::SigningKey::from_bytes(&self.signing_key.to_bytes())?;
+                let s = sk.try_sign_prehashed(hash, &random::nonce())?;
+                Ok(Vec::from(s.as_bytes().as_slice()))
+            }
+            SIG_SCHEME_ECDSA_RECOVERABLE => {
+                let s: recoverable::Signature = self.signing_key.sign_prehash(hash)?;
+                Ok(Vec::from(s.as_ref()))
+            }
+            SIG_SCHEME_ETH_PERSONAL_SIGN => {
+                // Signing a message with a recoverable signature hashes it using keccak256.
+                let msg = [ETH_PERSONAL_SIGN_PREFIX, hash.as_slice()].concat();
+                let s: recoverable::Signature = self.signing_key.try_sign(&msg)?;
+                let mut sig = Vec::from(s.as_ref());
+                // Ethereum wallets encode the recovery id as 27 or 28.
+                sig[64] += 27;
+                Ok(sig)
+            }
+            _ => Err(perun::Error::new("unknown signature scheme")),
+        }
     }
 
     pub fn dispute(
@@ -162,7 +402,10 @@ impl Client {
         channel_cell: OutPoint,
         channel_state: ChannelStatus,
         pcts: Script,
-        sigs: [Vec<u8>; 2],
+        sigs: Vec<Vec<u8>>,
+        sub_channels: Vec<SignedState>,
+        fee: u64,
+        bond: u64,
     ) -> Result<transaction::DisputeResult, perun::Error> {
         let dr = transaction::mk_dispute(
             ctx,
@@ -170,16 +413,118 @@ impl Client {
             transaction::DisputeArgs {
                 channel_cell,
                 state: channel_state,
-                party_index: self.index,
+                party_index: self.payer_index(),
                 pcts_script: pcts,
                 sigs,
+                sub_channels,
+                fee,
+                bond,
             },
         )?;
+        let dr = transaction::DisputeResult {
+            tx: self.authorize(ctx, dr.tx)?,
+            ..dr
+        };
         let cycles = ctx.verify_tx(&dr.tx, env.max_cycles)?;
         println!("consumed cycles: {}", cycles);
         Ok(dr)
     }
 
+    pub fn checkpoint(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        _cid: test::ChannelId,
+        channel_cell: OutPoint,
+        channel_state: ChannelStatus,
+        pcts: Script,
+        sigs: Vec<Vec<u8>>,
+    ) -> Result<transaction::CheckpointResult, perun::Error> {
+        let cr = transaction::mk_checkpoint(
+            ctx,
+            env,
+            transaction::CheckpointArgs {
+                channel_cell,
+                state: channel_state,
+                party_index: self.payer_index(),
+                pcts_script: pcts,
+                sigs,
+            },
+        )?;
+        let cr = transaction::CheckpointResult {
+            tx: self.authorize(ctx, cr.tx)?,
+            ..cr
+        };
+        let cycles = ctx.verify_tx(&cr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(cr)
+    }
+
+    pub fn deposit(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        _cid: test::ChannelId,
+        channel_cell: OutPoint,
+        channel_state: ChannelStatus,
+        pcts: Script,
+        sigs: Vec<Vec<u8>>,
+        amount: u64,
+    ) -> Result<transaction::DepositResult, perun::Error> {
+        // Prepare environment so that this party has the funds to deposit.
+        let inputs = env.create_ckbytes_funds_for_index(ctx, self.index, amount)?;
+        let dr = transaction::mk_deposit(
+            ctx,
+            env,
+            transaction::DepositArgs {
+                channel_cell,
+                state: channel_state,
+                sigs,
+                amount,
+                party_index: self.index,
+                inputs,
+                pcts,
+            },
+        )?;
+        let cycles = ctx.verify_tx(&dr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(dr)
+    }
+
+    pub fn withdraw(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        _cid: test::ChannelId,
+        channel_cell: OutPoint,
+        funds_cells: Vec<FundingCellCKBytes>,
+        prev_state: ChannelState,
+        prev_sigs: Vec<Vec<u8>>,
+        channel_state: ChannelStatus,
+        pcts: Script,
+        sigs: Vec<Vec<u8>>,
+        amount: u64,
+    ) -> Result<transaction::WithdrawResult, perun::Error> {
+        let wr = transaction::mk_withdraw(
+            ctx,
+            env,
+            transaction::WithdrawArgs {
+                channel_cell,
+                funds_cells,
+                prev_state,
+                prev_sigs,
+                state: channel_state,
+                sigs,
+                amount,
+                party_index: self.index,
+                pcts,
+            },
+        )?;
+        let cycles = ctx.verify_tx(&wr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(wr)
+    }
+
     pub fn abort(
         &self,
         ctx: &mut Context,
@@ -188,6 +533,7 @@ impl Client {
         state: ChannelStatus,
         channel_cell: OutPoint,
         funds: Vec<FundingCell>,
+        since: u64,
     ) -> Result<transaction::AbortResult, perun::Error> {
         let ar = transaction::mk_abort(
             ctx,
@@ -197,6 +543,7 @@ impl Client {
                 funds,
                 state,
                 party_index: self.index,
+                since,
             },
         )?;
         let cycles = ctx.verify_tx(&ar.tx, env.max_cycles)?;
@@ -212,7 +559,9 @@ impl Client {
         channel_cell: OutPoint,
         funds_cells: Vec<FundingCell>,
         state: ChannelStatus,
-        sigs: [Vec<u8>; 2],
+        sigs: Vec<Vec<u8>>,
+        htlcs: transaction::HTLCSettlement,
+        skim: u64,
     ) -> Result<transaction::CloseResult, perun::Error> {
         let cr = transaction::mk_close(
             ctx,
@@ -220,16 +569,133 @@ impl Client {
             transaction::CloseArgs {
                 channel_cell,
                 funds_cells,
-                party_index: self.index,
+                party_index: self.payer_index(),
                 state,
                 sigs,
+                htlcs,
+                skim,
             },
         )?;
+        let cr = transaction::CloseResult {
+            tx: self.authorize(ctx, cr.tx)?,
+        };
         let cycles = ctx.verify_tx(&cr.tx, env.max_cycles)?;
         println!("consumed cycles: {}", cycles);
         Ok(cr)
     }
 
+    pub fn splice(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        _cid: test::ChannelId,
+        channel_cell: OutPoint,
+        funds_cells: Vec<FundingCell>,
+        state: ChannelStatus,
+        sigs: Vec<Vec<u8>>,
+        successor_state: ChannelStatus,
+        successor_pcts: Script,
+        funding_agreement: &test::FundingAgreement,
+        amount: u64,
+    ) -> Result<transaction::SpliceResult, perun::Error> {
+        // Prepare environment so that this party has the funds to add to the successor.
+        let inputs = match amount {
+            0 => Vec::new(),
+            _ => env.create_ckbytes_funds_for_index(ctx, self.index, amount)?,
+        };
+        let sr = transaction::mk_splice(
+            ctx,
+            env,
+            transaction::SpliceArgs {
+                channel_cell,
+                funds_cells,
+                state,
+                sigs,
+                successor_state,
+                successor_pcts,
+                register: funding_agreement.register().clone(),
+                amount,
+                party_index: self.index,
+                inputs,
+            },
+        )?;
+        let cycles = ctx.verify_tx(&sr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(sr)
+    }
+
+    pub fn rotate(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        _cid: test::ChannelId,
+        channel_cell: OutPoint,
+        funds_cells: Vec<FundingCell>,
+        successor_state: ChannelStatus,
+        sigs: Vec<Vec<u8>>,
+        successor_pcts: Script,
+    ) -> Result<transaction::RotateResult, perun::Error> {
+        let rr = transaction::mk_rotate(
+            ctx,
+            env,
+            transaction::RotateArgs {
+                channel_cell,
+                funds_cells,
+                successor_state,
+                sigs,
+                successor_pcts,
+                party_index: self.payer_index(),
+            },
+        )?;
+        let rr = transaction::RotateResult {
+            tx: self.authorize(ctx, rr.tx)?,
+            ..rr
+        };
+        let cycles = ctx.verify_tx(&rr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(rr)
+    }
+
+    /// batch_close closes all given channels in a single transaction.
+    pub fn batch_close(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        closes: Vec<transaction::CloseArgs>,
+        tagged: bool,
+    ) -> Result<transaction::BatchCloseResult, perun::Error> {
+        let bcr = transaction::mk_batch_close(ctx, env, closes, self.index, tagged)?;
+        let cycles = ctx.verify_tx(&bcr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(bcr)
+    }
+
+    /// batch_fund funds all given channels in a single transaction.
+    pub fn batch_fund(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        funds: Vec<transaction::FundArgs>,
+    ) -> Result<transaction::BatchFundResult, perun::Error> {
+        let bfr = transaction::mk_batch_fund(ctx, env, funds, self.index)?;
+        let cycles = ctx.verify_tx(&bfr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(bfr)
+    }
+
+    /// batch_dispute disputes all given channels in a single transaction.
+    pub fn batch_dispute(
+        &self,
+        ctx: &mut Context,
+        env: &harness::Env,
+        disputes: Vec<transaction::DisputeArgs>,
+    ) -> Result<transaction::BatchDisputeResult, perun::Error> {
+        let bdr = transaction::mk_batch_dispute(ctx, env, disputes, self.index)?;
+        let cycles = ctx.verify_tx(&bdr.tx, env.max_cycles)?;
+        println!("consumed cycles: {}", cycles);
+        Ok(bdr)
+    }
+
     pub fn force_close(
         &self,
         ctx: &mut Context,
@@ -238,20 +704,28 @@ impl Client {
         channel_cell: OutPoint,
         funds_cells: Vec<FundingCell>,
         state: ChannelStatus,
+        since: u64,
+        htlcs: transaction::HTLCSettlement,
+        fee: u64,
+        bond: u64,
     ) -> Result<transaction::ForceCloseResult, perun::Error> {
-        // We will pass all available headers to the force close transaction.
-        let hs = ctx.headers.keys().cloned().collect();
         let fcr = transaction::mk_force_close(
             ctx,
             env,
             transaction::ForceCloseArgs {
-                headers: hs,
+                since,
                 channel_cell,
-                party_index: self.index,
+                party_index: self.payer_index(),
                 funds_cells,
                 state,
+                htlcs,
+                fee,
+                bond,
             },
         )?;
+        let fcr = transaction::ForceCloseResult {
+            tx: self.authorize(ctx, fcr.tx)?,
+        };
         let cycles = ctx.verify_tx(&fcr.tx, env.max_cycles)?;
         println!("consumed cycles: {}", cycles);
         Ok(fcr)
@@ -1,5 +1,5 @@
 use ckb_occupied_capacity::Capacity;
-use ckb_testtool::ckb_types::packed::{Byte as PackedByte, Script};
+use ckb_testtool::ckb_types::packed::{Byte as PackedByte, Byte32Vec, Script};
 use ckb_testtool::ckb_types::prelude::*;
 use ckb_testtool::context::Context;
 use ckb_types::bytes::Bytes;
@@ -37,6 +37,7 @@ impl FundingAgreement {
                     sudts: Vec::new(),
                     index: i as u8,
                     pub_key: acc.public_key(),
+                    sig_scheme: acc.sig_scheme(),
                 })
                 .collect(),
             register: AssetRegister::new(),
@@ -48,23 +49,51 @@ impl FundingAgreement {
         asset: &Script,
         max_cap: u64,
         asset_amt: Vec<(P, u128)>,
+    ) -> Self {
+        Self::new_with_capacities_and_sudts(
+            caps,
+            vec![(asset.clone(), max_cap)],
+            asset_amt
+                .into_iter()
+                .map(|(acc, amt)| (acc, vec![amt]))
+                .collect(),
+        )
+    }
+
+    /// new_with_capacities_and_sudts creates a funding agreement over several sUDT assets, given as
+    /// pairs of type script and max capacity. The assets are registered as given, so the same
+    /// asset can be listed twice.
+    pub fn new_with_capacities_and_sudts<P: perun::Account>(
+        caps: Vec<(P, u64)>,
+        assets: Vec<(Script, u64)>,
+        asset_amts: Vec<(P, Vec<u128>)>,
     ) -> Self {
         let mut r = AssetRegister::new();
-        let a = r.register_asset(
-            SUDTAsset::new_builder()
-                .type_script(asset.clone())
-                .max_capacity(max_cap.pack())
-                .build(),
-        );
+        let a: Vec<Asset> = assets
+            .iter()
+            .map(|(asset, max_cap)| {
+                r.register_asset(
+                    SUDTAsset::new_builder()
+                        .type_script(asset.clone())
+                        .max_capacity(max_cap.pack())
+                        .build(),
+                )
+            })
+            .collect();
         FundingAgreement {
             entries: caps
                 .iter()
                 .enumerate()
                 .map(|(i, (acc, c))| FundingAgreementEntry {
                     ckbytes: *c,
-                    sudts: vec![(a, asset_amt.get(i).unwrap().1)],
+                    sudts: a
+                        .iter()
+                        .cloned()
+                        .zip(asset_amts.get(i).unwrap().1.iter().cloned())
+                        .collect(),
                     index: i as u8,
                     pub_key: acc.public_key(),
+                    sig_scheme: acc.sig_scheme(),
                 })
                 .collect(),
             register: r,
@@ -106,6 +135,14 @@ impl FundingAgreement {
                     )
                     .expect("script");
                 let unlock_script_hash = unlock_script.calc_script_hash();
+                // Every party delegates to a watchtower, which is identified by the always success
+                // script parameterized by the delegate index of the party.
+                let delegate_script = ctx
+                    .build_script(
+                        &env.always_success_out_point,
+                        Bytes::from(vec![perun::harness::delegate_index(entry.index)]),
+                    )
+                    .expect("script");
                 ParticipantBuilder::default()
                     // The payment script hash used to lock the funds after a channel close for
                     // this party.
@@ -118,6 +155,13 @@ impl FundingAgreement {
                     // party index.
                     .unlock_script_hash(unlock_script_hash.clone())
                     .pub_key(sec1_pub_key)
+                    // The scheme used by this party to sign channel states.
+                    .sig_scheme(entry.sig_scheme.into())
+                    .delegates(
+                        Byte32Vec::new_builder()
+                            .push(delegate_script.calc_script_hash())
+                            .build(),
+                    )
                     .build()
             })
             .collect()
@@ -126,11 +170,12 @@ impl FundingAgreement {
     /// mk_balances creates a Balances object from the funding agreement where the given indices
     /// already funded their part.
     pub fn mk_balances(&self, indices: Vec<u8>) -> Result<Balances, perun::Error> {
-        let mut ckbytes = [0u64; 2];
+        let num_parties = self.entries.len();
+        let mut ckbytes = vec![0u64; num_parties];
         let sudts = self.register.get_sudtassets();
-        let mut sudt_dist: Vec<[u128; 2]> = Vec::new();
+        let mut sudt_dist: Vec<Vec<u128>> = Vec::new();
         for _ in 0..sudts.len() {
-            sudt_dist.push([0u128, 0]);
+            sudt_dist.push(vec![0u128; num_parties]);
         }
         for fae in self.entries.iter() {
             if indices.iter().find(|&&i| i == fae.index).is_none() {
@@ -147,12 +192,7 @@ impl FundingAgreement {
             sudt_alloc.push(
                 SUDTBalances::new_builder()
                     .asset(asset.clone())
-                    .distribution(
-                        SUDTDistribution::new_builder()
-                            .nth0(sudt_dist[i][0].pack())
-                            .nth1(sudt_dist[i][1].pack())
-                            .build(),
-                    )
+                    .distribution(SUDTDistribution::from_vec(sudt_dist[i].clone()))
                     .build(),
             );
         }
@@ -160,12 +200,7 @@ impl FundingAgreement {
         println!("mkbalances ckbytes: {:?}", ckbytes);
 
         Ok(Balances::new_builder()
-            .ckbytes(
-                CKByteDistribution::new_builder()
-                    .nth0(ckbytes[0].pack())
-                    .nth1(ckbytes[1].pack())
-                    .build(),
-            )
+            .ckbytes(CKByteDistribution::from_vec(ckbytes))
             .sudts(SUDTAllocation::new_builder().set(sudt_alloc).build())
             .build())
     }
@@ -213,6 +248,7 @@ pub struct FundingAgreementEntry {
     pub sudts: Vec<(Asset, u128)>,
     pub index: u8,
     pub pub_key: PublicKey,
+    pub sig_scheme: u8,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
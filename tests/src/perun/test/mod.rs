@@ -13,3 +13,7 @@ pub mod keys;
 pub mod transaction;
 
 pub mod cell;
+
+#[cfg(test)]
+mod sub_channel;
+pub use sub_channel::*;
@@ -0,0 +1,100 @@
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    packed::{Byte32, BytesVec},
+    prelude::*,
+};
+use ckb_testtool::context::Context;
+use perun_common::{
+    cfalse, ctrue,
+    helpers::blake2b256,
+    perun_types::{
+        self, Balances, CKByteDistribution, ChannelParameters, ChannelState, SignedState,
+    },
+};
+
+use crate::perun::{self, harness, random, test, Account};
+
+use super::Client;
+
+/// VirtualChannel is a Perun test virtual channel. It never lives in a channel cell itself, but is
+/// funded by sub-allocations in the states of its parent ledger channels. It only handles the
+/// parameters and participants of the virtual channel, so that its states can be signed and
+/// registered in disputes of the parent channels.
+pub struct VirtualChannel {
+    /// The parameters of the virtual channel.
+    params: ChannelParameters,
+    /// The participants of the virtual channel, in the order of the channel parameters.
+    parts: Vec<Client>,
+    /// The code hash of the pcts, which the signatures on states of the virtual channel are bound
+    /// to.
+    pcts_code_hash: Byte32,
+}
+
+impl VirtualChannel {
+    pub fn new(
+        ctx: &mut Context,
+        env: &harness::Env,
+        parts: &[perun::TestAccount],
+    ) -> Self {
+        // Only the participants of the funding agreement are used, virtual channels are funded by
+        // their parent channels.
+        let funding_agreement = test::FundingAgreement::new_with_capacities(
+            parts.iter().cloned().map(|p| (p, 0u64)).collect(),
+        );
+        let parties = funding_agreement.mk_participants(ctx, env, env.min_capacity_no_script);
+        let params = perun_types::ChannelParametersBuilder::default()
+            .parties(perun_types::Participants::new_builder().set(parties).build())
+            .nonce(random::nonce().pack())
+            .challenge_duration(env.challenge_duration.pack())
+            .is_ledger_channel(cfalse!())
+            .is_virtual_channel(ctrue!())
+            .build();
+        let parts = parts
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                Client::new(i as u8, p.name(), p.sk.clone()).with_sig_scheme(p.sig_scheme)
+            })
+            .collect();
+        let pcts_code_hash = env.build_pcts(ctx, Bytes::new()).code_hash();
+        VirtualChannel {
+            params,
+            parts,
+            pcts_code_hash,
+        }
+    }
+
+    /// id returns the channel id of the virtual channel.
+    pub fn id(&self) -> Byte32 {
+        blake2b256(self.params.as_slice()).pack()
+    }
+
+    /// mk_state returns a state of the virtual channel with the given version and CKByte
+    /// balances. `ckbytes` holds one entry per participant.
+    pub fn mk_state(&self, version: u64, ckbytes: Vec<u64>) -> ChannelState {
+        ChannelState::new_builder()
+            .channel_id(self.id())
+            .balances(
+                Balances::new_builder()
+                    .ckbytes(CKByteDistribution::from_vec(ckbytes))
+                    .build(),
+            )
+            .version(version.pack())
+            .is_final(cfalse!())
+            .build()
+    }
+
+    /// sign returns the given state together with the signatures of all participants.
+    pub fn sign(&self, state: ChannelState) -> Result<SignedState, perun::Error> {
+        let sigs: Result<Vec<_>, _> = self
+            .parts
+            .iter()
+            .map(|p| p.sign(&self.pcts_code_hash, state.clone()))
+            .collect();
+        Ok(SignedState::new_builder()
+            .params(self.params.clone())
+            .state(state)
+            .sigs(BytesVec::new_builder().set(sigs?.iter().map(|s| s.pack()).collect()).build())
+            .build())
+    }
+}
@@ -1,6 +1,5 @@
 use ckb_testtool::{
     ckb_types::{
-        bytes::Bytes,
         core::{TransactionBuilder, TransactionView},
         packed::{CellInput, OutPoint},
         prelude::{Builder, Entity, Pack},
@@ -9,9 +8,9 @@ use ckb_testtool::{
 };
 use perun_common::{perun_types::ChannelStatus, redeemer};
 
-use crate::perun::{self, harness, test::{cell::FundingCell, transaction::common::add_cap_to_a}};
+use crate::perun::{self, harness, test::cell::FundingCell};
 
-use super::common::{channel_witness, create_cells};
+use super::common::{channel_capacity, channel_witness, create_cells, mk_payouts};
 
 #[derive(Debug, Clone)]
 pub struct AbortArgs {
@@ -19,6 +18,8 @@ pub struct AbortArgs {
     pub funds: Vec<FundingCell>,
     pub state: ChannelStatus,
     pub party_index: u8,
+    /// The absolute since with which the channel cell is consumed.
+    pub since: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -44,7 +45,8 @@ pub fn mk_abort(
     let witness_args = channel_witness!(abort_action);
     let mut inputs = vec![
         CellInput::new_builder()
-            .previous_output(args.channel_cell)
+            .previous_output(args.channel_cell.clone())
+            .since(args.since.pack())
             .build(),
         CellInput::new_builder()
             .previous_output(payment_input)
@@ -59,10 +61,27 @@ pub fn mk_abort(
     let headers: Vec<_> = ctx.headers.keys().cloned().collect();
     // TODO: We are expecting the output amounts to be greater than the minimum amount necessary to
     // accomodate the space required for each output cell.
-    let f = |idx| env.build_lock_script(ctx, Bytes::from(vec![idx]));
-    let channel_cap = env.min_capacity_for_channel(args.state.clone())?;
-    let balances = add_cap_to_a(&args.state.state().balances(), channel_cap);
-    let outputs = balances.mk_outputs(f, vec![0]);
+    let channel_cap = channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    // Only the parties that already funded the channel are payed out.
+    let funded: Vec<u8> = args
+        .state
+        .funded_parties()
+        .to_vec()
+        .iter()
+        .enumerate()
+        .filter(|(_, funded)| **funded)
+        .map(|(i, _)| i as u8)
+        .collect();
+    let outputs = mk_payouts(
+        ctx,
+        env,
+        &args.channel_cell,
+        &args.state,
+        &args.state.state().balances(),
+        channel_cap,
+        0,
+        funded,
+    )?;
     let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
 
     let cell_deps = vec![
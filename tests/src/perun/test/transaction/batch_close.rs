@@ -0,0 +1,106 @@
+use ckb_testtool::{
+    ckb_types::packed::CellInput,
+    ckb_types::{
+        core::{TransactionBuilder, TransactionView},
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{close, redeemer};
+
+use crate::perun::{self, harness, test::transaction::common::channel_witness};
+
+use super::common::{channel_capacity, create_cells, mk_payouts, mk_sigs, tag_outputs};
+use super::CloseArgs;
+
+#[derive(Debug, Clone)]
+pub struct BatchCloseResult {
+    pub tx: TransactionView,
+}
+
+impl Default for BatchCloseResult {
+    fn default() -> Self {
+        BatchCloseResult {
+            tx: TransactionBuilder::default().build(),
+        }
+    }
+}
+
+/// mk_batch_close closes all given channels in a single transaction issued by the party at
+/// `party_index`. If `tagged` is set, the payouts of each channel are tagged with the script hash of
+/// its pcts.
+pub fn mk_batch_close(
+    ctx: &mut Context,
+    env: &harness::Env,
+    closes: Vec<CloseArgs>,
+    party_index: u8,
+    tagged: bool,
+) -> Result<BatchCloseResult, perun::Error> {
+    // The channel cells come first, so that the witness of each channel has the index of its
+    // channel cell.
+    let mut inputs: Vec<_> = closes
+        .iter()
+        .map(|c| {
+            CellInput::new_builder()
+                .previous_output(c.channel_cell.clone())
+                .build()
+        })
+        .collect();
+    let payment_input = env.create_min_cell_for_index(ctx, party_index);
+    inputs.push(
+        CellInput::new_builder()
+            .previous_output(payment_input)
+            .build(),
+    );
+    let mut outputs = Vec::new();
+    let mut witnesses = Vec::new();
+    for c in closes.iter() {
+        inputs.extend(c.funds_cells.iter().map(|f| {
+            CellInput::new_builder()
+                .previous_output(f.outpoint())
+                .build()
+        }));
+        let channel_cap = channel_capacity(ctx, env, &c.channel_cell, c.state.clone(), 0)?;
+        let mut payouts = mk_payouts(
+            ctx,
+            env,
+            &c.channel_cell,
+            &c.state,
+            &c.state.state().balances(),
+            channel_cap,
+            0,
+            (0..c.sigs.len() as u8).collect(),
+        )?;
+        if tagged {
+            let pcts = ctx
+                .get_cell(&c.channel_cell)
+                .and_then(|(cell, _)| cell.type_().to_opt())
+                .ok_or(perun::Error::new("channel cell without pcts"))?;
+            payouts = tag_outputs(payouts, pcts.calc_script_hash().as_bytes());
+        }
+        outputs.extend(payouts);
+
+        let close_action = redeemer!(close!(c.state.state(), mk_sigs(&c.sigs)));
+        witnesses.push(channel_witness!(close_action).as_bytes().pack());
+    }
+
+    let cell_deps = vec![
+        env.pcls_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pfls_script_dep.clone(),
+        env.always_success_script_dep.clone(),
+    ];
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs.iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .witnesses(witnesses)
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs);
+    Ok(BatchCloseResult { tx })
+}
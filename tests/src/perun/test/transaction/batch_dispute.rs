@@ -0,0 +1,85 @@
+use ckb_testtool::{
+    ckb_types::packed::CellInput,
+    ckb_types::{
+        core::{TransactionBuilder, TransactionView},
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{dispute, perun_types::SignedStates, redeemer};
+
+use crate::perun::{self, harness, test::transaction::common::channel_witness};
+
+use super::common::{create_cells, mk_sigs};
+use super::{mk_dispute_output, DisputeArgs};
+
+#[derive(Debug, Clone)]
+pub struct BatchDisputeResult {
+    pub tx: TransactionView,
+}
+
+impl Default for BatchDisputeResult {
+    fn default() -> Self {
+        BatchDisputeResult {
+            tx: TransactionBuilder::default().build(),
+        }
+    }
+}
+
+/// mk_batch_dispute disputes all given channels in a single transaction issued by the party at
+/// `party_index`, which pays the transaction fees from an untagged input.
+pub fn mk_batch_dispute(
+    ctx: &mut Context,
+    env: &harness::Env,
+    disputes: Vec<DisputeArgs>,
+    party_index: u8,
+) -> Result<BatchDisputeResult, perun::Error> {
+    // The channel cells come first, so that the witness of each channel has the index of its
+    // channel cell.
+    let mut inputs: Vec<_> = disputes
+        .iter()
+        .map(|d| {
+            CellInput::new_builder()
+                .previous_output(d.channel_cell.clone())
+                .build()
+        })
+        .collect();
+    let payment_input = env.create_min_cell_for_index(ctx, party_index);
+    inputs.push(
+        CellInput::new_builder()
+            .previous_output(payment_input)
+            .build(),
+    );
+    let mut outputs = Vec::new();
+    let mut witnesses = Vec::new();
+    for d in disputes.iter() {
+        let (channel_cell, state) = mk_dispute_output(ctx, env, d)?;
+        outputs.push((channel_cell, state.as_bytes()));
+
+        let sub_channels = SignedStates::new_builder()
+            .set(d.sub_channels.clone())
+            .build();
+        let dispute_action = redeemer!(dispute!(mk_sigs(&d.sigs), sub_channels));
+        witnesses.push(channel_witness!(dispute_action).as_bytes().pack());
+    }
+
+    let cell_deps = vec![
+        env.pcls_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pfls_script_dep.clone(),
+        env.always_success_script_dep.clone(),
+    ];
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs.iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .witnesses(witnesses)
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs);
+    Ok(BatchDisputeResult { tx })
+}
@@ -0,0 +1,131 @@
+use ckb_occupied_capacity::{Capacity, IntoCapacity};
+use ckb_testtool::{
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellInput, CellOutput, OutPoint},
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{fund, perun_types::ChannelStatus, redeemer};
+
+use crate::perun::{self, harness};
+
+use super::common::{channel_capacity, channel_witness, create_cells, create_funding_from};
+use super::FundArgs;
+
+#[derive(Debug, Clone)]
+pub struct BatchFundResult {
+    pub tx: TransactionView,
+    /// The channel cells of the funded channels, in the order of the given funds.
+    pub channel_cells: Vec<OutPoint>,
+    /// The channel status of each funded channel, in the order of the given funds.
+    pub states: Vec<ChannelStatus>,
+}
+
+impl Default for BatchFundResult {
+    fn default() -> Self {
+        BatchFundResult {
+            tx: TransactionBuilder::default().build(),
+            channel_cells: vec![],
+            states: vec![],
+        }
+    }
+}
+
+/// mk_batch_fund funds all given channels in a single transaction issued by the party at
+/// `party_index`. The remaining capacity of the inputs is returned to the party in a single untagged
+/// change output.
+pub fn mk_batch_fund(
+    ctx: &mut Context,
+    env: &harness::Env,
+    funds: Vec<FundArgs>,
+    party_index: u8,
+) -> Result<BatchFundResult, perun::Error> {
+    // The channel cells come first, so that the witness of each channel has the index of its
+    // channel cell. The new channel cells are the first outputs in the same order.
+    let mut inputs: Vec<_> = funds
+        .iter()
+        .map(|f| {
+            CellInput::new_builder()
+                .previous_output(f.channel_cell.clone())
+                .build()
+        })
+        .collect();
+    let pcls = env.build_pcls(ctx, Default::default());
+    let mut channel_outputs = Vec::new();
+    let mut fund_outputs = Vec::new();
+    let mut witnesses = Vec::new();
+    let mut states = Vec::new();
+    let mut available = Capacity::zero();
+    let mut wanted = Capacity::zero();
+    for f in funds.iter() {
+        for (outpoint, capacity) in f.inputs.iter() {
+            inputs.push(
+                CellInput::new_builder()
+                    .previous_output(outpoint.clone())
+                    .build(),
+            );
+            available = available.safe_add(*capacity)?;
+        }
+        wanted = wanted.safe_add(
+            (f.funding_agreement
+                .expected_ckbytes_funding_for(party_index)?
+                + f.funding_agreement.sudt_max_cap_sum())
+            .into_capacity(),
+        )?;
+        let pfls = env.build_pfls(ctx, f.pcts.calc_script_hash().as_bytes());
+        let balances = f.funding_agreement.mk_balances(vec![party_index])?;
+        fund_outputs.extend(balances.mk_outputs(|_| pfls.clone(), vec![party_index]));
+
+        let updated_cs = f.state.clone().mk_funded_by(party_index as usize)?;
+        let capacity = channel_capacity(ctx, env, &f.channel_cell, updated_cs.clone(), 0)?;
+        channel_outputs.push((
+            CellOutput::new_builder()
+                .capacity(capacity.pack())
+                .lock(pcls.clone())
+                .type_(Some(f.pcts.clone()).pack())
+                .build(),
+            updated_cs.as_bytes(),
+        ));
+        states.push(updated_cs);
+
+        let fund_action = redeemer!(fund!(party_index));
+        witnesses.push(channel_witness!(fund_action).as_bytes().pack());
+    }
+    let change = CellOutput::new_builder()
+        .capacity(create_funding_from(available, wanted)?.pack())
+        .lock(env.build_lock_script(ctx, Bytes::from(vec![party_index])))
+        .build();
+    let num_channels = channel_outputs.len();
+    let mut outputs = channel_outputs;
+    outputs.extend(fund_outputs);
+    outputs.push((change, Bytes::new()));
+
+    let cell_deps = vec![
+        env.always_success_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pcls_script_dep.clone(),
+        env.sample_udt_script_dep.clone(),
+    ];
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs.iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .witnesses(witnesses)
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs);
+    Ok(BatchFundResult {
+        channel_cells: (0..num_channels)
+            .map(|i| OutPoint::new(tx.hash(), i as u32))
+            .collect(),
+        states,
+        tx,
+    })
+}
@@ -0,0 +1,94 @@
+use ckb_testtool::{
+    ckb_types::packed::{CellInput, CellOutput, OutPoint},
+    ckb_types::{
+        core::{TransactionBuilder, TransactionView},
+        packed::Script,
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{checkpoint, perun_types::ChannelStatus, redeemer};
+
+use crate::perun::{self, harness, test::transaction::common::channel_witness};
+
+use super::common::{channel_capacity, create_cells, mk_sigs};
+
+#[derive(Debug, Clone)]
+pub struct CheckpointArgs {
+    /// The channel cell which tracks the channel on-chain.
+    pub channel_cell: OutPoint,
+    /// The channel state which shall be recorded.
+    pub state: ChannelStatus,
+    /// The DER encoded signatures for the channel state in proper order of parties.
+    pub sigs: Vec<Vec<u8>>,
+    /// The Perun channel type script used for the current channel.
+    pub pcts_script: Script,
+    pub party_index: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckpointResult {
+    pub tx: TransactionView,
+    pub channel_cell: OutPoint,
+}
+
+impl Default for CheckpointResult {
+    fn default() -> Self {
+        CheckpointResult {
+            tx: TransactionBuilder::default().build(),
+            channel_cell: OutPoint::default(),
+        }
+    }
+}
+
+pub fn mk_checkpoint(
+    ctx: &mut Context,
+    env: &harness::Env,
+    args: CheckpointArgs,
+) -> Result<CheckpointResult, perun::Error> {
+    let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
+    let inputs = vec![
+        CellInput::new_builder()
+            .previous_output(args.channel_cell.clone())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(payment_input)
+            .build(),
+    ];
+
+    let cell_deps = vec![
+        env.pcls_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pfls_script_dep.clone(),
+        env.always_success_script_dep.clone(),
+    ];
+
+    let pcls_script = env.build_pcls(ctx, Default::default());
+    let capacity_for_cs = channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    let channel_cell = CellOutput::new_builder()
+        .capacity(capacity_for_cs.pack())
+        .lock(pcls_script.clone())
+        .type_(Some(args.pcts_script.clone()).pack())
+        .build();
+    let outputs = vec![(channel_cell.clone(), args.state.as_bytes())];
+    let outputs_data: Vec<_> = outputs.iter().map(|e| e.1.clone()).collect();
+
+    let checkpoint_action = redeemer!(checkpoint!(mk_sigs(&args.sigs)));
+    let witness_args = channel_witness!(checkpoint_action);
+
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs.iter().map(|e| e.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .header_deps(headers)
+        .witness(witness_args.as_bytes().pack())
+        .cell_deps(cell_deps)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs);
+    Ok(CheckpointResult {
+        channel_cell: OutPoint::new(tx.hash(), 0),
+        tx,
+    })
+}
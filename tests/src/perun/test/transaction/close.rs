@@ -1,9 +1,9 @@
 use ckb_testtool::{
-    ckb_types::packed::{CellInput, OutPoint},
+    ckb_types::packed::{CellInput, CellOutput, OutPoint},
     ckb_types::{
         bytes::Bytes,
         core::{TransactionBuilder, TransactionView},
-        prelude::{Builder, Entity, Pack},
+        prelude::{Builder, Entity, Pack, Unpack},
     },
     context::Context,
 };
@@ -14,7 +14,7 @@ use crate::perun::{
     test::{cell::FundingCell, transaction::common::channel_witness},
 };
 
-use super::common::{create_cells, add_cap_to_a};
+use super::common::{channel_capacity, create_cells, mk_payouts, mk_sigs, HTLCSettlement};
 
 #[derive(Debug, Clone)]
 pub struct CloseArgs {
@@ -25,8 +25,13 @@ pub struct CloseArgs {
     /// The channel state which shall be used for closing.
     pub state: ChannelStatus,
     /// The DER encoded signatures for the channel state in proper order of parties.
-    pub sigs: [Vec<u8>; 2],
+    pub sigs: Vec<Vec<u8>>,
+    /// The preimages and the since with which the HTLCs of the channel state are settled.
+    pub htlcs: HTLCSettlement,
     pub party_index: u8,
+    /// The CKBytes which the closing party takes from each sUDT payout of the other parties. It is
+    /// only set to test that sUDT payouts are verified.
+    pub skim: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -50,10 +55,11 @@ pub fn mk_close(
     let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
     let mut inputs = vec![
         CellInput::new_builder()
-            .previous_output(args.channel_cell)
+            .previous_output(args.channel_cell.clone())
             .build(),
         CellInput::new_builder()
             .previous_output(payment_input)
+            .since(args.htlcs.since.pack())
             .build(),
     ];
     inputs.extend(args.funds_cells.iter().cloned().map(|f| {
@@ -68,16 +74,27 @@ pub fn mk_close(
         env.pfls_script_dep.clone(),
         env.always_success_script_dep.clone(),
     ];
-    let channel_cap = env.min_capacity_for_channel(args.state.clone())?;
-    let balances = add_cap_to_a(&args.state.state().balances(), channel_cap);
-    let f = |idx| env.build_lock_script(ctx, Bytes::from(vec![idx]));
-    let outputs = balances.mk_outputs(f, vec![0, 1]);
+    let channel_cap = channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    let settled = args.htlcs.settle(&args.state.state().balances())?;
+    let mut outputs = mk_payouts(
+        ctx,
+        env,
+        &args.channel_cell,
+        &args.state,
+        &settled,
+        channel_cap,
+        0,
+        (0..args.sigs.len() as u8).collect(),
+    )?;
+    if args.skim > 0 {
+        outputs = skim_sudt_payouts(ctx, env, outputs, args.party_index, args.skim);
+    }
     let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
 
     let close_action = redeemer!(close!(
         args.state.state(),
-        args.sigs[0].pack(),
-        args.sigs[1].pack()
+        mk_sigs(&args.sigs),
+        args.htlcs.preimages()
     ));
     let witness_args = channel_witness!(close_action);
 
@@ -94,3 +111,39 @@ pub fn mk_close(
     create_cells(ctx, tx.hash(), outputs);
     Ok(CloseResult { tx })
 }
+
+/// skim_sudt_payouts takes `skim` CKBytes from each sUDT payout in `outputs`, which is not payed to
+/// the party at `party_index`, and pays them to that party in an additional output.
+fn skim_sudt_payouts(
+    ctx: &mut Context,
+    env: &harness::Env,
+    outputs: Vec<(CellOutput, Bytes)>,
+    party_index: u8,
+    skim: u64,
+) -> Vec<(CellOutput, Bytes)> {
+    let own_lock = env.build_lock_script(ctx, Bytes::from(vec![party_index]));
+    let mut skimmed = 0u64;
+    let mut outputs: Vec<_> = outputs
+        .into_iter()
+        .map(|(output, data)| {
+            if output.type_().is_none() || output.lock().as_slice() == own_lock.as_slice() {
+                return (output, data);
+            }
+            skimmed += skim;
+            let capacity: u64 = output.capacity().unpack();
+            let output = output
+                .as_builder()
+                .capacity((capacity - skim).pack())
+                .build();
+            (output, data)
+        })
+        .collect();
+    outputs.push((
+        CellOutput::new_builder()
+            .capacity(skimmed.pack())
+            .lock(own_lock)
+            .build(),
+        Bytes::new(),
+    ));
+    outputs
+}
@@ -1,13 +1,18 @@
 use ckb_occupied_capacity::Capacity;
 use ckb_testtool::{
     bytes,
-    ckb_types::{packed::{Byte32, CellOutput, OutPoint}, prelude::{Unpack, Pack}},
+    ckb_types::{packed::{Byte32, BytesVec, CellOutput, OutPoint, Script}, prelude::{Pack, Unpack}},
     context::Context,
 };
 use molecule::prelude::{Entity, Builder};
-use perun_common::perun_types::Balances;
+use perun_common::{
+    helpers,
+    perun_types::{
+        Balances, CKByteDistribution, CapacityRefundUnion, ChannelConstants, ChannelStatus,
+    },
+};
 
-use crate::perun;
+use crate::perun::{self, harness};
 
 /// Build witness args containing the given action.
 macro_rules! channel_witness {
@@ -33,9 +38,168 @@ pub fn create_cells(ctx: &mut Context, hash: Byte32, outputs: Vec<(CellOutput, b
     }
 }
 
-pub fn add_cap_to_a(balances: &Balances, cap: Capacity) -> Balances {
-    let bal_a: u64 = balances.ckbytes().nth0().unpack();
-    balances.clone().as_builder().ckbytes(
-        balances.ckbytes().as_builder().nth0(
-            (cap.as_u64() + bal_a).pack()).build()).build()
-}
\ No newline at end of file
+pub fn mk_sigs(sigs: &Vec<Vec<u8>>) -> BytesVec {
+    BytesVec::new_builder().set(sigs.iter().map(|s| s.pack()).collect()).build()
+}
+
+/// channel_constants returns the channel constants in the args of the pcts of the given channel cell.
+pub fn channel_constants(
+    ctx: &Context,
+    channel_cell: &OutPoint,
+) -> Result<ChannelConstants, perun::Error> {
+    let pcts = ctx
+        .get_cell(channel_cell)
+        .and_then(|(cell, _)| cell.type_().to_opt())
+        .ok_or(perun::Error::new("channel cell without pcts"))?;
+    Ok(ChannelConstants::from_slice(&pcts.args().raw_data())?)
+}
+
+/// refund_lock_script returns the lock script of the party or sponsor that is refunded the capacity
+/// of the channel cell according to `constants`. The sponsor is identified by `harness::SPONSOR_INDEX`.
+pub fn refund_lock_script(
+    ctx: &mut Context,
+    env: &harness::Env,
+    constants: &ChannelConstants,
+) -> Result<Script, perun::Error> {
+    let index = match constants.refund_party() {
+        Some(r) => r as u8,
+        None => harness::SPONSOR_INDEX,
+    };
+    let lock = env.build_lock_script(ctx, bytes::Bytes::from(vec![index]));
+    if let CapacityRefundUnion::RefundSponsor(sponsor) = constants.capacity_refund().to_enum() {
+        if sponsor.as_slice() != lock.calc_script_hash().as_slice() {
+            return Err(perun::Error::new("unknown sponsor"));
+        }
+    }
+    Ok(lock)
+}
+
+/// mk_payouts creates the payment outputs of a channel closed with `balances`. The capacity `cap` of
+/// the channel cell is refunded to the party or sponsor recorded in the channel constants, except for
+/// the dispute `bond`, which is payed to its holder recorded in `status`. Like the pcts, the dust
+/// policy of the channel is applied. Only the parties at `indices` and the refunded party are payed.
+pub fn mk_payouts(
+    ctx: &mut Context,
+    env: &harness::Env,
+    channel_cell: &OutPoint,
+    status: &ChannelStatus,
+    balances: &Balances,
+    cap: Capacity,
+    bond: u64,
+    mut indices: Vec<u8>,
+) -> Result<Vec<(CellOutput, bytes::Bytes)>, perun::Error> {
+    let constants = channel_constants(ctx, channel_cell)?;
+    let refund_party = constants.refund_party();
+    let mut ckbytes = balances.ckbytes().to_vec();
+    let mut refund = cap.as_u64();
+    if let Some(holder) = status.bond_holder().to_opt() {
+        refund -= bond;
+        ckbytes[u8::from(holder) as usize] += bond;
+    }
+    if let Some(r) = refund_party {
+        ckbytes[r] += refund;
+        if !indices.contains(&(r as u8)) {
+            indices.push(r as u8);
+        }
+    }
+    // The pcts considers the max_capacity of the sUDT assets, which is reimbursed to the parties, as
+    // part of their CKByte payouts.
+    let reimburse = balances.sudts().get_locked_ckbytes();
+    let payed = |i: usize| indices.contains(&(i as u8));
+    let mut payouts: Vec<u64> = ckbytes
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match payed(i) {
+            true => c + reimburse,
+            false => 0,
+        })
+        .collect();
+    let rounded_up = helpers::apply_dust_policy(&mut payouts, refund, &constants)?;
+    for (i, payout) in payouts.into_iter().enumerate() {
+        if payed(i) {
+            ckbytes[i] = payout - reimburse;
+        }
+    }
+    let balances = balances
+        .clone()
+        .as_builder()
+        .ckbytes(CKByteDistribution::from_vec(ckbytes))
+        .build();
+
+    let refund_lock = refund_lock_script(ctx, env, &constants)?;
+    let f = |idx| env.build_lock_script(ctx, bytes::Bytes::from(vec![idx]));
+    let mut outputs = balances.mk_outputs(f, indices);
+    if refund_party.is_none() {
+        outputs.push((
+            CellOutput::new_builder()
+                .capacity((refund - rounded_up).pack())
+                .lock(refund_lock)
+                .build(),
+            bytes::Bytes::new(),
+        ));
+    }
+    Ok(outputs)
+}
+
+/// HTLCSettlement holds what a closing transaction provides to settle the HTLCs of a channel.
+#[derive(Debug, Clone, Default)]
+pub struct HTLCSettlement {
+    /// The preimages revealed in the witness.
+    pub preimages: Vec<Vec<u8>>,
+    /// The absolute since of the payment input, which times out the HTLCs.
+    pub since: u64,
+    /// The time at which `since` matures, measured in the challenge duration metric of the channel.
+    pub time: u64,
+}
+
+impl HTLCSettlement {
+    pub fn preimages(&self) -> BytesVec {
+        mk_sigs(&self.preimages)
+    }
+
+    /// settle returns the balances in which all HTLCs are settled. An HTLC that can be settled in
+    /// neither way is payed back to its sender, which the pcts rejects.
+    pub fn settle(&self, balances: &Balances) -> Result<Balances, perun::Error> {
+        let preimages = self.preimages();
+        match balances.settle_htlcs(&preimages, |timeout| timeout <= self.time) {
+            Ok(settled) => Ok(settled),
+            Err(_) => Ok(balances.settle_htlcs(&preimages, |_| true)?),
+        }
+    }
+}
+
+/// tag_outputs attributes the given payment outputs to a channel by appending `tag` to their data.
+pub fn tag_outputs(
+    outputs: Vec<(CellOutput, bytes::Bytes)>,
+    tag: bytes::Bytes,
+) -> Vec<(CellOutput, bytes::Bytes)> {
+    outputs
+        .into_iter()
+        .map(|(output, data)| {
+            let mut tagged = data.to_vec();
+            tagged.extend_from_slice(&tag);
+            (output, bytes::Bytes::from(tagged))
+        })
+        .collect()
+}
+
+/// channel_capacity returns the capacity of the given channel cell minus `fee`, but at least the
+/// minimum capacity of a channel cell holding `status`.
+pub fn channel_capacity(
+    ctx: &Context,
+    env: &harness::Env,
+    channel_cell: &OutPoint,
+    status: ChannelStatus,
+    fee: u64,
+) -> Result<Capacity, perun::Error> {
+    let (cell, _) = ctx
+        .get_cell(channel_cell)
+        .ok_or(perun::Error::new("channel cell not found"))?;
+    let capacity: u64 = cell.capacity().unpack();
+    let pcts = cell
+        .type_()
+        .to_opt()
+        .ok_or(perun::Error::new("channel cell without pcts"))?;
+    let min_capacity = env.min_capacity_for_channel(&pcts, status)?;
+    Ok(Capacity::shannons(capacity.saturating_sub(fee)).max(min_capacity))
+}
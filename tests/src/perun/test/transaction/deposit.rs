@@ -0,0 +1,124 @@
+use ckb_occupied_capacity::{Capacity, IntoCapacity};
+use ckb_testtool::{
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellInput, CellOutput, OutPoint, Script},
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{deposit, perun_types::ChannelStatus, redeemer};
+
+use crate::perun::{
+    self, harness,
+    test::cell::{FundingCell, FundingCellCKBytes},
+};
+
+use super::common::{
+    channel_capacity, channel_witness, create_cells, create_funding_from, mk_sigs,
+};
+
+#[derive(Debug, Clone)]
+pub struct DepositArgs {
+    /// The channel cell which tracks the channel on-chain.
+    pub channel_cell: OutPoint,
+    /// The channel status crediting the deposit to the depositor.
+    pub state: ChannelStatus,
+    /// The DER encoded signatures for the channel state in proper order of parties.
+    pub sigs: Vec<Vec<u8>>,
+    /// The amount of CKBytes deposited by the party.
+    pub amount: u64,
+    pub party_index: u8,
+    pub inputs: Vec<(OutPoint, Capacity)>,
+    pub pcts: Script,
+}
+
+#[derive(Debug, Clone)]
+pub struct DepositResult {
+    pub tx: TransactionView,
+    pub channel_cell: OutPoint,
+    pub funds_cells: Vec<FundingCell>,
+}
+
+impl Default for DepositResult {
+    fn default() -> Self {
+        DepositResult {
+            tx: TransactionBuilder::default().build(),
+            channel_cell: OutPoint::default(),
+            funds_cells: vec![],
+        }
+    }
+}
+
+pub fn mk_deposit(
+    ctx: &mut Context,
+    env: &harness::Env,
+    args: DepositArgs,
+) -> Result<DepositResult, perun::Error> {
+    let deposit_action = redeemer!(deposit!(mk_sigs(&args.sigs)));
+    let witness_args = channel_witness!(deposit_action);
+    let pfls = env.build_pfls(ctx, args.pcts.calc_script_hash().as_bytes());
+
+    let my_available_funds = Capacity::shannons(args.inputs.iter().map(|(_, c)| c.as_u64()).sum());
+    let exchange_cell = create_funding_from(my_available_funds, args.amount.into_capacity())?;
+    let mut inputs = vec![
+        CellInput::new_builder()
+            .previous_output(args.channel_cell.clone())
+            .build(),
+    ];
+    for (outpoint, _) in args.inputs.iter() {
+        inputs.push(CellInput::new_builder().previous_output(outpoint.clone()).build());
+    }
+    let capacity_for_new_cs =
+        channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    let pcls = env.build_pcls(ctx, Default::default());
+    let new_channel_cell = CellOutput::new_builder()
+        .capacity(capacity_for_new_cs.pack())
+        .lock(pcls.clone())
+        .type_(Some(args.pcts.clone()).pack())
+        .build();
+    let outputs = vec![
+        (
+            CellOutput::new_builder()
+                .capacity(args.amount.pack())
+                .lock(pfls)
+                .build(),
+            Bytes::new(),
+        ),
+        (new_channel_cell, args.state.as_bytes()),
+        (
+            CellOutput::new_builder()
+                .capacity(exchange_cell.pack())
+                .lock(env.build_lock_script(ctx, Bytes::from(vec![args.party_index])))
+                .build(),
+            Bytes::new(),
+        ),
+    ];
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let cell_deps = vec![
+        env.always_success_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pcls_script_dep.clone(),
+    ];
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .witness(witness_args.as_bytes().pack())
+        .outputs(outputs.clone().into_iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs.clone());
+    Ok(DepositResult {
+        channel_cell: OutPoint::new(tx.hash(), 1),
+        funds_cells: vec![FundingCell::FundingCellCKBytes(FundingCellCKBytes {
+            index: args.party_index,
+            cap: args.amount,
+            out_point: OutPoint::new(tx.hash(), 0),
+        })],
+        tx,
+    })
+}
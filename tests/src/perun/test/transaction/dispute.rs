@@ -3,15 +3,19 @@ use ckb_testtool::{
     ckb_types::{
         core::{TransactionBuilder, TransactionView},
         packed::Script,
-        prelude::{Builder, Entity, Pack},
+        prelude::{Builder, Entity, Pack, Unpack},
     },
     context::Context,
 };
-use perun_common::{dispute, perun_types::ChannelStatus, redeemer};
+use perun_common::{
+    dispute,
+    perun_types::{ChannelStatus, SignedState, SignedStates},
+    redeemer,
+};
 
 use crate::perun::{self, harness, test::transaction::common::channel_witness};
 
-use super::common::create_cells;
+use super::common::{channel_capacity, create_cells, mk_sigs};
 
 #[derive(Debug, Clone)]
 pub struct DisputeArgs {
@@ -20,16 +24,24 @@ pub struct DisputeArgs {
     /// The channel state which shall be used for closing.
     pub state: ChannelStatus,
     /// The DER encoded signatures for the channel state in proper order of parties.
-    pub sigs: [Vec<u8>; 2],
+    pub sigs: Vec<Vec<u8>>,
     /// The Perun channel type script used for the current channel.
     pub pcts_script: Script,
     pub party_index: u8,
+    /// The signed states of sub-channels which are registered together with the channel state.
+    pub sub_channels: Vec<SignedState>,
+    /// The fee in shannons which is payed from the capacity of the channel cell.
+    pub fee: u64,
+    /// The dispute bond in shannons which the disputing party locks in the channel cell.
+    pub bond: u64,
 }
 
 #[derive(Debug, Clone)]
 pub struct DisputeResult {
     pub tx: TransactionView,
     pub channel_cell: OutPoint,
+    /// The registered channel status, which adds the fee payed by the dispute to the spent fee.
+    pub state: ChannelStatus,
 }
 
 impl Default for DisputeResult {
@@ -37,6 +49,7 @@ impl Default for DisputeResult {
         DisputeResult {
             tx: TransactionBuilder::default().build(),
             channel_cell: OutPoint::default(),
+            state: ChannelStatus::default(),
         }
     }
 }
@@ -49,7 +62,7 @@ pub fn mk_dispute(
     let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
     let inputs = vec![
         CellInput::new_builder()
-            .previous_output(args.channel_cell)
+            .previous_output(args.channel_cell.clone())
             .build(),
         CellInput::new_builder()
             .previous_output(payment_input)
@@ -61,19 +74,16 @@ pub fn mk_dispute(
         env.pcts_script_dep.clone(),
         env.pfls_script_dep.clone(),
         env.always_success_script_dep.clone(),
+        // The pcts executes the app validator upon unilateral progressions of app channels.
+        env.sample_tic_tac_toe_script_dep.clone(),
     ];
 
-    let pcls_script = env.build_pcls(ctx, Default::default());
-    let capacity_for_cs = env.min_capacity_for_channel(args.state.clone())?;
-    let channel_cell = CellOutput::new_builder()
-        .capacity(capacity_for_cs.pack())
-        .lock(pcls_script.clone())
-        .type_(Some(args.pcts_script.clone()).pack())
-        .build();
-    let outputs = vec![(channel_cell.clone(), args.state.as_bytes())];
+    let (channel_cell, state) = mk_dispute_output(ctx, env, &args)?;
+    let outputs = vec![(channel_cell.clone(), state.as_bytes())];
     let outputs_data: Vec<_> = outputs.iter().map(|e| e.1.clone()).collect();
 
-    let dispute_action = redeemer!(dispute!(args.sigs[0].pack(), args.sigs[1].pack()));
+    let sub_channels = SignedStates::new_builder().set(args.sub_channels).build();
+    let dispute_action = redeemer!(dispute!(mk_sigs(&args.sigs), sub_channels));
     let witness_args = channel_witness!(dispute_action);
 
     let headers: Vec<_> = ctx.headers.keys().cloned().collect();
@@ -90,5 +100,34 @@ pub fn mk_dispute(
     Ok(DisputeResult {
         channel_cell: OutPoint::new(tx.hash(), 0),
         tx,
+        state,
     })
 }
+
+/// mk_dispute_output returns the channel cell and the channel status registered by the dispute `args`.
+/// The channel cell pays the fee of the dispute and locks its bond.
+pub fn mk_dispute_output(
+    ctx: &mut Context,
+    env: &harness::Env,
+    args: &DisputeArgs,
+) -> Result<(CellOutput, ChannelStatus), perun::Error> {
+    let pcls_script = env.build_pcls(ctx, Default::default());
+    let capacity_for_cs =
+        channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), args.fee)?;
+    let channel_cell = CellOutput::new_builder()
+        .capacity((capacity_for_cs.as_u64() + args.bond).pack())
+        .lock(pcls_script)
+        .type_(Some(args.pcts_script.clone()).pack())
+        .build();
+    // The fee payed from the capacity of the channel cell is recorded in the status.
+    let capacity = channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    let spent_fee: u64 = args.state.spent_fee().unpack();
+    let spent_fee = spent_fee + capacity.as_u64().saturating_sub(capacity_for_cs.as_u64());
+    let state = args
+        .state
+        .clone()
+        .as_builder()
+        .spent_fee(spent_fee.pack())
+        .build();
+    Ok((channel_cell, state))
+}
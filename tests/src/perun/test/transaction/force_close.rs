@@ -1,33 +1,38 @@
+use ckb_occupied_capacity::Capacity;
 use ckb_testtool::{
     ckb_types::packed::{CellInput, OutPoint},
     ckb_types::{
-        bytes::Bytes,
         core::{TransactionBuilder, TransactionView},
-        packed::Byte32,
-        prelude::{Builder, Entity, Pack},
+        prelude::{Builder, Entity, Pack, Unpack},
     },
     context::Context,
 };
-use perun_common::{perun_types::ChannelStatus, redeemer};
+use perun_common::{force_close, perun_types::ChannelStatus, redeemer};
 
 use crate::perun::{
     self, harness,
     test::{cell::FundingCell, transaction::common::channel_witness},
 };
 
-use super::common::{create_cells, add_cap_to_a};
+use super::common::{create_cells, mk_payouts, HTLCSettlement};
 
 #[derive(Debug, Clone)]
 pub struct ForceCloseArgs {
     /// The channel cell which tracks the channel on-chain.
     pub channel_cell: OutPoint,
-    /// The latest headers for the chain containing some timestamps.
-    pub headers: Vec<Byte32>,
+    /// The relative since with which the channel cell is consumed.
+    pub since: u64,
     /// All funding cells used to initially fund the channel.
     pub funds_cells: Vec<FundingCell>,
     /// The channel state which shall be used for closing.
     pub state: ChannelStatus,
+    /// The preimages and the since with which the HTLCs of the channel state are settled.
+    pub htlcs: HTLCSettlement,
     pub party_index: u8,
+    /// The fee in shannons which is payed from the channel funds.
+    pub fee: u64,
+    /// The dispute bond in shannons which is payed to its holder recorded in `state`.
+    pub bond: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -51,10 +56,12 @@ pub fn mk_force_close(
     let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
     let mut inputs = vec![
         CellInput::new_builder()
-            .previous_output(args.channel_cell)
+            .previous_output(args.channel_cell.clone())
+            .since(args.since.pack())
             .build(),
         CellInput::new_builder()
             .previous_output(payment_input)
+            .since(args.htlcs.since.pack())
             .build(),
     ];
     inputs.extend(args.funds_cells.iter().cloned().map(|f| {
@@ -71,20 +78,39 @@ pub fn mk_force_close(
     ];
 
     // Rust...
-    let channel_cap = env.min_capacity_for_channel(args.state.clone())?;
-    let balances = add_cap_to_a(&args.state.state().balances(), channel_cap);
-    let f = |idx| env.build_lock_script(ctx, Bytes::from(vec![idx]));
-    let outputs = balances.mk_outputs(f, vec![0, 1]);
+    // The fee is payed from the capacity of the channel cell, which is refunded to the party or
+    // sponsor that provided it.
+    let (channel_cell, _) = ctx
+        .get_cell(&args.channel_cell)
+        .ok_or(perun::Error::new("channel cell not found"))?;
+    let channel_cap = Capacity::shannons(channel_cell.capacity().unpack()).safe_sub(args.fee)?;
+    // The funds locked for sub-channels are payed out according to the registered sub-channel states.
+    let settled = args.htlcs.settle(
+        &args
+            .state
+            .state()
+            .balances()
+            .settle_sub_allocs(&args.state.sub_states())?,
+    )?;
+    let outputs = mk_payouts(
+        ctx,
+        env,
+        &args.channel_cell,
+        &args.state,
+        &settled,
+        channel_cap,
+        args.bond,
+        (0..settled.ckbytes().len() as u8).collect(),
+    )?;
     let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
 
-    let force_close_action = redeemer!(ForceClose);
+    let force_close_action = redeemer!(force_close!(args.htlcs.preimages()));
     let witness_args = channel_witness!(force_close_action);
 
     let rtx = TransactionBuilder::default()
         .inputs(inputs)
         .outputs(outputs.iter().map(|o| o.0.clone()))
         .outputs_data(outputs_data.pack())
-        .header_deps(args.headers)
         .witness(witness_args.as_bytes().pack())
         .cell_deps(cell_deps)
         .build();
@@ -15,7 +15,7 @@ use crate::perun::{
     test::{cell::{FundingCell, mk_funding_cell}, FundingAgreement},
 };
 
-use super::common::{channel_witness, create_cells, create_funding_from};
+use super::common::{channel_capacity, channel_witness, create_cells, create_funding_from};
 
 #[derive(Debug, Clone)]
 pub struct FundArgs {
@@ -51,7 +51,7 @@ pub fn mk_fund(
     env: &harness::Env,
     args: FundArgs,
 ) -> Result<FundResult, perun::Error> {
-    let fund_action = redeemer!(fund!());
+    let fund_action = redeemer!(fund!(args.party_index));
     let witness_args = channel_witness!(fund_action);
     let wanted = args
         .funding_agreement
@@ -62,23 +62,21 @@ pub fn mk_fund(
     // Note: we do not really need to shrink the balances to only contain the party's balances, as balances.mk_outputs will do so anyway.
     let balances = args.funding_agreement.mk_balances(vec![args.party_index])?;
     let pfls = |_| pfls.clone();
-    let mut outputs = balances.mk_outputs(pfls, vec![1]);
+    let mut outputs = balances.mk_outputs(pfls, vec![args.party_index]);
     let num_fund_ouputs = outputs.len();
 
     let my_available_funds = Capacity::shannons(args.inputs.iter().map(|(_, c)| c.as_u64()).sum());
     let exchange_cell = create_funding_from(my_available_funds, (wanted + args.funding_agreement.sudt_max_cap_sum()).into_capacity())?;
     let mut inputs = vec![
         CellInput::new_builder()
-            .previous_output(args.channel_cell)
+            .previous_output(args.channel_cell.clone())
             .build(),
     ];
     for (outpoint, _) in args.inputs.iter() {
         inputs.push(CellInput::new_builder().previous_output(outpoint.clone()).build());
     }
-    // NOTE: mk_fund currently expects the be called for the last party funding the channel.
-    // Otherwise the call to `mk_funded` returns a wrong channel state.
-    let updated_cs = args.state.mk_funded();
-    let capacity_for_new_cs = env.min_capacity_for_channel(updated_cs.clone())?;
+    let updated_cs = args.state.mk_funded_by(args.party_index as usize)?;
+    let capacity_for_new_cs = channel_capacity(ctx, env, &args.channel_cell, updated_cs.clone(), 0)?;
     let pcls = env.build_pcls(ctx, Default::default());
     let new_channel_cell = CellOutput::new_builder()
         .capacity(capacity_for_new_cs.pack())
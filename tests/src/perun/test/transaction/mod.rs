@@ -16,4 +16,29 @@ pub use force_close::*;
 mod dispute;
 pub use dispute::*;
 
+mod deposit;
+pub use deposit::*;
+
+mod withdraw;
+pub use withdraw::*;
+
+mod splice;
+pub use splice::*;
+
+mod checkpoint;
+pub use checkpoint::*;
+
+mod rotate;
+pub use rotate::*;
+
+mod batch_close;
+pub use batch_close::*;
+
+mod batch_fund;
+pub use batch_fund::*;
+
+mod batch_dispute;
+pub use batch_dispute::*;
+
 mod common;
+pub use common::HTLCSettlement;
@@ -26,9 +26,15 @@ pub struct OpenArgs {
     pub channel_token_outpoint: OutPoint,
     pub inputs: Vec<(OutPoint, Capacity)>,
     pub party_index: u8,
+    /// The other parties which jointly fund the channel in the opening transaction, together with
+    /// the inputs they fund it with.
+    pub joint_funders: Vec<(u8, Vec<(OutPoint, Capacity)>)>,
     pub pcls_script: Script,
     pub pcts_script: Script,
     pub pfls_script: Script,
+    pub app_data: Bytes,
+    /// The fee budget of the channel, which the opening party adds to the capacity of the channel cell.
+    pub max_fee: u64,
 }
 
 pub struct OpenResult {
@@ -56,12 +62,15 @@ pub fn mk_open(
     env: &harness::Env,
     args: OpenArgs,
 ) -> Result<OpenResult, perun::Error> {
+    let mut funders = vec![(args.party_index, args.inputs.clone())];
+    funders.extend(args.joint_funders.iter().cloned());
+    let funder_indices: Vec<u8> = funders.iter().map(|(idx, _)| *idx).collect();
     let mut inputs = vec![
         CellInput::new_builder()
             .previous_output(args.channel_token_outpoint)
             .build(),
     ];
-    for (outpoint, _) in args.inputs.iter() {
+    for (outpoint, _) in funders.iter().flat_map(|(_, inputs)| inputs.iter()) {
         inputs.push(
             CellInput::new_builder()
                 .previous_output(outpoint.clone())
@@ -69,39 +78,47 @@ pub fn mk_open(
         );
     }
     let initial_cs =
-        env.build_initial_channel_state(args.cid, args.party_index, &args.funding_agreement)?;
-    let capacity_for_cs = env.min_capacity_for_channel(initial_cs.clone())?;
+        env.build_initial_channel_state(
+            args.cid,
+            &funder_indices,
+            &args.funding_agreement,
+            args.app_data.clone(),
+        )?;
+    let capacity_for_cs = env.min_capacity_for_channel(&args.pcts_script, initial_cs.clone())?;
     let channel_cell = CellOutput::new_builder()
-        .capacity(capacity_for_cs.pack())
+        .capacity((capacity_for_cs.as_u64() + args.max_fee).pack())
         .lock(args.pcls_script.clone())
         .type_(Some(args.pcts_script.clone()).pack())
         .build();
-    let wanted = args
-        .funding_agreement
-        .expected_ckbytes_funding_for(args.party_index)?;
-    
     let pfls = |_| args.pfls_script.clone();
 
-    let balances = args.funding_agreement.mk_balances(vec![args.party_index])?;
-    let mut outputs = balances.mk_outputs(pfls, vec![0]);
+    // Each funder locks its funds to the pfls and receives the remainder of its inputs in an
+    // exchange cell.
+    let mut outputs = Vec::new();
+    let mut funds_indices = Vec::new();
+    let mut exchange_cells = Vec::new();
+    for (idx, funder_inputs) in funders.iter() {
+        let wanted = args.funding_agreement.expected_ckbytes_funding_for(*idx)?;
+        let balances = args.funding_agreement.mk_balances(vec![*idx])?;
+        let funds = balances.mk_outputs(pfls, vec![*idx]);
+        funds_indices.extend(vec![*idx; funds.len()]);
+        outputs.extend(funds);
+        // TODO: Make sure enough funds available all cells!
+        let available_funds = Capacity::shannons(funder_inputs.iter().map(|(_, c)| c.as_u64()).sum());
+        let exchange_cell_cap = create_funding_from(available_funds, (wanted + args.funding_agreement.sudt_max_cap_sum()).into_capacity())?;
+        exchange_cells.push((
+            CellOutput::new_builder()
+                .capacity(exchange_cell_cap.pack())
+                .lock(env.build_lock_script(ctx, Bytes::from(vec![*idx])))
+                .build(),
+            Bytes::new(),
+        ));
+    }
     let num_of_funds = outputs.len();
-    // TODO: Make sure enough funds available all cells!
-    let my_available_funds = Capacity::shannons(args.inputs.iter().map(|(_, c)| c.as_u64()).sum());
-    let exchange_cell_cap = create_funding_from(my_available_funds, (wanted + args.funding_agreement.sudt_max_cap_sum()).into_capacity())?;
     // NOTE: The ORDER here is important. We need to reference the outpoints later on by using the
     // correct index in the output array of the transaction we build.
-    outputs.append(
-        vec![
-            (channel_cell.clone(), initial_cs.as_bytes()),
-            (
-                CellOutput::new_builder()
-                    .capacity(exchange_cell_cap.pack())
-                    .lock(env.build_lock_script(ctx, Bytes::from(vec![args.party_index])))
-                    .build(),
-                Bytes::new(),
-            ),
-        ].as_mut()
-    );
+    outputs.push((channel_cell.clone(), initial_cs.as_bytes()));
+    outputs.append(&mut exchange_cells);
 
     let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
     let cell_deps = vec![
@@ -120,8 +137,8 @@ pub fn mk_open(
     Ok(OpenResult {
         // See NOTE above for magic indices.
         channel_cell: OutPoint::new(tx.hash(), num_of_funds as u32),
-        funds_cells: outputs[..num_of_funds].iter().enumerate().map(|(i, (co, bytes))| 
-            mk_funding_cell(args.party_index, OutPoint::new(tx.hash(), i as u32), co, bytes.clone(), args.funding_agreement.register())).collect(),
+        funds_cells: outputs[..num_of_funds].iter().zip(funds_indices.iter()).enumerate().map(|(i, ((co, bytes), idx))|
+            mk_funding_cell(*idx, OutPoint::new(tx.hash(), i as u32), co, bytes.clone(), args.funding_agreement.register())).collect(),
         tx,
         pcts: args.pcts_script,
         state: initial_cs,
@@ -0,0 +1,137 @@
+use ckb_occupied_capacity::Capacity;
+use ckb_testtool::{
+    ckb_types::{
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellInput, CellOutput, OutPoint, Script},
+        prelude::{Builder, Entity, Pack, Unpack},
+    },
+    context::Context,
+};
+use perun_common::{perun_types::ChannelStatus, redeemer, rotate};
+
+use crate::perun::{self, harness, test::cell::FundingCell};
+
+use super::common::{channel_witness, create_cells, mk_sigs};
+
+#[derive(Debug, Clone)]
+pub struct RotateArgs {
+    /// The channel cell which tracks the rotated channel on-chain.
+    pub channel_cell: OutPoint,
+    /// All funding cells of the rotated channel.
+    pub funds_cells: Vec<FundingCell>,
+    /// The status of the successor channel, which continues the status of the rotated channel.
+    pub successor_state: ChannelStatus,
+    /// The DER encoded signatures of the participants of the rotated channel on the successor
+    /// state in proper order of parties.
+    pub sigs: Vec<Vec<u8>>,
+    /// The pcts of the successor channel.
+    pub successor_pcts: Script,
+    pub party_index: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct RotateResult {
+    pub tx: TransactionView,
+    pub channel_cell: OutPoint,
+    pub funds_cells: Vec<FundingCell>,
+}
+
+impl Default for RotateResult {
+    fn default() -> Self {
+        RotateResult {
+            tx: TransactionBuilder::default().build(),
+            channel_cell: OutPoint::default(),
+            funds_cells: vec![],
+        }
+    }
+}
+
+pub fn mk_rotate(
+    ctx: &mut Context,
+    env: &harness::Env,
+    args: RotateArgs,
+) -> Result<RotateResult, perun::Error> {
+    let rotate_action = redeemer!(rotate!(mk_sigs(&args.sigs)));
+    let witness_args = channel_witness!(rotate_action);
+
+    let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
+    let mut inputs = vec![
+        CellInput::new_builder()
+            .previous_output(args.channel_cell.clone())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(payment_input)
+            .build(),
+    ];
+    inputs.extend(args.funds_cells.iter().map(|f| {
+        CellInput::new_builder()
+            .previous_output(f.outpoint())
+            .build()
+    }));
+
+    // Every funding cell is moved to the pfls of the successor unaltered.
+    let successor_pfls = env.build_pfls(ctx, args.successor_pcts.calc_script_hash().as_bytes());
+    let mut outputs = args
+        .funds_cells
+        .iter()
+        .map(|f| {
+            let (cell, data) = ctx
+                .get_cell(&f.outpoint())
+                .ok_or(perun::Error::new("funding cell not found"))?;
+            Ok((cell.as_builder().lock(successor_pfls.clone()).build(), data))
+        })
+        .collect::<Result<Vec<_>, perun::Error>>()?;
+    let num_of_funds = outputs.len();
+
+    // The successor channel cell takes over the capacity of the rotated channel cell, which is
+    // refunded to the party or sponsor recorded as capacity_refund upon close.
+    let (channel_cell, _) = ctx
+        .get_cell(&args.channel_cell)
+        .ok_or(perun::Error::new("channel cell not found"))?;
+    // The successor may occupy more capacity, which is added by the rotating party.
+    let capacity = env
+        .start_capacity_for_channel(&args.successor_pcts, args.successor_state.clone())?
+        .max(Capacity::shannons(channel_cell.capacity().unpack()));
+    // NOTE: The ORDER here is important. We need to reference the outpoints later on by using the
+    // correct index in the output array of the transaction we build.
+    outputs.push((
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(channel_cell.lock())
+            .type_(Some(args.successor_pcts.clone()).pack())
+            .build(),
+        args.successor_state.as_bytes(),
+    ));
+
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let cell_deps = vec![
+        env.pcls_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pfls_script_dep.clone(),
+        env.always_success_script_dep.clone(),
+        env.sample_udt_script_dep.clone(),
+    ];
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs.iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .witness(witness_args.as_bytes().pack())
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs);
+    let funds_cells = args
+        .funds_cells
+        .iter()
+        .enumerate()
+        .map(|(i, f)| f.with_outpoint(OutPoint::new(tx.hash(), i as u32)))
+        .collect();
+    Ok(RotateResult {
+        // See NOTE above for magic indices.
+        channel_cell: OutPoint::new(tx.hash(), num_of_funds as u32),
+        funds_cells,
+        tx,
+    })
+}
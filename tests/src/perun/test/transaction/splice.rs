@@ -0,0 +1,181 @@
+use ckb_occupied_capacity::{Capacity, IntoCapacity};
+use ckb_testtool::{
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellInput, CellOutput, OutPoint, Script},
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{close, perun_types::ChannelStatus, redeemer};
+
+use crate::perun::{
+    self, harness,
+    test::{
+        cell::{mk_funding_cell, FundingCell},
+        AssetRegister,
+    },
+};
+
+use super::common::{
+    channel_capacity, channel_constants, channel_witness, create_cells, create_funding_from,
+    mk_sigs, refund_lock_script,
+};
+
+#[derive(Debug, Clone)]
+pub struct SpliceArgs {
+    /// The channel cell which tracks the spliced channel on-chain.
+    pub channel_cell: OutPoint,
+    /// All funding cells of the spliced channel.
+    pub funds_cells: Vec<FundingCell>,
+    /// The final channel state committing to the successor.
+    pub state: ChannelStatus,
+    /// The DER encoded signatures for the final channel state in proper order of parties.
+    pub sigs: Vec<Vec<u8>>,
+    /// The initial status of the successor channel.
+    pub successor_state: ChannelStatus,
+    /// The pcts of the successor channel.
+    pub successor_pcts: Script,
+    /// The register of the sudt assets of the channel.
+    pub register: AssetRegister,
+    /// The amount of CKBytes added to the successor by the party.
+    pub amount: u64,
+    pub party_index: u8,
+    pub inputs: Vec<(OutPoint, Capacity)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpliceResult {
+    pub tx: TransactionView,
+    pub channel_cell: OutPoint,
+    pub funds_cells: Vec<FundingCell>,
+}
+
+impl Default for SpliceResult {
+    fn default() -> Self {
+        SpliceResult {
+            tx: TransactionBuilder::default().build(),
+            channel_cell: OutPoint::default(),
+            funds_cells: vec![],
+        }
+    }
+}
+
+pub fn mk_splice(
+    ctx: &mut Context,
+    env: &harness::Env,
+    args: SpliceArgs,
+) -> Result<SpliceResult, perun::Error> {
+    let close_action = redeemer!(close!(args.state.state(), mk_sigs(&args.sigs)));
+    let witness_args = channel_witness!(close_action);
+
+    let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
+    let mut inputs = vec![
+        CellInput::new_builder()
+            .previous_output(args.channel_cell.clone())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(payment_input)
+            .build(),
+    ];
+    inputs.extend(args.funds_cells.iter().map(|f| {
+        CellInput::new_builder()
+            .previous_output(f.outpoint())
+            .build()
+    }));
+    for (outpoint, _) in args.inputs.iter() {
+        inputs.push(CellInput::new_builder().previous_output(outpoint.clone()).build());
+    }
+
+    // The final balances of the spliced channel together with the added funds are locked to the
+    // pfls of the successor.
+    let successor_pfls = env.build_pfls(ctx, args.successor_pcts.calc_script_hash().as_bytes());
+    let num_parties = args.sigs.len() as u8;
+    let pfls = |_| successor_pfls.clone();
+    let mut outputs = args
+        .successor_state
+        .state()
+        .balances()
+        .mk_outputs(pfls, (0..num_parties).collect());
+    let num_of_funds = outputs.len();
+
+    let channel_cap = channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    let successor_cap =
+        env.start_capacity_for_channel(&args.successor_pcts, args.successor_state.clone())?;
+    let my_available_funds = Capacity::shannons(args.inputs.iter().map(|(_, c)| c.as_u64()).sum());
+    let exchange_cell = create_funding_from(my_available_funds, args.amount.into_capacity())?;
+    let pcls = env.build_pcls(ctx, Default::default());
+    let refund_lock = refund_lock_script(ctx, env, &channel_constants(ctx, &args.channel_cell)?)?;
+    // NOTE: The ORDER here is important. We need to reference the outpoints later on by using the
+    // correct index in the output array of the transaction we build.
+    outputs.append(
+        vec![
+            (
+                CellOutput::new_builder()
+                    .capacity(successor_cap.pack())
+                    .lock(pcls)
+                    .type_(Some(args.successor_pcts.clone()).pack())
+                    .build(),
+                args.successor_state.as_bytes(),
+            ),
+            // The capacity of the spliced channel cell is refunded to the party or sponsor that
+            // provided it.
+            (
+                CellOutput::new_builder()
+                    .capacity(channel_cap.pack())
+                    .lock(refund_lock)
+                    .build(),
+                Bytes::new(),
+            ),
+            (
+                CellOutput::new_builder()
+                    .capacity(exchange_cell.pack())
+                    .lock(env.build_lock_script(ctx, Bytes::from(vec![args.party_index])))
+                    .build(),
+                Bytes::new(),
+            ),
+        ]
+        .as_mut(),
+    );
+
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let cell_deps = vec![
+        env.pcls_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pfls_script_dep.clone(),
+        env.always_success_script_dep.clone(),
+        env.sample_udt_script_dep.clone(),
+    ];
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs.iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .witness(witness_args.as_bytes().pack())
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs.clone());
+    // The ckbytes outputs hold one cell per party, followed by one cell per party for each sudt asset.
+    let funds_cells = outputs[..num_of_funds]
+        .iter()
+        .enumerate()
+        .map(|(i, (co, bytes))| {
+            mk_funding_cell(
+                (i % num_parties as usize) as u8,
+                OutPoint::new(tx.hash(), i as u32),
+                co,
+                bytes.clone(),
+                &args.register,
+            )
+        })
+        .collect();
+    Ok(SpliceResult {
+        // See NOTE above for magic indices.
+        channel_cell: OutPoint::new(tx.hash(), num_of_funds as u32),
+        funds_cells,
+        tx,
+    })
+}
@@ -0,0 +1,145 @@
+use ckb_testtool::{
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{CellInput, CellOutput, OutPoint, Script},
+        prelude::{Builder, Entity, Pack},
+    },
+    context::Context,
+};
+use perun_common::{
+    perun_types::{ChannelState, ChannelStatus},
+    redeemer, withdraw,
+};
+
+use crate::perun::{
+    self, harness,
+    test::cell::{FundingCell, FundingCellCKBytes},
+};
+
+use super::common::{channel_capacity, channel_witness, create_cells, mk_sigs};
+
+#[derive(Debug, Clone)]
+pub struct WithdrawArgs {
+    /// The channel cell which tracks the channel on-chain.
+    pub channel_cell: OutPoint,
+    /// The CKBytes funding cells of the channel, which are consumed by the withdrawal.
+    pub funds_cells: Vec<FundingCellCKBytes>,
+    /// The latest off-chain channel state before the withdrawal.
+    pub prev_state: ChannelState,
+    /// The DER encoded signatures for the latest off-chain channel state in proper order of parties.
+    pub prev_sigs: Vec<Vec<u8>>,
+    /// The channel status deducting the withdrawal from the withdrawing party.
+    pub state: ChannelStatus,
+    /// The DER encoded signatures for the channel state in proper order of parties.
+    pub sigs: Vec<Vec<u8>>,
+    /// The amount of CKBytes withdrawn by the party.
+    pub amount: u64,
+    pub party_index: u8,
+    pub pcts: Script,
+}
+
+#[derive(Debug, Clone)]
+pub struct WithdrawResult {
+    pub tx: TransactionView,
+    pub channel_cell: OutPoint,
+    /// The funding cell holding the remaining funds of the channel.
+    pub funds_cell: FundingCell,
+}
+
+impl Default for WithdrawResult {
+    fn default() -> Self {
+        WithdrawResult {
+            tx: TransactionBuilder::default().build(),
+            channel_cell: OutPoint::default(),
+            funds_cell: FundingCell::default(),
+        }
+    }
+}
+
+pub fn mk_withdraw(
+    ctx: &mut Context,
+    env: &harness::Env,
+    args: WithdrawArgs,
+) -> Result<WithdrawResult, perun::Error> {
+    let withdraw_action = redeemer!(withdraw!(
+        args.prev_state.clone(),
+        mk_sigs(&args.prev_sigs),
+        mk_sigs(&args.sigs)
+    ));
+    let witness_args = channel_witness!(withdraw_action);
+    let pfls = env.build_pfls(ctx, args.pcts.calc_script_hash().as_bytes());
+
+    // The withdrawing party authorizes the transaction with one of its own cells.
+    let payment_input = env.create_min_cell_for_index(ctx, args.party_index);
+    let mut inputs = vec![
+        CellInput::new_builder()
+            .previous_output(args.channel_cell.clone())
+            .build(),
+        CellInput::new_builder()
+            .previous_output(payment_input)
+            .build(),
+    ];
+    inputs.extend(args.funds_cells.iter().map(|f| {
+        CellInput::new_builder()
+            .previous_output(f.out_point.clone())
+            .build()
+    }));
+    let locked: u64 = args.funds_cells.iter().map(|f| f.cap).sum();
+    let remainder = locked
+        .checked_sub(args.amount)
+        .ok_or(perun::Error::new("insufficient funds in channel"))?;
+
+    let capacity_for_new_cs =
+        channel_capacity(ctx, env, &args.channel_cell, args.state.clone(), 0)?;
+    let pcls = env.build_pcls(ctx, Default::default());
+    let new_channel_cell = CellOutput::new_builder()
+        .capacity(capacity_for_new_cs.pack())
+        .lock(pcls.clone())
+        .type_(Some(args.pcts.clone()).pack())
+        .build();
+    let outputs = vec![
+        (
+            CellOutput::new_builder()
+                .capacity(remainder.pack())
+                .lock(pfls)
+                .build(),
+            Bytes::new(),
+        ),
+        (new_channel_cell, args.state.as_bytes()),
+        (
+            CellOutput::new_builder()
+                .capacity(args.amount.pack())
+                .lock(env.build_lock_script(ctx, Bytes::from(vec![args.party_index])))
+                .build(),
+            Bytes::new(),
+        ),
+    ];
+    let outputs_data: Vec<_> = outputs.iter().map(|o| o.1.clone()).collect();
+    let cell_deps = vec![
+        env.always_success_script_dep.clone(),
+        env.pcts_script_dep.clone(),
+        env.pcls_script_dep.clone(),
+        env.pfls_script_dep.clone(),
+    ];
+    let headers: Vec<_> = ctx.headers.keys().cloned().collect();
+    let rtx = TransactionBuilder::default()
+        .inputs(inputs)
+        .witness(witness_args.as_bytes().pack())
+        .outputs(outputs.clone().into_iter().map(|o| o.0.clone()))
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .header_deps(headers)
+        .build();
+    let tx = ctx.complete_tx(rtx);
+    create_cells(ctx, tx.hash(), outputs.clone());
+    Ok(WithdrawResult {
+        channel_cell: OutPoint::new(tx.hash(), 1),
+        funds_cell: FundingCell::FundingCellCKBytes(FundingCellCKBytes {
+            index: args.party_index,
+            cap: remainder,
+            out_point: OutPoint::new(tx.hash(), 0),
+        }),
+        tx,
+    })
+}
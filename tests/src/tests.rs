@@ -7,9 +7,16 @@ use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::context::Context;
 use perun;
 use perun::test;
-use perun_common::helpers::blake2b256;
-use perun_common::perun_types::{Balances, Bool, ChannelState, SEC1EncodedPubKey, CKByteDistribution};
-use perun_common::sig::verify_signature;
+use perun_common::helpers::{
+    blake2b256, state_signing_digest, CHALLENGE_DURATION_METRIC_BLOCK_NUMBER,
+    CHALLENGE_DURATION_METRIC_EPOCH, CHALLENGE_DURATION_METRIC_TIMESTAMP, DUST_POLICY_COUNTERPARTY,
+    DUST_POLICY_FEE, DUST_POLICY_ROUND_UP,
+};
+use perun_common::perun_types::{CapacityRefund, RefundSponsor, SEC1EncodedPubKey};
+use perun_common::sig::{
+    verify_signature, SIG_SCHEME_ECDSA_RECOVERABLE, SIG_SCHEME_ETH_PERSONAL_SIGN,
+    SIG_SCHEME_SCHNORR,
+};
 
 const MAX_CYCLES: u64 = 10 * 10_000_000;
 const CHALLENGE_DURATION_MS: u64 = 10 * 1000;
@@ -35,20 +42,46 @@ fn test_signature() {
         .unwrap();
     SEC1EncodedPubKey::new_builder().set(pubkey_bytes).build();
 
-    let balances_array: [Uint64; 2] = [10u64.pack(), 11u64.pack()];
-    let balances = Balances::new_builder().ckbytes(CKByteDistribution::new_builder().set(balances_array).build()).build();
-    let channel_state = ChannelState::new_builder()
-        .channel_id(Byte32::zero())
-        .balances(balances)
-        .is_final(Bool::from_bool(true))
-        .version(10u64.pack())
-        .build();
-    let msg = channel_state.as_slice();
+    // The signature was generated on a channel state with channel_id zero, version 10, is_final
+    // set and the CKByte balances [10, 11], encoded in the former two-party layout, in which the
+    // CKByteDistribution was a fixed array of two Uint64. We pin that encoding here, so that this
+    // test keeps covering the interoperability of the signature scheme itself.
+    let msg_string = "610000001400000034000000540000005c000000000000000000000000000000000000000000000000000000000000000000000020000000\
+                      0c0000001c0000000a000000000000000b00000000000000040000000a000000000000000000000000";
+    let msg = hex::decode(msg_string).expect("decoding message");
+    let msg = msg.as_slice();
     let msg_hash = blake2b256(msg);
 
     verify_signature(&msg_hash, &sig_bytes, pubkey.as_slice()).expect("valid signature");
 }
 
+#[test]
+fn test_state_signing_digest() {
+    // Off-chain implementations have to produce the same digest for the signatures on channel
+    // states to be valid on-chain.
+    let state = perun_common::perun_types::ChannelState::new_builder()
+        .channel_id([0x11u8; 32].pack())
+        .version(10u64.pack())
+        .build();
+    let state_string = "710000001c0000003c00000060000000680000006d0000007100000011111111111111111111111111111111\
+                        111111111111111111111111111111112400000014000000180000001c000000200000000000000004000000\
+                        04000000040000000a00000000000000000000000000000000";
+    assert_eq!(hex::encode(state.as_slice()), state_string);
+    let vectors = [
+        (
+            [0x00u8; 32],
+            "16ad5c171f65c15e280a05a9a7dc5ab73f57b57d9158dd060fe7087c6aac97aa",
+        ),
+        (
+            [0xaau8; 32],
+            "64e00d08494c0135843ad902bf46c90cae6d5ce5768db95493181d66c5668ede",
+        ),
+    ];
+    for (pcts_hash, digest) in vectors {
+        assert_eq!(hex::encode(state_signing_digest(&pcts_hash, &state)), digest);
+    }
+}
+
 // TODO: Add mutator to channel state that can be passed to dispute, and close.
 #[test]
 fn channel_test_bench() -> Result<(), perun::Error> {
@@ -56,15 +89,57 @@ fn channel_test_bench() -> Result<(), perun::Error> {
         test_funding_abort,
         test_successful_funding_with_udt,
         test_successful_funding_without_udt,
+        test_joint_funding,
+        test_joint_funding_with_udt,
+        test_funding_order,
+        test_funding_deadline,
         test_early_force_close,
         test_close,
         test_force_close,
+        test_force_close_final_state,
+        test_dispute_final_state,
         test_multiple_disputes,
         test_multiple_disputes_same_version,
         test_multi_asset_payment,
         test_multi_asset_abort,
         test_multi_asset_abort_zero_sudt_balance,
         test_multi_asset_force_close,
+        test_multi_party_close,
+        test_multi_party_partial_funding_abort,
+        test_multi_party_force_close,
+        test_app_dispute_progression,
+        test_app_invalid_progression,
+        test_virtual_channel_force_close,
+        test_virtual_channel_outdated_sub_state,
+        test_close_with_locked_funds,
+        test_dispute_final_state_with_locked_funds,
+        test_deposit,
+        test_deposit_disputed_channel,
+        test_withdraw,
+        test_withdraw_disputed_channel,
+        test_checkpoint,
+        test_max_fee,
+        test_dispute_bond,
+        test_sudt_payout_capacity,
+        test_sudt_allocation,
+        test_dust_policy,
+        test_capacity_refund,
+        test_splice,
+        test_splice_with_udt,
+        test_rotation,
+        test_rotation_with_udt,
+        test_batch_close,
+        test_batch_fund_and_dispute,
+        test_delegate_dispute,
+        test_delegate_close,
+        test_tx_signature_dispute,
+        test_tx_signature_covers_witnesses,
+        test_sig_schemes_dispute,
+        test_sig_schemes_close,
+        test_force_close_since_metrics,
+        test_dispute_deadline,
+        test_htlc_force_close,
+        test_htlc_close,
     ]
     .iter()
     .map(|test| {
@@ -177,7 +252,10 @@ fn test_successful_funding_with_udt(
     })
 }
 
-fn test_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+fn test_joint_funding(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
@@ -189,59 +267,93 @@ fn test_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), pe
     );
     create_channel_test(context, env, &parts, |chan| {
         chan.with(alice)
-            .open(&funding_agreement)
+            .open_jointly(&funding_agreement)
+            .expect("opening channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(10)?.as_u64()));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A jointly funded channel has no funding phase, so it can neither be funded nor aborted.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open_jointly(&funding_agreement)
             .expect("opening channel");
 
         chan.with(bob)
+            .invalid()
             .fund(&funding_agreement)
             .expect("funding channel");
 
+        chan.assert();
+        Ok(())
+    })?;
+
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open_jointly(&funding_agreement)
+            .expect("opening channel");
+
         chan.with(alice)
-            .finalize()
-            .close()
-            .expect("closing channel");
+            .invalid()
+            .abort()
+            .expect("aborting channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-fn test_force_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+fn test_joint_funding_with_udt(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
         Capacity::bytes(100)?.as_u64(),
         Capacity::bytes(100)?.as_u64(),
     ];
-    let funding_agreement = test::FundingAgreement::new_with_capacities(
+    let asset_funding = [20u128, 30u128];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
     );
     create_channel_test(context, env, &parts, |chan| {
         chan.with(alice)
-            .open(&funding_agreement)
+            .open_jointly(&funding_agreement)
             .expect("opening channel");
 
-        chan.with(bob)
-            .fund(&funding_agreement)
-            .expect("funding channel");
-
-        chan.with(bob).dispute().expect("invalid channel dispute");
-
-        chan.delay(env.challenge_duration);
+        chan.update(pay_sudt(Direction::BtoA, 10, 0));
 
-        chan.with(bob).force_close().expect("force closing channel");
+        chan.finalize();
+        chan.with(bob).close().expect("closing channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-fn test_early_force_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
-    let (alice, bob) = ("alice", "bob");
-    let parts = [random::account(alice), random::account(bob)];
+fn test_funding_order(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob, carol) = ("alice", "bob", "carol");
+    let parts = [random::account(alice), random::account(bob), random::account(carol)];
     let funding = [
         Capacity::bytes(100)?.as_u64(),
         Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
     ];
     let funding_agreement = test::FundingAgreement::new_with_capacities(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
@@ -251,60 +363,140 @@ fn test_early_force_close(context: &mut Context, env: &perun::harness::Env) -> R
             .open(&funding_agreement)
             .expect("opening channel");
 
+        // The parties may fund in any order.
+        chan.with(carol)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
         chan.with(bob)
             .fund(&funding_agreement)
             .expect("funding channel");
 
-        chan.with(bob).dispute().expect("invalid channel dispute");
+        chan.update(pay_ckbytes(Direction::FromTo(2, 0), 50));
 
-        chan.with(bob).invalid().force_close().expect("force closing channel");
+        chan.with(bob).finalize().close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Each party funds only once.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(carol)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(carol)
+            .invalid()
+            .fund(&funding_agreement)
+            .expect("funding channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-fn test_multiple_disputes_same_version(
+fn test_funding_deadline(
     context: &mut Context,
     env: &perun::harness::Env,
 ) -> Result<(), perun::Error> {
-    let (alice, bob) = ("alice", "bob");
-    let parts = [random::account(alice), random::account(bob)];
+    let (alice, bob, carol) = ("alice", "bob", "carol");
+    let parts = [random::account(alice), random::account(bob), random::account(carol)];
     let funding = [
         Capacity::bytes(100)?.as_u64(),
         Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
     ];
     let funding_agreement = test::FundingAgreement::new_with_capacities(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
     );
+    let deadline = env.challenge_duration;
+
+    // Before the deadline, the channel can be funded, but not aborted.
     create_channel_test(context, env, &parts, |chan| {
-        chan.with(alice)
+        chan.with_funding_deadline(deadline)
+            .with(alice)
             .open(&funding_agreement)
             .expect("opening channel");
 
+        chan.delay(deadline / 2);
+
         chan.with(bob)
             .fund(&funding_agreement)
             .expect("funding channel");
 
         chan.with(alice)
-            .valid()
-            .dispute()
-            .expect("disputing channel");
+            .invalid()
+            .abort()
+            .expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Once the deadline has passed, the channel can be aborted.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with_funding_deadline(deadline)
+            .with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.delay(deadline);
+
+        chan.with(alice).abort().expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Funding after the deadline can not be rejected, if the channel cell was created before it. The
+    // channel can still be aborted afterwards.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with_funding_deadline(deadline)
+            .with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.delay(deadline);
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel after the deadline");
+
+        chan.with(alice).abort().expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Funding is rejected once the channel cell was created after the deadline.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with_funding_deadline(deadline)
+            .with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.delay(deadline);
 
         chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel after the deadline");
+
+        chan.with(carol)
             .invalid()
-            .dispute()
-            .expect("disputing channel");
+            .fund(&funding_agreement)
+            .expect("funding channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-fn test_multiple_disputes(
-    context: &mut Context,
-    env: &perun::harness::Env,
-) -> Result<(), perun::Error> {
+fn test_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
@@ -324,40 +516,24 @@ fn test_multiple_disputes(
             .expect("funding channel");
 
         chan.with(alice)
-            .valid()
-            .dispute()
-            .expect("disputing channel");
-
-        chan.with(bob)
-            .valid()
-            .update(bump_version())
-            .dispute()
-            .expect("disputing channel");
+            .finalize()
+            .close()
+            .expect("closing channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-fn test_multi_asset_payment(
-    context: &mut Context,
-    env: &perun::harness::Env,
-) -> Result<(), perun::Error> {
+fn test_force_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
         Capacity::bytes(100)?.as_u64(),
         Capacity::bytes(100)?.as_u64(),
     ];
-    let asset_funding = [
-        20u128,
-        30u128,
-    ];
-    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
-        &env.sample_udt_script,
-        env.sample_udt_max_cap.as_u64(),
-        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
     );
     create_channel_test(context, env, &parts, |chan| {
         chan.with(alice)
@@ -368,99 +544,103 @@ fn test_multi_asset_payment(
             .fund(&funding_agreement)
             .expect("funding channel");
 
-        chan.update(pay_ckbytes(Direction::AtoB, 50));
-        chan.update(pay_sudt(Direction::BtoA, 10, 0));
+        chan.with(bob).dispute().expect("invalid channel dispute");
 
-        chan.with(alice).finalize().close().expect("closing channel");
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-pub fn test_multi_asset_abort(
+fn test_force_close_final_state(
     context: &mut Context,
     env: &perun::harness::Env,
 ) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
-        Capacity::bytes(0)?.as_u64(),
-        Capacity::bytes(0)?.as_u64(),
-    ];
-    let asset_funding = [
-        30u128,
-        20u128,
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
     ];
-    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
-        &env.sample_udt_script,
-        env.sample_udt_max_cap.as_u64(),
-        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
     );
     create_channel_test(context, env, &parts, |chan| {
         chan.with(alice)
             .open(&funding_agreement)
             .expect("opening channel");
 
-        chan.with(alice).abort().expect("aborting channel");
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+
+        chan.with(bob)
+            .finalize()
+            .dispute()
+            .expect("disputing channel");
+
+        // No later state can exist, so the final state is settled without waiting for the
+        // challenge duration.
+        chan.with(bob).force_close().expect("force closing channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-pub fn test_multi_asset_abort_zero_sudt_balance(
+fn test_dispute_final_state(
     context: &mut Context,
     env: &perun::harness::Env,
 ) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
-        Capacity::bytes(0)?.as_u64(),
-        Capacity::bytes(0)?.as_u64(),
-    ];
-    let asset_funding = [
-        0u128,
-        0u128,
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
     ];
-    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
-        &env.sample_udt_script,
-        env.sample_udt_max_cap.as_u64(),
-        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
     );
     create_channel_test(context, env, &parts, |chan| {
         chan.with(alice)
             .open(&funding_agreement)
             .expect("opening channel");
 
-        chan.with(alice).abort().expect("aborting channel");
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob)
+            .finalize()
+            .dispute()
+            .expect("disputing channel");
+
+        // A disputed final state can not be refuted.
+        chan.with(alice)
+            .update(bump_version())
+            .invalid()
+            .dispute()
+            .expect("disputing channel");
 
         chan.assert();
         Ok(())
     })
 }
 
-fn test_multi_asset_force_close(
-    context: &mut Context,
-    env: &perun::harness::Env,
-) -> Result<(), perun::Error> {
+fn test_early_force_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
     let (alice, bob) = ("alice", "bob");
     let parts = [random::account(alice), random::account(bob)];
     let funding = [
         Capacity::bytes(100)?.as_u64(),
         Capacity::bytes(100)?.as_u64(),
     ];
-    let asset_funding = [
-        20u128,
-        30u128,
-    ];
-    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
         parts.iter().cloned().zip(funding.iter().cloned()).collect(),
-        &env.sample_udt_script,
-        env.sample_udt_max_cap.as_u64(),
-        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
     );
     create_channel_test(context, env, &parts, |chan| {
         chan.with(alice)
@@ -471,14 +651,2626 @@ fn test_multi_asset_force_close(
             .fund(&funding_agreement)
             .expect("funding channel");
 
-        chan.update(pay_ckbytes(Direction::AtoB, 50));
-        chan.update(pay_sudt(Direction::BtoA, 10, 0));
+        chan.with(bob).dispute().expect("invalid channel dispute");
 
-        chan.with(bob).dispute().expect("disputing channel");
-        
-        chan.delay(env.challenge_duration);
+        chan.with(bob).invalid().force_close().expect("force closing channel");
 
-        chan.with(bob).force_close().expect("force closing channel");
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multiple_disputes_same_version(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice)
+            .valid()
+            .dispute()
+            .expect("disputing channel");
+
+        chan.with(bob)
+            .invalid()
+            .dispute()
+            .expect("disputing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multiple_disputes(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice)
+            .valid()
+            .dispute()
+            .expect("disputing channel");
+
+        chan.with(bob)
+            .valid()
+            .update(bump_version())
+            .dispute()
+            .expect("disputing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multi_asset_payment(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let asset_funding = [
+        20u128,
+        30u128,
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+        chan.update(pay_sudt(Direction::BtoA, 10, 0));
+
+        chan.with(alice).finalize().close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+pub fn test_multi_asset_abort(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(0)?.as_u64(),
+        Capacity::bytes(0)?.as_u64(),
+    ];
+    let asset_funding = [
+        30u128,
+        20u128,
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(alice).abort().expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+pub fn test_multi_asset_abort_zero_sudt_balance(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(0)?.as_u64(),
+        Capacity::bytes(0)?.as_u64(),
+    ];
+    let asset_funding = [
+        0u128,
+        0u128,
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(alice).abort().expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multi_asset_force_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let asset_funding = [
+        20u128,
+        30u128,
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+        chan.update(pay_sudt(Direction::BtoA, 10, 0));
+
+        chan.with(bob).dispute().expect("disputing channel");
+        
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multi_party_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob, carol) = ("alice", "bob", "carol");
+    let parts = [random::account(alice), random::account(bob), random::account(carol)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        // Parties fund one after another.
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(carol)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::FromTo(0, 2), 50));
+        chan.update(pay_ckbytes(Direction::FromTo(2, 1), 20));
+
+        chan.with(carol).finalize().close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multi_party_partial_funding_abort(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob, carol) = ("alice", "bob", "carol");
+    let parts = [random::account(alice), random::account(bob), random::account(carol)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let asset_funding = [
+        20u128,
+        30u128,
+        40u128,
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        // Carol never funds, so alice and bob get their funds back.
+        chan.with(bob).abort().expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_multi_party_force_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob, carol, dave) = ("alice", "bob", "carol", "dave");
+    let parts = [
+        random::account(alice),
+        random::account(bob),
+        random::account(carol),
+        random::account(dave),
+    ];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(0)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        // Carol has nothing to fund and is considered funded from the start.
+        chan.with(dave)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::FromTo(3, 2), 70));
+
+        chan.with(carol).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(dave).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_app_dispute_progression(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let app = env.sample_tic_tac_toe_app(context);
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open_with_app(&funding_agreement, app.clone(), tic_tac_toe_start())
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        // The first moves are signed by both parties off-chain.
+        chan.update(tic_tac_toe_move(0, 4));
+        chan.update(tic_tac_toe_move(1, 0));
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        // Once disputed, the game continues on-chain with moves signed only by the acting party.
+        chan.with(alice)
+            .update(tic_tac_toe_move(0, 2))
+            .progress()
+            .expect("progressing channel");
+
+        chan.with(bob)
+            .update(tic_tac_toe_move(1, 8))
+            .progress()
+            .expect("progressing channel");
+
+        // Alice wins and receives all balances.
+        chan.with(alice)
+            .update(tic_tac_toe_move(0, 6))
+            .progress()
+            .expect("progressing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_app_invalid_progression(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let app = env.sample_tic_tac_toe_app(context);
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open_with_app(&funding_agreement, app.clone(), tic_tac_toe_start())
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(tic_tac_toe_move(0, 4));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        // It is bob's turn, so alice can not progress the channel.
+        chan.with(alice)
+            .update(tic_tac_toe_move(0, 0))
+            .invalid()
+            .progress()
+            .expect("progressing channel out of turn");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_virtual_channel_force_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, ingrid, bob) = ("alice", "ingrid", "bob");
+    let (acc_alice, acc_ingrid, acc_bob) = (
+        random::account(alice),
+        random::account(ingrid),
+        random::account(bob),
+    );
+    // The virtual channel between alice and bob is funded by the ledger channels alice-ingrid and
+    // ingrid-bob. In each ledger channel, ingrid stands in for the party that is not part of it.
+    let virtual_channel =
+        test::VirtualChannel::new(context, env, &[acc_alice.clone(), acc_bob.clone()]);
+    let initial = virtual_channel.sign(virtual_channel.mk_state(
+        0,
+        vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+    ))?;
+    let latest = virtual_channel.sign(virtual_channel.mk_state(
+        1,
+        vec![Capacity::bytes(20)?.as_u64(), Capacity::bytes(60)?.as_u64()],
+    ))?;
+    let contributions = vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+
+    let parts = [acc_alice.clone(), acc_ingrid.clone()];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(ingrid)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(lock_sub_alloc(virtual_channel.id(), contributions.clone(), vec![0, 1]));
+
+        chan.with(alice)
+            .with_sub_channel(initial.clone())
+            .dispute()
+            .expect("disputing channel");
+
+        // A newer state of the virtual channel can be registered with a later dispute, which keeps
+        // the registered state of the ledger channel.
+        chan.with(ingrid)
+            .with_sub_channel(latest.clone())
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        // Alice receives 100 - 50 + 20, ingrid receives 100 - 30 + 60.
+        chan.with(alice).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    let parts = [acc_ingrid.clone(), acc_bob.clone()];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(ingrid)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(lock_sub_alloc(virtual_channel.id(), contributions.clone(), vec![0, 1]));
+
+        chan.with(bob)
+            .with_sub_channel(latest.clone())
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        // Ingrid receives 100 - 50 + 20, bob receives 100 - 30 + 60.
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_virtual_channel_outdated_sub_state(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, ingrid, bob) = ("alice", "ingrid", "bob");
+    let parts = [random::account(alice), random::account(ingrid)];
+    let virtual_channel =
+        test::VirtualChannel::new(context, env, &[parts[0].clone(), random::account(bob)]);
+    let initial = virtual_channel.sign(virtual_channel.mk_state(
+        0,
+        vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+    ))?;
+    let latest = virtual_channel.sign(virtual_channel.mk_state(
+        1,
+        vec![Capacity::bytes(20)?.as_u64(), Capacity::bytes(60)?.as_u64()],
+    ))?;
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(ingrid)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(lock_sub_alloc(
+            virtual_channel.id(),
+            vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+            vec![0, 1],
+        ));
+
+        chan.with(ingrid)
+            .with_sub_channel(latest.clone())
+            .dispute()
+            .expect("disputing channel");
+
+        // Alice can not replace the registered state of the virtual channel with an older one.
+        chan.with(alice)
+            .update(bump_version())
+            .with_sub_channel(initial.clone())
+            .invalid()
+            .dispute()
+            .expect("disputing channel with outdated sub-channel state");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(ingrid)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(lock_sub_alloc(
+            virtual_channel.id(),
+            vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+            vec![0, 1],
+        ));
+
+        chan.with(ingrid)
+            .with_sub_channel(latest.clone())
+            .dispute()
+            .expect("disputing channel");
+
+        // This also holds if alice keeps the registered state of the ledger channel.
+        chan.with(alice)
+            .with_sub_channel(initial.clone())
+            .invalid()
+            .dispute()
+            .expect("disputing channel with outdated sub-channel state");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_close_with_locked_funds(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, ingrid, bob) = ("alice", "ingrid", "bob");
+    let parts = [random::account(alice), random::account(ingrid)];
+    let virtual_channel =
+        test::VirtualChannel::new(context, env, &[parts[0].clone(), random::account(bob)]);
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(ingrid)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(lock_sub_alloc(
+            virtual_channel.id(),
+            vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+            vec![0, 1],
+        ));
+
+        // Funds locked for a virtual channel can not be payed out by a cooperative close.
+        chan.finalize();
+        chan.with(alice)
+            .invalid()
+            .close()
+            .expect("closing channel with locked funds");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_dispute_final_state_with_locked_funds(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, ingrid, bob) = ("alice", "ingrid", "bob");
+    let parts = [random::account(alice), random::account(ingrid)];
+    let virtual_channel =
+        test::VirtualChannel::new(context, env, &[parts[0].clone(), random::account(bob)]);
+    let initial = virtual_channel.sign(virtual_channel.mk_state(
+        0,
+        vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+    ))?;
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(ingrid)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(lock_sub_alloc(
+            virtual_channel.id(),
+            vec![Capacity::bytes(50)?.as_u64(), Capacity::bytes(30)?.as_u64()],
+            vec![0, 1],
+        ));
+
+        // A disputed final state is settled at once, so it must not lock funds for a virtual
+        // channel, whose registered state could otherwise not be refuted anymore.
+        chan.finalize();
+        chan.with(alice)
+            .with_sub_channel(initial.clone())
+            .invalid()
+            .dispute()
+            .expect("disputing final state with locked funds");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_deposit(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(50)?.as_u64()));
+
+        // Both parties top up the running channel, which has progressed off-chain in the meantime.
+        chan.with(bob)
+            .deposit(Capacity::bytes(200)?.as_u64())
+            .expect("depositing funds");
+        chan.with(alice)
+            .deposit(Capacity::bytes(50)?.as_u64())
+            .expect("depositing funds");
+
+        chan.update(pay_ckbytes(Direction::BtoA, Capacity::bytes(250)?.as_u64()));
+
+        chan.finalize();
+        chan.with(alice).close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_deposit_disputed_channel(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        // A disputed channel can not be topped up anymore.
+        chan.with(bob)
+            .invalid()
+            .deposit(Capacity::bytes(100)?.as_u64())
+            .expect("depositing funds into disputed channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_withdraw(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        // Bob takes part of his balance out, the channel stays open.
+        chan.with(bob)
+            .withdraw(Capacity::bytes(250)?.as_u64())
+            .expect("withdrawing funds");
+
+        chan.update(pay_ckbytes(Direction::BtoA, Capacity::bytes(50)?.as_u64()));
+
+        chan.with(alice)
+            .withdraw(Capacity::bytes(100)?.as_u64())
+            .expect("withdrawing funds");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(50)?.as_u64()));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_withdraw_disputed_channel(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        // Funds can not be withdrawn from a disputed channel, it can only be closed.
+        chan.with(bob)
+            .invalid()
+            .withdraw(Capacity::bytes(100)?.as_u64())
+            .expect("withdrawing funds from disputed channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_checkpoint(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(alice)
+            .checkpoint()
+            .expect("checkpointing channel");
+
+        chan.update(pay_ckbytes(Direction::BtoA, Capacity::bytes(50)?.as_u64()));
+
+        chan.with(bob)
+            .checkpoint()
+            .expect("checkpointing channel again");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(20)?.as_u64()));
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Once a state is checkpointed, a dispute has to register a newer state.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(alice)
+            .checkpoint()
+            .expect("checkpointing channel");
+
+        chan.with(bob)
+            .invalid()
+            .dispute()
+            .expect("invalid dispute with checkpointed state");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A checkpoint has to register a newer state, even if the channel is still in its initial state.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice)
+            .invalid()
+            .checkpoint()
+            .expect("invalid checkpoint of the initial state");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A disputed channel can not be checkpointed.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(bob)
+            .invalid()
+            .checkpoint()
+            .expect("invalid checkpoint of disputed channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_max_fee(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let max_fee = Capacity::bytes(2)?.as_u64();
+    // The dispute and the force close both pay their fee from the channel funds.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_max_fee(max_fee)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(bob)
+            .with_fee(Capacity::bytes(1)?.as_u64())
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A force close can not pay more than the fee budget from the channel funds.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_max_fee(max_fee)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob)
+            .with_fee(Capacity::bytes(3)?.as_u64())
+            .invalid()
+            .force_close()
+            .expect("invalid force close exceeding the fee budget");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The disputes and the force close of a channel share the fee budget. Once a dispute has spent
+    // it, the force close can not pay any fee from the channel funds.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_max_fee(max_fee)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob)
+            .with_fee(max_fee)
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob)
+            .with_fee(Capacity::bytes(1)?.as_u64())
+            .invalid()
+            .force_close()
+            .expect("invalid force close exceeding the fee budget");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A refutation can not pay more than what the first dispute has left of the fee budget, even
+    // if the channel cell holds the dispute bond.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_max_fee(max_fee)
+            .with_dispute_bond(Capacity::bytes(20)?.as_u64())
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob)
+            .with_fee(max_fee)
+            .dispute()
+            .expect("disputing channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(alice)
+            .invalid()
+            .dispute()
+            .expect("invalid refutation exceeding the fee budget");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_dispute_bond(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let bond = Capacity::bytes(10)?.as_u64();
+    // An unrefuted dispute returns the bond to the disputing party.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dispute_bond(bond)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A dispute with a stale state loses the bond to the counterparty, which registers the newer state.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dispute_bond(bond)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.update(pay_ckbytes(Direction::BtoA, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(alice).dispute().expect("refuting channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A party refuting its own stale state keeps the bond, as it also signed the newer state.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dispute_bond(bond)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(bob).dispute().expect("refuting channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice)
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A refutation can not pass the bond to a party that did not submit it.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dispute_bond(bond)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        chan.with(bob)
+            .with_bond_holder(0)
+            .invalid()
+            .dispute()
+            .expect("invalid refutation passing the bond to alice");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A unilateral progression of an app channel passes the bond to the acting party only.
+    let app = env.sample_tic_tac_toe_app(context);
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dispute_bond(bond)
+            .open_with_app(&funding_agreement, app.clone(), tic_tac_toe_start())
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(tic_tac_toe_move(0, 4));
+        chan.update(tic_tac_toe_move(1, 0));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.with(alice)
+            .update(tic_tac_toe_move(0, 2))
+            .progress()
+            .expect("progressing channel");
+
+        chan.with(bob)
+            .update(tic_tac_toe_move(1, 8))
+            .with_bond_holder(0)
+            .invalid()
+            .progress()
+            .expect("invalid progression passing the bond to alice");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The first dispute has to lock the bond in the channel cell.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dispute_bond(bond)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob)
+            .with_dispute_bond(bond / 2)
+            .invalid()
+            .dispute()
+            .expect("invalid dispute without the bond");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_sudt_payout_capacity(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(0)?.as_u64(),
+    ];
+    let asset_funding = [
+        20u128,
+        30u128,
+    ];
+    // The max_capacity of the asset is the least one accepted upon start, which just covers an sUDT cell
+    // payed to the parties. Bob, who only holds sUDTs, is payed in such a cell.
+    let max_cap = env.min_sudt_max_capacity()?;
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_sudt(Direction::AtoB, 10, 0));
+
+        chan.with(alice).finalize().close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Every sUDT payout has to carry the max_capacity of its asset.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_sudt(Direction::AtoB, 10, 0));
+
+        chan.with(alice)
+            .with_skimmed_sudt_payouts(Capacity::bytes(1)?.as_u64())
+            .finalize()
+            .invalid()
+            .close()
+            .expect("invalid close skimming sUDT payouts");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_sudt_allocation(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let asset_funding = [
+        20u128,
+        30u128,
+    ];
+    let max_cap = env.sample_udt_max_cap.as_u64();
+
+    // An asset must not appear twice in the balances.
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudts(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        vec![
+            (env.sample_udt_script.clone(), max_cap),
+            (env.sample_udt_script.clone(), max_cap),
+        ],
+        parts
+            .iter()
+            .cloned()
+            .zip(asset_funding.iter().map(|amt| vec![*amt, *amt]))
+            .collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .invalid()
+            .open(&funding_agreement)
+            .expect("invalid open with duplicate asset");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The max_capacity of an asset has to cover an sUDT payout to each party.
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.min_sudt_max_capacity()?.as_u64() - 1,
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .invalid()
+            .open(&funding_agreement)
+            .expect("invalid open with too small max_capacity");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The number of assets must not exceed the limit of the channel.
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        max_cap,
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_max_sudt_assets(0)
+            .invalid()
+            .open(&funding_agreement)
+            .expect("invalid open with too many assets");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_dust_policy(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    // Bob ends up with a balance below his payment_min_capacity.
+    let payment = Capacity::bytes(99)?.as_u64();
+
+    // The dust of bob is payed to alice.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dust_policy(DUST_POLICY_COUNTERPARTY)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::BtoA, payment));
+
+        chan.with(alice).finalize().close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The payout of bob is rounded up from the channel capacity.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dust_policy(DUST_POLICY_ROUND_UP)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::BtoA, payment));
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice).force_close().expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A channel can not be opened with an unknown dust policy.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_dust_policy(DUST_POLICY_ROUND_UP + 1)
+            .invalid()
+            .open(&funding_agreement)
+            .expect("invalid open with unknown dust policy");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_capacity_refund(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let sponsor_refund = env.sponsor_refund(context);
+    // A sponsor with the payment address of alice.
+    let alice_payment_lock = env.build_lock_script(context, Bytes::from(vec![0u8]));
+    let alice_sponsor_refund = CapacityRefund::new_builder()
+        .set(RefundSponsor::from_slice(
+            alice_payment_lock.calc_script_hash().as_slice(),
+        )?)
+        .build();
+
+    // The channel capacity is refunded to bob.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_capacity_refund(perun::harness::party_refund(1))
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+
+        chan.with(alice).finalize().close().expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The channel capacity is refunded to a sponsor, which pays the fee of the force close.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_capacity_refund(sponsor_refund.clone())
+            .with_max_fee(Capacity::bytes(2)?.as_u64())
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob)
+            .with_fee(Capacity::bytes(1)?.as_u64())
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The channel capacity of an aborted channel is refunded to the sponsor.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_capacity_refund(sponsor_refund.clone())
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(alice).abort().expect("aborting channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The channel capacity can only be refunded to a party of the channel.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_capacity_refund(perun::harness::party_refund(2))
+            .invalid()
+            .open(&funding_agreement)
+            .expect("invalid open refunding an unknown party");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A sponsor must not share the payment address of a party.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .with_capacity_refund(alice_sponsor_refund.clone())
+            .invalid()
+            .open(&funding_agreement)
+            .expect("invalid open refunding a sponsor with the payment address of a party");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_splice(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        // Bob resizes the channel by adding funds to the successor in the splice transaction.
+        chan.with(bob)
+            .splice(&funding_agreement, Capacity::bytes(200)?.as_u64())
+            .expect("splicing channel");
+
+        chan.update(pay_ckbytes(Direction::BtoA, Capacity::bytes(150)?.as_u64()));
+
+        chan.with(alice).finalize().close().expect("closing successor");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_splice_with_udt(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let asset_funding = [20u128, 30u128];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_sudt(Direction::BtoA, 10, 0));
+
+        chan.with(alice)
+            .splice(&funding_agreement, 0)
+            .expect("splicing channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+
+        chan.with(bob).dispute().expect("disputing successor");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice).force_close().expect("force closing successor");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_rotation(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(100)?.as_u64()));
+
+        // Bob replaces his key. The channel continues with the same state.
+        chan.with(bob)
+            .rotate(bob, &random::account(bob))
+            .expect("rotating bob's key");
+
+        chan.update(pay_ckbytes(Direction::BtoA, Capacity::bytes(50)?.as_u64()));
+
+        // Alice switches to another signature scheme with a new key.
+        chan.with(alice)
+            .rotate(
+                alice,
+                &random::account(alice).with_sig_scheme(SIG_SCHEME_SCHNORR),
+            )
+            .expect("rotating alice's key");
+
+        chan.update(pay_ckbytes(Direction::AtoB, Capacity::bytes(20)?.as_u64()));
+
+        chan.with(alice)
+            .dispute()
+            .expect("disputing rotated channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob)
+            .force_close()
+            .expect("force closing rotated channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A rotation has to be signed by all participants of the channel.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(bob)
+            .invalid()
+            .rotate_signed_by(bob, &random::account(bob), &[bob.to_string()])
+            .expect("invalid rotation without alice's signature");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // A disputed channel can only be closed.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.with(bob)
+            .invalid()
+            .rotate(bob, &random::account(bob))
+            .expect("invalid rotation of disputed channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_rotation_with_udt(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let asset_funding = [20u128, 30u128];
+    let funding_agreement = test::FundingAgreement::new_with_capacities_and_sudt(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+        &env.sample_udt_script,
+        env.sample_udt_max_cap.as_u64(),
+        parts.iter().cloned().zip(asset_funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_sudt(Direction::BtoA, 10, 0));
+
+        chan.with(alice)
+            .rotate(alice, &random::account(alice))
+            .expect("rotating alice's key");
+
+        chan.update(pay_sudt(Direction::AtoB, 5, 0));
+
+        chan.with(bob)
+            .finalize()
+            .close()
+            .expect("closing rotated channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_batch_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let clients: Vec<_> = parts
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            test::Client::new(i as u8, p.name.clone(), p.sk.clone()).with_sig_scheme(p.sig_scheme)
+        })
+        .collect();
+
+    // Open two channels between the same parties.
+    let mut closes = Vec::new();
+    for _ in 0..2 {
+        let (cid, or) = clients[0].open(
+            context,
+            env,
+            &funding_agreement,
+            Default::default(),
+            Bytes::new(),
+            CHALLENGE_DURATION_METRIC_TIMESTAMP,
+            None,
+            0,
+            None,
+            DUST_POLICY_FEE,
+            perun::harness::MAX_SUDT_ASSETS,
+            Default::default(),
+        )?;
+        let fr = clients[1].fund(
+            context,
+            env,
+            cid,
+            &funding_agreement,
+            or.channel_cell.clone(),
+            or.state.clone(),
+            or.pcts.clone(),
+        )?;
+        let old_version: u64 = fr.state.state().version().unpack();
+        let state = fr
+            .state
+            .state()
+            .as_builder()
+            .is_final(perun_common::ctrue!())
+            .version((old_version + 1).pack())
+            .build();
+        let pcts_hash = or.pcts.calc_script_hash();
+        let sigs = clients
+            .iter()
+            .map(|c| c.sign(&pcts_hash, state.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut funds_cells = or.funds_cells.clone();
+        funds_cells.extend(fr.funds_cells.iter().cloned());
+        closes.push(test::transaction::CloseArgs {
+            channel_cell: fr.channel_cell.clone(),
+            funds_cells,
+            state: fr.state.as_builder().state(state).build(),
+            sigs,
+            htlcs: Default::default(),
+            party_index: 0,
+            skim: 0,
+        });
+    }
+
+    // Untagged payouts can not be attributed to either channel, as both pay the same parties.
+    assert!(clients[0]
+        .batch_close(context, env, closes.clone(), false)
+        .is_err());
+
+    clients[0].batch_close(context, env, closes, true)?;
+    Ok(())
+}
+
+fn test_batch_fund_and_dispute(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(300)?.as_u64(),
+        Capacity::bytes(300)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let clients: Vec<_> = parts
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            test::Client::new(i as u8, p.name.clone(), p.sk.clone()).with_sig_scheme(p.sig_scheme)
+        })
+        .collect();
+
+    // Open two channels between the same parties, which bob funds in a single transaction. His change
+    // output is untagged, as only the funds locked to the pfls of each channel are attributed to it.
+    let mut funds = Vec::new();
+    for _ in 0..2 {
+        let (_, or) = clients[0].open(
+            context,
+            env,
+            &funding_agreement,
+            Default::default(),
+            Bytes::new(),
+            CHALLENGE_DURATION_METRIC_TIMESTAMP,
+            None,
+            0,
+            None,
+            DUST_POLICY_FEE,
+            perun::harness::MAX_SUDT_ASSETS,
+            Default::default(),
+        )?;
+        funds.push(test::transaction::FundArgs {
+            channel_cell: or.channel_cell.clone(),
+            funding_agreement: funding_agreement.clone(),
+            party_index: 1,
+            inputs: env.create_funds_from_agreement(context, 1, &funding_agreement)?,
+            pcts: or.pcts.clone(),
+            state: or.state.clone(),
+        });
+    }
+    let bfr = clients[1].batch_fund(context, env, funds.clone())?;
+
+    // Alice disputes both channels in a single transaction, paying its fees from an untagged input.
+    let mut disputes = Vec::new();
+    for ((channel_cell, state), f) in bfr.channel_cells.iter().zip(bfr.states.iter()).zip(funds) {
+        let state = state
+            .clone()
+            .as_builder()
+            .disputed(perun_common::ctrue!())
+            .build();
+        let pcts_hash = f.pcts.calc_script_hash();
+        let sigs = clients
+            .iter()
+            .map(|c| c.sign(&pcts_hash, state.state()))
+            .collect::<Result<Vec<_>, _>>()?;
+        disputes.push(test::transaction::DisputeArgs {
+            channel_cell: channel_cell.clone(),
+            state,
+            sigs,
+            pcts_script: f.pcts,
+            party_index: 0,
+            sub_channels: vec![],
+            fee: 0,
+            bond: 0,
+        });
+    }
+    clients[0].batch_dispute(context, env, disputes)?;
+    Ok(())
+}
+
+fn test_delegate_dispute(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+
+        // Bob is offline, his watchtower disputes and force closes the channel on his behalf.
+        chan.with_delegate_of(bob)
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with_delegate_of(bob)
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_delegate_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        // Delegates may not close the channel, even with a final state signed by all parties.
+        chan.with_delegate_of(bob)
+            .invalid()
+            .finalize()
+            .close()
+            .expect("closing channel as delegate");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_tx_signature_dispute(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+
+        // Bob only signs the transactions, an outside party pays the fees.
+        chan.with_signature_of(bob)
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with_signature_of(bob)
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_tx_signature_covers_witnesses(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let clients: Vec<_> = parts
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            test::Client::new(i as u8, p.name.clone(), p.sk.clone()).with_sig_scheme(p.sig_scheme)
+        })
+        .collect();
+    let (cid, or) = clients[0].open(
+        context,
+        env,
+        &funding_agreement,
+        Default::default(),
+        Bytes::new(),
+        CHALLENGE_DURATION_METRIC_TIMESTAMP,
+        None,
+        0,
+        None,
+        DUST_POLICY_FEE,
+        perun::harness::MAX_SUDT_ASSETS,
+        Default::default(),
+    )?;
+    let fr = clients[1].fund(
+        context,
+        env,
+        cid,
+        &funding_agreement,
+        or.channel_cell.clone(),
+        or.state.clone(),
+        or.pcts.clone(),
+    )?;
+    let state = fr
+        .state
+        .clone()
+        .as_builder()
+        .disputed(perun_common::ctrue!())
+        .build();
+    let pcts_hash = or.pcts.calc_script_hash();
+    let sigs = clients
+        .iter()
+        .map(|c| c.sign(&pcts_hash, state.state()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Bob authorizes the dispute by a signature, an outside party pays the fees.
+    let dr = clients[1].signing_txs().dispute(
+        context,
+        env,
+        cid,
+        fr.channel_cell.clone(),
+        state,
+        or.pcts.clone(),
+        sigs,
+        vec![],
+        0,
+        0,
+    )?;
+
+    // The signature covers the witness of the channel cell, which can not be changed afterwards.
+    let mut witnesses: Vec<Bytes> = dr
+        .tx
+        .witnesses()
+        .into_iter()
+        .map(|w| w.raw_data())
+        .collect();
+    witnesses[0] = WitnessArgs::from_slice(&witnesses[0])?
+        .as_builder()
+        .output_type(Some(Bytes::from(vec![0u8])).pack())
+        .build()
+        .as_bytes();
+    let tx = dr
+        .tx
+        .as_advanced_builder()
+        .set_witnesses(witnesses.iter().map(|w| w.pack()).collect())
+        .build();
+    assert!(context.verify_tx(&tx, env.max_cycles).is_err());
+    Ok(())
+}
+
+fn test_sig_schemes_dispute(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [
+        random::account(alice).with_sig_scheme(SIG_SCHEME_SCHNORR),
+        random::account(bob).with_sig_scheme(SIG_SCHEME_ETH_PERSONAL_SIGN),
+    ];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::AtoB, 50));
+
+        // Bob also authorizes the dispute transaction using his Ethereum wallet.
+        chan.with_signature_of(bob)
+            .dispute()
+            .expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice)
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_sig_schemes_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [
+        random::account(alice).with_sig_scheme(SIG_SCHEME_ECDSA_RECOVERABLE),
+        random::account(bob),
+    ];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(pay_ckbytes(Direction::BtoA, 25));
+
+        chan.with(alice)
+            .finalize()
+            .close()
+            .expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_force_close_since_metrics(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let metrics = [
+        CHALLENGE_DURATION_METRIC_TIMESTAMP,
+        CHALLENGE_DURATION_METRIC_BLOCK_NUMBER,
+        CHALLENGE_DURATION_METRIC_EPOCH,
+    ];
+    for metric in metrics {
+        create_channel_test(context, env, &parts, |chan| {
+            chan.with_challenge_duration_metric(metric)
+                .with(alice)
+                .open(&funding_agreement)
+                .expect("opening channel");
+
+            chan.with(bob)
+                .fund(&funding_agreement)
+                .expect("funding channel");
+
+            chan.with(bob).dispute().expect("disputing channel");
+
+            chan.delay(env.challenge_duration);
+
+            chan.with(bob).force_close().expect("force closing channel");
+
+            chan.assert();
+            Ok(())
+        })?;
+
+        create_channel_test(context, env, &parts, |chan| {
+            chan.with_challenge_duration_metric(metric)
+                .with(alice)
+                .open(&funding_agreement)
+                .expect("opening channel");
+
+            chan.with(bob)
+                .fund(&funding_agreement)
+                .expect("funding channel");
+
+            chan.with(bob).dispute().expect("disputing channel");
+
+            // The since of the channel cell has not yet matured for the challenge duration. For
+            // epochs, the challenge duration is a number of epochs.
+            chan.delay(env.challenge_duration - 1000);
+
+            chan.with(bob)
+                .invalid()
+                .force_close()
+                .expect("force closing channel");
+
+            chan.assert();
+            Ok(())
+        })?;
+    }
+
+    // Points in time measured in epochs are full epoch values. A plain number has an epoch length
+    // of 0 and is rejected as funding deadline or HTLC timeout.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with_challenge_duration_metric(CHALLENGE_DURATION_METRIC_EPOCH)
+            .with_funding_deadline(env.challenge_duration)
+            .with(alice)
+            .invalid()
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with_challenge_duration_metric(CHALLENGE_DURATION_METRIC_EPOCH)
+            .with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(add_htlc(
+            Direction::AtoB,
+            Capacity::bytes(30)?.as_u64(),
+            blake2b256(b"htlc preimage"),
+            2 * env.challenge_duration,
+        ));
+
+        chan.with(bob)
+            .invalid()
+            .dispute()
+            .expect("disputing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn test_dispute_deadline(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let metrics = [
+        CHALLENGE_DURATION_METRIC_TIMESTAMP,
+        CHALLENGE_DURATION_METRIC_BLOCK_NUMBER,
+        CHALLENGE_DURATION_METRIC_EPOCH,
+    ];
+    for metric in metrics {
+        // Refutations do not extend the challenge duration, which started with the first dispute.
+        create_channel_test(context, env, &parts, |chan| {
+            chan.with_challenge_duration_metric(metric)
+                .with(alice)
+                .open(&funding_agreement)
+                .expect("opening channel");
+
+            chan.with(bob)
+                .fund(&funding_agreement)
+                .expect("funding channel");
+
+            chan.with(alice).dispute().expect("disputing channel");
+
+            chan.delay(env.challenge_duration / 2);
+
+            chan.with(bob)
+                .update(bump_version())
+                .dispute()
+                .expect("refuting dispute");
+
+            chan.delay(env.challenge_duration / 4);
+
+            chan.with(alice)
+                .update(bump_version())
+                .dispute()
+                .expect("refuting dispute");
+
+            chan.delay(env.challenge_duration / 4);
+
+            chan.with(bob).force_close().expect("force closing channel");
+
+            chan.assert();
+            Ok(())
+        })?;
+
+        create_channel_test(context, env, &parts, |chan| {
+            chan.with_challenge_duration_metric(metric)
+                .with(alice)
+                .open(&funding_agreement)
+                .expect("opening channel");
+
+            chan.with(bob)
+                .fund(&funding_agreement)
+                .expect("funding channel");
+
+            chan.with(alice).dispute().expect("disputing channel");
+
+            chan.delay(env.challenge_duration / 2);
+
+            chan.with(bob)
+                .update(bump_version())
+                .dispute()
+                .expect("refuting dispute");
+
+            chan.delay(env.challenge_duration / 2 - 1000);
+
+            chan.with(bob)
+                .invalid()
+                .force_close()
+                .expect("force closing channel");
+
+            chan.assert();
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn test_htlc_force_close(
+    context: &mut Context,
+    env: &perun::harness::Env,
+) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let preimage = b"htlc preimage";
+    let hash_lock = blake2b256(preimage);
+    let timeout = 2 * env.challenge_duration;
+
+    // The receiver is payed, if the preimage is revealed.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(add_htlc(
+            Direction::AtoB,
+            Capacity::bytes(30)?.as_u64(),
+            hash_lock,
+            timeout,
+        ));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(bob)
+            .with_preimage(preimage)
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // The sender is payed back after the timeout.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(add_htlc(
+            Direction::AtoB,
+            Capacity::bytes(30)?.as_u64(),
+            hash_lock,
+            timeout,
+        ));
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.delay(timeout);
+
+        chan.with(alice)
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // Without the preimage, the HTLC can not be settled before the timeout.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(add_htlc(
+            Direction::AtoB,
+            Capacity::bytes(30)?.as_u64(),
+            hash_lock,
+            timeout,
+        ));
+
+        chan.with(alice).dispute().expect("disputing channel");
+
+        chan.delay(env.challenge_duration);
+
+        chan.with(alice)
+            .with_preimage(b"wrong preimage")
+            .invalid()
+            .force_close()
+            .expect("force closing channel");
+
+        chan.assert();
+        Ok(())
+    })
+}
+
+fn test_htlc_close(context: &mut Context, env: &perun::harness::Env) -> Result<(), perun::Error> {
+    let (alice, bob) = ("alice", "bob");
+    let parts = [random::account(alice), random::account(bob)];
+    let funding = [
+        Capacity::bytes(100)?.as_u64(),
+        Capacity::bytes(100)?.as_u64(),
+    ];
+    let funding_agreement = test::FundingAgreement::new_with_capacities(
+        parts.iter().cloned().zip(funding.iter().cloned()).collect(),
+    );
+    let preimage = b"htlc preimage";
+    let hash_lock = blake2b256(preimage);
+    let timeout = 2 * env.challenge_duration;
+
+    // HTLCs can be settled and failed off-chain and the remaining ones are settled by the close.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(add_htlc(
+            Direction::AtoB,
+            Capacity::bytes(10)?.as_u64(),
+            hash_lock,
+            timeout,
+        ))
+        .update(add_htlc(
+            Direction::BtoA,
+            Capacity::bytes(20)?.as_u64(),
+            hash_lock,
+            timeout,
+        ))
+        .update(add_htlc(
+            Direction::AtoB,
+            Capacity::bytes(30)?.as_u64(),
+            hash_lock,
+            timeout,
+        ))
+        .update(settle_htlc(0))
+        .update(fail_htlc(0));
+
+        chan.with(bob).dispute().expect("disputing channel");
+
+        chan.finalize();
+        chan.with(alice)
+            .with_preimage(preimage)
+            .close()
+            .expect("closing channel");
+
+        chan.assert();
+        Ok(())
+    })?;
+
+    // An HTLC between a participant and itself is invalid.
+    create_channel_test(context, env, &parts, |chan| {
+        chan.with(alice)
+            .open(&funding_agreement)
+            .expect("opening channel");
+
+        chan.with(bob)
+            .fund(&funding_agreement)
+            .expect("funding channel");
+
+        chan.update(add_htlc(
+            Direction::FromTo(0, 0),
+            Capacity::bytes(10)?.as_u64(),
+            hash_lock,
+            timeout,
+        ));
+
+        chan.with(alice)
+            .invalid()
+            .dispute()
+            .expect("disputing channel");
 
         chan.assert();
         Ok(())